@@ -1,10 +1,10 @@
 use std::{
     io::{self, Read, Seek},
-    ops::{Bound, RangeBounds},
+    ops::RangeBounds,
 };
 
 use noodles_bgzf::{self as bgzf, VirtualPosition};
-use noodles_core::Position;
+use noodles_core::{region, Position};
 use noodles_csi::index::reference_sequence::bin::Chunk;
 use noodles_sam::AlignmentRecord;
 
@@ -128,75 +128,8 @@ where
         record.alignment_end(),
     ) {
         (Some(id), Some(start), Some(end)) => {
-            id == reference_sequence_id && in_interval(start, end, interval)
+            id == reference_sequence_id && region::overlaps(start, end, interval)
         }
         _ => false,
     }
 }
-
-fn in_interval<B>(alignment_start: Position, alignment_end: Position, region_interval: B) -> bool
-where
-    B: RangeBounds<Position>,
-{
-    let a = match region_interval.start_bound() {
-        Bound::Included(start) => *start <= alignment_end,
-        Bound::Excluded(start) => *start < alignment_end,
-        Bound::Unbounded => true,
-    };
-
-    let b = match region_interval.end_bound() {
-        Bound::Included(end) => alignment_start <= *end,
-        Bound::Excluded(end) => alignment_start < *end,
-        Bound::Unbounded => true,
-    };
-
-    a && b
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_in_interval() -> Result<(), noodles_core::position::TryFromIntError> {
-        fn p(n: usize) -> Result<Position, noodles_core::position::TryFromIntError> {
-            Position::try_from(n)
-        }
-
-        let start = p(5)?;
-        let end = p(8)?;
-
-        assert!(in_interval(start, end, p(4)?..p(7)?));
-        assert!(in_interval(start, end, p(4)?..=p(6)?));
-
-        assert!(in_interval(start, end, p(6)?..p(8)?));
-        assert!(in_interval(start, end, p(6)?..=p(7)?));
-
-        assert!(in_interval(start, end, p(7)?..p(10)?));
-        assert!(in_interval(start, end, p(7)?..=p(9)?));
-
-        assert!(in_interval(start, end, p(4)?..p(10)?));
-        assert!(in_interval(start, end, p(4)?..=p(9)?));
-
-        assert!(in_interval(start, end, p(4)?..));
-
-        assert!(in_interval(start, end, ..p(6)?));
-        assert!(in_interval(start, end, ..p(10)?));
-
-        assert!(in_interval(start, end, ..=p(5)?));
-        assert!(in_interval(start, end, ..=p(9)?));
-
-        assert!(!in_interval(start, end, p(2)?..p(5)?));
-        assert!(!in_interval(start, end, p(2)?..=p(4)?));
-
-        assert!(!in_interval(start, end, p(9)?..p(12)?));
-        assert!(!in_interval(start, end, p(9)?..=p(11)?));
-
-        assert!(!in_interval(start, end, p(9)?..));
-
-        assert!(!in_interval(start, end, ..p(5)?));
-        assert!(!in_interval(start, end, ..=p(4)?));
-
-        Ok(())
-    }
-}
@@ -245,7 +245,7 @@ impl<W> From<W> for Writer<W> {
     }
 }
 
-impl<W> sam::AlignmentWriter for Writer<W>
+impl<W> sam::AlignmentWriter for Writer<bgzf::Writer<W>>
 where
     W: Write,
 {
@@ -274,7 +274,7 @@ where
     }
 
     fn finish(&mut self, _: &sam::Header) -> io::Result<()> {
-        Ok(())
+        self.try_finish()
     }
 }
 
@@ -393,6 +393,35 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_finish() -> io::Result<()> {
+        // § 4.1.2 "End-of-file marker" (2021-06-03)
+        static BGZF_EOF: &[u8] = &[
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1b, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let header = sam::Header::default();
+        let mut buf = Vec::new();
+
+        let mut writer = Writer::new(&mut buf);
+        writer.write_alignment_header(&header)?;
+        writer.write_alignment_record(&header, &sam::Record::default())?;
+        AlignmentWriter::finish(&mut writer, &header)?;
+        assert!(writer.get_ref().get_ref().ends_with(BGZF_EOF));
+        drop(writer);
+
+        let eof_count = buf
+            .windows(BGZF_EOF.len())
+            .filter(|window| *window == BGZF_EOF)
+            .count();
+
+        assert_eq!(eof_count, 1);
+        assert!(buf.ends_with(BGZF_EOF));
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_alignment_record() -> Result<(), Box<dyn std::error::Error>> {
         let mut writer = Writer::new(Vec::new());
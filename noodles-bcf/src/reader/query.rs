@@ -4,6 +4,7 @@ use std::{
 };
 
 use noodles_bgzf as bgzf;
+use noodles_core::region;
 use noodles_csi::index::reference_sequence::bin::Chunk;
 
 use crate::Record;
@@ -145,9 +146,5 @@ pub(crate) fn intersects(
     let start = i32::from(record.position());
     let end = record.end().map(i32::from)?;
 
-    Ok(id == chromosome_id && in_interval(start, end, interval_start, interval_end))
-}
-
-fn in_interval(a_start: i32, a_end: i32, b_start: i32, b_end: i32) -> bool {
-    a_start <= b_end && b_start <= a_end
+    Ok(id == chromosome_id && region::overlaps(start, end, interval_start..=interval_end))
 }
@@ -11,6 +11,9 @@ use crate::{
     },
 };
 
+const MISSING_VALUE: &str = ".";
+const DELIMITER: char = ',';
+
 pub fn read_info<R>(
     reader: &mut R,
     infos: &vcf::header::Infos,
@@ -90,12 +93,41 @@ fn read_info_field_value<R>(
 where
     R: Read,
 {
-    match info.ty() {
+    use vcf::header::Number;
+
+    let value = match info.ty() {
         Type::Integer => read_info_field_integer_value(reader),
         Type::Flag => read_info_field_flag_value(reader),
         Type::Float => read_info_field_float_value(reader),
         Type::Character => read_info_field_character_value(reader),
         Type::String => read_info_field_string_value(reader),
+    }?;
+
+    // Single values are encoded the same as arrays with one element, so the header number is
+    // used to determine which is expected.
+    match info.number() {
+        Number::Count(0 | 1) => Ok(value),
+        _ => Ok(value.map(into_array_value)),
+    }
+}
+
+fn into_array_value(value: vcf::record::info::field::Value) -> vcf::record::info::field::Value {
+    use vcf::record::info::field::Value;
+
+    match value {
+        Value::Integer(n) => Value::IntegerArray(vec![Some(n)]),
+        Value::Float(n) => Value::FloatArray(vec![Some(n)]),
+        Value::Character(c) => Value::CharacterArray(vec![Some(c)]),
+        // String arrays are written as a single string of delimited values.
+        Value::String(s) => Value::StringArray(
+            s.split(DELIMITER)
+                .map(|t| match t {
+                    MISSING_VALUE => None,
+                    _ => Some(t.into()),
+                })
+                .collect(),
+        ),
+        _ => value,
     }
 }
 
@@ -542,4 +574,37 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_info_field_value_with_string_array_value() -> Result<(), Box<dyn std::error::Error>>
+    {
+        use vcf::record::info::field::Value;
+
+        let info = vcf::header::Info::new(
+            "STRING_ARRAY".parse()?,
+            Number::Unknown,
+            Type::String,
+            String::new(),
+        );
+
+        // Some(Value::String(Some(String::from("ndls"))))
+        let mut reader = &[0x47, 0x6e, 0x64, 0x6c, 0x73][..];
+        assert_eq!(
+            read_info_field_value(&mut reader, &info)?,
+            Some(Value::StringArray(vec![Some(String::from("ndls"))]))
+        );
+
+        // Some(Value::String(Some(String::from("n,.,s"))))
+        let mut reader = &[0x57, 0x6e, 0x2c, 0x2e, 0x2c, 0x73][..];
+        assert_eq!(
+            read_info_field_value(&mut reader, &info)?,
+            Some(Value::StringArray(vec![
+                Some(String::from("n")),
+                None,
+                Some(String::from("s")),
+            ]))
+        );
+
+        Ok(())
+    }
 }
@@ -125,7 +125,7 @@ impl Info {
     ///
     /// assert_eq!(
     ///     info.get(&header, string_maps.strings(), &Key::AlleleCount).transpose()?,
-    ///     Some(Field::new(Key::AlleleCount, Some(Value::Integer(5))))
+    ///     Some(Field::new(Key::AlleleCount, Some(Value::IntegerArray(vec![Some(5)]))))
     /// );
     ///
     /// assert!(info.get(&header, string_maps.strings(), &Key::AncestralAllele).is_none());
@@ -177,7 +177,7 @@ impl Info {
     ///
     /// assert_eq!(
     ///     fields.next().transpose()?,
-    ///     Some(Field::new(Key::AlleleCount, Some(Value::Integer(5))))
+    ///     Some(Field::new(Key::AlleleCount, Some(Value::IntegerArray(vec![Some(5)]))))
     /// );
     ///
     /// assert_eq!(
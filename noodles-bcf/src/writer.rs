@@ -263,4 +263,71 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_vcf_record_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::Reader;
+
+        const RAW_HEADER: &str = "\
+##fileformat=VCFv4.3
+##FILTER=<ID=PASS,Description=\"All filters passed\">
+##FILTER=<ID=q10,Description=\"Quality below 10\">
+##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\">
+##INFO=<ID=AF,Number=A,Type=Float,Description=\"Allele frequency\">
+##INFO=<ID=DB,Number=0,Type=Flag,Description=\"dbSNP membership\">
+##INFO=<ID=SOMATIC,Number=0,Type=Flag,Description=\"Somatic mutation\">
+##INFO=<ID=NAMES,Number=.,Type=String,Description=\"Names\">
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+##FORMAT=<ID=GQ,Number=1,Type=Integer,Description=\"Genotype quality\">
+##FORMAT=<ID=HQ,Number=2,Type=Integer,Description=\"Haplotype quality\">
+##contig=<ID=sq0,length=8>
+##contig=<ID=sq1,length=13>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\tsample1
+";
+
+        const RAW_RECORDS: [&str; 3] = [
+            "sq0\t1\trs1\tA\tG\t30.5\tPASS\tNS=2;AF=0.5;DB;NAMES=n0\tGT:GQ:HQ\t0|1:48:51,51\t1/1:43:.,.",
+            "sq0\t5\t.\tC\tT,CA\t.\tq10\tNS=1;AF=0.25,0.125;NAMES=n0,.,n2\tGT:GQ\t0/2:.\t./.:7",
+            "sq1\t13\t.\tG\t.\t8\t.\tSOMATIC\tGT\t0\t1",
+        ];
+
+        let header: vcf::Header = RAW_HEADER.parse()?;
+        let string_maps = StringMaps::from(&header);
+
+        let expected = RAW_RECORDS
+            .iter()
+            .map(|s| vcf::Record::try_from_str(s, &header))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_format()?;
+        writer.write_header(&header)?;
+
+        for record in &expected {
+            writer.write_vcf_record(&header, &string_maps, record)?;
+        }
+
+        let data = writer.into_inner().finish()?;
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_format()?;
+        let raw_header = reader.read_header()?;
+
+        let actual_header: vcf::Header = raw_header.parse()?;
+        let actual_string_maps: StringMaps = raw_header.parse()?;
+        assert_eq!(actual_header, header);
+
+        let actual = reader
+            .records()
+            .map(|result| {
+                result.and_then(|record| {
+                    record.try_into_vcf_record(&actual_header, &actual_string_maps)
+                })
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
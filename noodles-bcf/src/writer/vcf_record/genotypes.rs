@@ -46,6 +46,11 @@ where
             values.push(value);
         }
 
+        if key == &Key::Genotype {
+            write_genotype_genotype_field_values(writer, &values)?;
+            continue;
+        }
+
         let format = header.formats().get(key).ok_or_else(|| {
             io::Error::new(io::ErrorKind::InvalidData, "missing FORMAT header record")
         })?;
@@ -679,6 +684,75 @@ where
     Ok(())
 }
 
+fn write_genotype_genotype_field_values<W>(
+    writer: &mut W,
+    values: &[Option<&Value>],
+) -> io::Result<()>
+where
+    W: Write,
+{
+    let mut encoded_values = Vec::with_capacity(values.len());
+
+    for value in values {
+        let encoded_value = match value {
+            Some(Value::String(s)) => s
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+                .and_then(|genotype| encode_genotype_genotype_field_value(&genotype))?,
+            // A missing genotype is encoded as a single missing allele.
+            None => vec![0],
+            v => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("type mismatch: expected String, got {:?}", v),
+                ))
+            }
+        };
+
+        encoded_values.push(encoded_value);
+    }
+
+    let max_len = encoded_values.iter().map(|v| v.len()).max().unwrap_or(0);
+
+    write_type(writer, Some(Type::Int8(max_len)))?;
+
+    for encoded_value in encoded_values {
+        for &n in &encoded_value {
+            writer.write_i8(n)?;
+        }
+
+        for _ in encoded_value.len()..max_len {
+            writer.write_i8(i8::from(Int8::EndOfVector))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn encode_genotype_genotype_field_value(
+    genotype: &vcf::record::genotypes::genotype::field::value::Genotype,
+) -> io::Result<Vec<i8>> {
+    use vcf::record::genotypes::genotype::field::value::genotype::allele::Phasing;
+
+    genotype
+        .iter()
+        .map(|allele| {
+            let i = allele.position().map(|position| position + 1).unwrap_or(0);
+            let is_phased = allele.phasing() == Some(Phasing::Phased);
+
+            i.checked_mul(2)
+                .map(|n| n | usize::from(is_phased))
+                .and_then(|n| i8::try_from(n).ok())
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("invalid genotype allele position: {:?}", allele.position()),
+                    )
+                })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use noodles_vcf::header::{format, Number};
@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use noodles_core::Position;
+
+use super::{record::BedN, GenomeBounds, Record};
+
+/// Returns the intervals of the genome that are not covered by any of the given records.
+///
+/// The input records do not need to be sorted or merged. Complement intervals are returned in
+/// the order of the reference sequences in the genome bounds, including the regions before the
+/// first and after the last feature of each reference sequence. Reference sequences without any
+/// features are returned whole. Records on reference sequences that are not in the genome bounds
+/// are ignored.
+///
+/// This is similar to `bedtools complement`.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bed::{self as bed, GenomeBounds};
+///
+/// let records: Vec<bed::Record<3>> = vec!["sq0\t5\t8".parse()?];
+/// let genome_bounds: GenomeBounds = "sq0\t13\nsq1\t21\n".parse()?;
+///
+/// let actual: Vec<_> = bed::complement(&records, &genome_bounds)
+///     .iter()
+///     .map(|record| record.to_string())
+///     .collect();
+///
+/// assert_eq!(actual, ["sq0\t0\t5", "sq0\t8\t13", "sq1\t0\t21"]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn complement<const N: u8>(
+    records: &[Record<N>],
+    genome_bounds: &GenomeBounds,
+) -> Vec<Record<3>>
+where
+    Record<N>: BedN<3>,
+{
+    let mut intervals: HashMap<&str, Vec<(Position, Position)>> = HashMap::new();
+
+    for record in records {
+        intervals
+            .entry(record.reference_sequence_name())
+            .or_default()
+            .push((record.start_position(), record.end_position()));
+    }
+
+    let mut complement_records = Vec::new();
+
+    for (name, &len) in genome_bounds.iter() {
+        let mut reference_sequence_intervals = intervals.remove(name.as_str()).unwrap_or_default();
+        reference_sequence_intervals.sort_unstable();
+
+        // The next position that is not covered by a feature.
+        let mut next_start = 1;
+
+        for (start, end) in reference_sequence_intervals {
+            let (start, end) = (usize::from(start), usize::from(end));
+
+            if start > next_start {
+                push_interval(&mut complement_records, name, next_start, start - 1);
+            }
+
+            next_start = next_start.max(end.saturating_add(1));
+        }
+
+        if next_start <= len {
+            push_interval(&mut complement_records, name, next_start, len);
+        }
+    }
+
+    complement_records
+}
+
+fn push_interval(records: &mut Vec<Record<3>>, name: &str, start: usize, end: usize) {
+    if let (Some(start), Some(end)) = (Position::new(start), Position::new(end)) {
+        records.push(Record::from_interval(name, start, end));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complement() -> Result<(), Box<dyn std::error::Error>> {
+        let genome_bounds: GenomeBounds = "sq0\t21\n".parse()?;
+
+        let records: Vec<Record<3>> = vec!["sq0\t13\t17".parse()?, "sq0\t5\t8".parse()?];
+        let actual: Vec<_> = complement(&records, &genome_bounds)
+            .iter()
+            .map(|record| record.to_string())
+            .collect();
+        assert_eq!(actual, ["sq0\t0\t5", "sq0\t8\t13", "sq0\t17\t21"]);
+
+        // overlapping and adjacent features at the reference sequence bounds
+        let records: Vec<Record<3>> = vec![
+            "sq0\t0\t5".parse()?,
+            "sq0\t3\t8".parse()?,
+            "sq0\t8\t13".parse()?,
+            "sq0\t17\t21".parse()?,
+            "sq1\t0\t8".parse()?,
+        ];
+        let actual: Vec<_> = complement(&records, &genome_bounds)
+            .iter()
+            .map(|record| record.to_string())
+            .collect();
+        assert_eq!(actual, ["sq0\t13\t17"]);
+
+        let records: Vec<Record<3>> = Vec::new();
+        let actual: Vec<_> = complement(&records, &genome_bounds)
+            .iter()
+            .map(|record| record.to_string())
+            .collect();
+        assert_eq!(actual, ["sq0\t0\t21"]);
+
+        Ok(())
+    }
+}
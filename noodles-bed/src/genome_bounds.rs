@@ -0,0 +1,205 @@
+//! BED genome bounds.
+
+use std::{
+    error, fmt, num,
+    ops::{Deref, DerefMut},
+    str::FromStr,
+};
+
+use indexmap::IndexMap;
+use noodles_sam as sam;
+
+const DELIMITER: char = '\t';
+const COMMENT_PREFIX: char = '#';
+
+/// An ordered map of reference sequence names to reference sequence lengths.
+///
+/// This is typically read from a genome file, i.e., a tab-delimited file where each line has a
+/// reference sequence name and length (e.g., `sq0\t8`).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GenomeBounds(IndexMap<String, usize>);
+
+impl Deref for GenomeBounds {
+    type Target = IndexMap<String, usize>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for GenomeBounds {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<IndexMap<String, usize>> for GenomeBounds {
+    fn from(bounds: IndexMap<String, usize>) -> Self {
+        Self(bounds)
+    }
+}
+
+impl FromIterator<(String, usize)> for GenomeBounds {
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = (String, usize)>,
+    {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl From<&sam::header::ReferenceSequences> for GenomeBounds {
+    /// Creates genome bounds from a SAM header reference sequence dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::GenomeBounds;
+    /// use noodles_sam::{self as sam, header::ReferenceSequence};
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+    ///     .build();
+    ///
+    /// let genome_bounds = GenomeBounds::from(header.reference_sequences());
+    /// assert_eq!(genome_bounds.get("sq0"), Some(&8));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    fn from(reference_sequences: &sam::header::ReferenceSequences) -> Self {
+        reference_sequences
+            .iter()
+            .map(|(name, reference_sequence)| (name.clone(), reference_sequence.len() as usize))
+            .collect()
+    }
+}
+
+/// An error returned when a raw genome file fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// A reference sequence name is missing.
+    MissingName,
+    /// A reference sequence length is missing.
+    MissingLength,
+    /// A reference sequence length is invalid.
+    InvalidLength(num::ParseIntError),
+    /// A reference sequence name is duplicated.
+    DuplicateName(String),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidLength(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingName => f.write_str("missing name"),
+            Self::MissingLength => f.write_str("missing length"),
+            Self::InvalidLength(_) => f.write_str("invalid length"),
+            Self::DuplicateName(name) => write!(f, "duplicate name: {}", name),
+        }
+    }
+}
+
+impl FromStr for GenomeBounds {
+    type Err = ParseError;
+
+    /// Parses a raw genome file.
+    ///
+    /// Empty lines and lines starting with `#` are skipped. Columns after the length are ignored,
+    /// so a FASTA index (`.fai`) can also be used as a genome file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::GenomeBounds;
+    ///
+    /// let genome_bounds: GenomeBounds = "sq0\t8\nsq1\t13\n".parse()?;
+    /// assert_eq!(genome_bounds.get("sq1"), Some(&13));
+    /// # Ok::<_, noodles_bed::genome_bounds::ParseError>(())
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bounds = IndexMap::new();
+
+        for line in s.lines() {
+            if line.is_empty() || line.starts_with(COMMENT_PREFIX) {
+                continue;
+            }
+
+            let mut fields = line.split(DELIMITER);
+
+            let name = fields
+                .next()
+                .filter(|s| !s.is_empty())
+                .ok_or(ParseError::MissingName)?;
+
+            let len = fields
+                .next()
+                .ok_or(ParseError::MissingLength)
+                .and_then(|s| s.parse().map_err(ParseError::InvalidLength))?;
+
+            if bounds.insert(name.into(), len).is_some() {
+                return Err(ParseError::DuplicateName(name.into()));
+            }
+        }
+
+        Ok(Self(bounds))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_reference_sequences_for_genome_bounds() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::header::ReferenceSequence;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+            .add_reference_sequence(ReferenceSequence::new("sq1".parse()?, 13)?)
+            .build();
+
+        let actual = GenomeBounds::from(header.reference_sequences());
+        let expected: GenomeBounds = [(String::from("sq0"), 8), (String::from("sq1"), 13)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str() -> Result<(), ParseError> {
+        let s = "# genome\nsq0\t8\n\nsq1\t13\t6\t60\t61\n";
+        let genome_bounds: GenomeBounds = s.parse()?;
+
+        assert_eq!(genome_bounds.len(), 2);
+        assert_eq!(genome_bounds.get("sq0"), Some(&8));
+        assert_eq!(genome_bounds.get("sq1"), Some(&13));
+        assert!(genome_bounds.get("sq2").is_none());
+
+        assert_eq!("".parse(), Ok(GenomeBounds::default()));
+        assert_eq!("\t8".parse::<GenomeBounds>(), Err(ParseError::MissingName));
+        assert_eq!(
+            "sq0".parse::<GenomeBounds>(),
+            Err(ParseError::MissingLength)
+        );
+        assert!(matches!(
+            "sq0\tndls".parse::<GenomeBounds>(),
+            Err(ParseError::InvalidLength(_))
+        ));
+        assert_eq!(
+            "sq0\t8\nsq0\t13".parse::<GenomeBounds>(),
+            Err(ParseError::DuplicateName(String::from("sq0")))
+        );
+
+        Ok(())
+    }
+}
@@ -2,8 +2,14 @@
 
 //! **noodles-bed** handles the reading and writing of the BED (Browser Extensible Data) format.
 
+mod complement;
+pub mod genome_bounds;
 mod reader;
 pub mod record;
+pub mod sort;
 mod writer;
 
-pub use self::{reader::Reader, record::Record, writer::Writer};
+pub use self::{
+    complement::complement, genome_bounds::GenomeBounds, reader::Reader, record::Record,
+    sort::sort, writer::Writer,
+};
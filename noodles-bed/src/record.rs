@@ -1,14 +1,19 @@
 //! BED record and fields.
 
 pub mod builder;
+pub mod color;
 pub mod name;
+pub mod narrow_peak;
 pub mod score;
 pub mod strand;
 
-pub use self::{builder::Builder, name::Name, score::Score, strand::Strand};
+pub use self::{
+    builder::Builder, color::Color, name::Name, narrow_peak::NarrowPeak, score::Score,
+    strand::Strand,
+};
 
 use std::{
-    error,
+    cmp, error,
     fmt::{self, Write},
     num,
     ops::Deref,
@@ -17,6 +22,8 @@ use std::{
 
 use noodles_core::Position;
 
+use crate::GenomeBounds;
+
 const DELIMITER: char = '\t';
 const MISSING_STRING: &str = ".";
 const MISSING_NUMBER: &str = "0";
@@ -59,6 +66,30 @@ impl Deref for OptionalFields {
     }
 }
 
+impl OptionalFields {
+    /// Parses the fields as narrowPeak (BED6+4) fields.
+    ///
+    /// The raw fields are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed as bed;
+    ///
+    /// let record: bed::Record<6> = "sq0\t8\t13\t.\t0\t.\t182\t5.0945\t-1\t50".parse()?;
+    /// let narrow_peak = record.optional_fields().as_narrow_peak()?;
+    ///
+    /// assert_eq!(narrow_peak.signal_value(), 182.0);
+    /// assert_eq!(narrow_peak.p_value(), Some(5.0945));
+    /// assert_eq!(narrow_peak.q_value(), None);
+    /// assert_eq!(narrow_peak.peak(), Some(50));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn as_narrow_peak(&self) -> Result<NarrowPeak, narrow_peak::ParseError> {
+        NarrowPeak::try_from(self)
+    }
+}
+
 impl fmt::Display for OptionalFields {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for (i, field) in self.0.iter().enumerate() {
@@ -94,6 +125,40 @@ mod optional_fields_tests {
         let fields = OptionalFields::from(vec![String::from("n"), String::from("d")]);
         assert_eq!(fields.to_string(), "n\td");
     }
+
+    #[test]
+    fn test_as_narrow_peak() -> Result<(), Box<dyn std::error::Error>> {
+        let record: Record<6> = "sq0\t8\t13\tpeak0\t1000\t.\t182.5\t5.0945\t3.2\t-1".parse()?;
+        let narrow_peak = record.optional_fields().as_narrow_peak()?;
+
+        assert_eq!(narrow_peak.signal_value(), 182.5);
+        assert_eq!(narrow_peak.p_value(), Some(5.0945));
+        assert_eq!(narrow_peak.q_value(), Some(3.2));
+        assert_eq!(narrow_peak.peak(), None);
+
+        assert_eq!(record.optional_fields().len(), 4);
+
+        Ok(())
+    }
+}
+
+/// An error returned when a BED record fails to extend.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExtendError {
+    /// The reference sequence is not in the genome bounds.
+    MissingReferenceSequence(String),
+}
+
+impl error::Error for ExtendError {}
+
+impl fmt::Display for ExtendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingReferenceSequence(name) => {
+                write!(f, "missing reference sequence: {}", name)
+            }
+        }
+    }
 }
 
 /// A BED record.
@@ -223,6 +288,75 @@ where
     pub fn optional_fields(&self) -> &OptionalFields {
         &self.optional_fields
     }
+
+    /// Extends the feature by the given number of bases on each side.
+    ///
+    /// The start position is clamped to the start of the reference sequence, and the end
+    /// position is clamped to the reference sequence length given by the genome bounds. This is
+    /// similar to `bedtools slop`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::{self as bed, GenomeBounds};
+    /// use noodles_core::Position;
+    ///
+    /// let mut record = bed::Record::<3>::builder()
+    ///     .set_reference_sequence_name("sq0")
+    ///     .set_start_position(Position::try_from(8)?)
+    ///     .set_end_position(Position::try_from(13)?)
+    ///     .build()?;
+    ///
+    /// let genome_bounds: GenomeBounds = [(String::from("sq0"), 21)].into_iter().collect();
+    /// record.extend(5, 13, &genome_bounds)?;
+    ///
+    /// assert_eq!(record.start_position(), Position::try_from(3)?);
+    /// assert_eq!(record.end_position(), Position::try_from(21)?);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn extend(
+        &mut self,
+        left: usize,
+        right: usize,
+        genome_bounds: &GenomeBounds,
+    ) -> Result<(), ExtendError> {
+        let reference_sequence_len = genome_bounds
+            .get(self.reference_sequence_name())
+            .copied()
+            .ok_or_else(|| {
+                ExtendError::MissingReferenceSequence(self.reference_sequence_name().into())
+            })?;
+
+        let start = usize::from(self.start_position()).saturating_sub(left);
+
+        self.standard_fields.start_position = Position::new(start).unwrap_or(Position::MIN);
+
+        let end = usize::from(self.end_position())
+            .saturating_add(right)
+            .min(reference_sequence_len);
+
+        if let Some(position) = Position::new(end) {
+            self.standard_fields.end_position = cmp::max(self.end_position(), position);
+        }
+
+        Ok(())
+    }
+}
+
+impl Record<3> {
+    pub(crate) fn from_interval<N>(
+        reference_sequence_name: N,
+        start_position: Position,
+        end_position: Position,
+    ) -> Self
+    where
+        N: Into<String>,
+    {
+        let standard_fields =
+            StandardFields::new(reference_sequence_name, start_position, end_position);
+
+        Self::new(standard_fields, OptionalFields::default())
+    }
 }
 
 impl<const N: u8> Record<N>
@@ -620,6 +754,32 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_extend() -> Result<(), Box<dyn std::error::Error>> {
+        let genome_bounds: GenomeBounds = [(String::from("sq0"), 21)].into_iter().collect();
+
+        let mut record: Record<3> = "sq0\t7\t13".parse()?;
+        record.extend(2, 3, &genome_bounds)?;
+        assert_eq!(record.to_string(), "sq0\t5\t16");
+
+        // past the reference sequence start and end
+        let mut record: Record<3> = "sq0\t7\t13".parse()?;
+        record.extend(13, 34, &genome_bounds)?;
+        assert_eq!(record.to_string(), "sq0\t0\t21");
+
+        let mut record: Record<3> = "sq0\t0\t8".parse()?;
+        record.extend(usize::MAX, usize::MAX, &genome_bounds)?;
+        assert_eq!(record.to_string(), "sq0\t0\t21");
+
+        let mut record: Record<3> = "sq1\t7\t13".parse()?;
+        assert_eq!(
+            record.extend(2, 3, &genome_bounds),
+            Err(ExtendError::MissingReferenceSequence(String::from("sq1")))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt_for_record_3() -> Result<(), noodles_core::position::TryFromIntError> {
         let start = Position::try_from(8)?;
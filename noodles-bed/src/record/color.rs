@@ -0,0 +1,133 @@
+//! BED record color.
+
+use std::{error, fmt, num, str::FromStr};
+
+const DEFAULT: &str = "0";
+const DELIMITER: char = ',';
+
+/// A BED record color (`itemRgb`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Color {
+    /// The default color (`0`).
+    ///
+    /// This means the display uses its own default color.
+    Default,
+    /// An RGB color (`R,G,B`).
+    Rgb(u8, u8, u8),
+}
+
+impl Default for Color {
+    fn default() -> Self {
+        Self::Default
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Default => f.write_str(DEFAULT),
+            Self::Rgb(r, g, b) => write!(f, "{}{}{}{}{}", r, DELIMITER, g, DELIMITER, b),
+        }
+    }
+}
+
+/// An error returned when a raw BED record color fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The input does not have exactly three components.
+    InvalidLength(usize),
+    /// A component is invalid.
+    InvalidComponent(num::ParseIntError),
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::InvalidLength(len) => write!(f, "invalid length: expected 3, got {}", len),
+            Self::InvalidComponent(e) => write!(f, "invalid component: {}", e),
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        } else if s == DEFAULT {
+            return Ok(Self::Default);
+        }
+
+        let components = s
+            .split(DELIMITER)
+            .map(|t| t.parse().map_err(ParseError::InvalidComponent))
+            .collect::<Result<Vec<u8>, _>>()?;
+
+        match components[..] {
+            [r, g, b] => Ok(Self::Rgb(r, g, b)),
+            _ => Err(ParseError::InvalidLength(components.len())),
+        }
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        Self::Rgb(r, g, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        assert_eq!(Color::default(), Color::Default);
+    }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Color::Default.to_string(), "0");
+        assert_eq!(Color::Rgb(255, 0, 0).to_string(), "255,0,0");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("0".parse(), Ok(Color::Default));
+        assert_eq!("255,0,0".parse(), Ok(Color::Rgb(255, 0, 0)));
+        assert_eq!("0,0,0".parse(), Ok(Color::Rgb(0, 0, 0)));
+
+        for s in ["0", "255,0,0"] {
+            assert_eq!(
+                s.parse::<Color>().map(|color| color.to_string()),
+                Ok(s.into())
+            );
+        }
+
+        assert_eq!("".parse::<Color>(), Err(ParseError::Empty));
+        assert_eq!("255,0".parse::<Color>(), Err(ParseError::InvalidLength(2)));
+        assert_eq!(
+            "255,0,0,0".parse::<Color>(),
+            Err(ParseError::InvalidLength(4))
+        );
+        assert!(matches!(
+            "256,0,0".parse::<Color>(),
+            Err(ParseError::InvalidComponent(_))
+        ));
+        assert!(matches!(
+            "r,g,b".parse::<Color>(),
+            Err(ParseError::InvalidComponent(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_u8_tuple_for_color() {
+        assert_eq!(Color::from((255, 0, 0)), Color::Rgb(255, 0, 0));
+    }
+}
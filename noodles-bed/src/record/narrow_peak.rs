@@ -0,0 +1,158 @@
+//! BED record narrowPeak fields.
+
+use std::{error, fmt, num};
+
+use super::OptionalFields;
+
+const FIELD_COUNT: usize = 4;
+const MISSING_VALUE: &str = "-1";
+
+/// Typed narrowPeak (BED6+4) fields.
+///
+/// These are the four fields that follow the standard BED6 fields in a narrowPeak record. See
+/// [`OptionalFields::as_narrow_peak`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct NarrowPeak {
+    signal_value: f64,
+    p_value: Option<f64>,
+    q_value: Option<f64>,
+    peak: Option<u64>,
+}
+
+impl NarrowPeak {
+    /// Returns the overall enrichment of the region.
+    pub fn signal_value(&self) -> f64 {
+        self.signal_value
+    }
+
+    /// Returns the statistical significance (-log10) of the region.
+    ///
+    /// This is `None` if the value is missing (`-1`).
+    pub fn p_value(&self) -> Option<f64> {
+        self.p_value
+    }
+
+    /// Returns the statistical significance (-log10) of the region using false discovery rate.
+    ///
+    /// This is `None` if the value is missing (`-1`).
+    pub fn q_value(&self) -> Option<f64> {
+        self.q_value
+    }
+
+    /// Returns the 0-based offset of the peak from the start position.
+    ///
+    /// This is `None` if the value is missing (`-1`).
+    pub fn peak(&self) -> Option<u64> {
+        self.peak
+    }
+}
+
+/// An error returned when raw BED record optional fields fail to parse as narrowPeak fields.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The number of fields is invalid.
+    InvalidFieldCount(usize),
+    /// The signal value is invalid.
+    InvalidSignalValue(num::ParseFloatError),
+    /// The p-value is invalid.
+    InvalidPValue(num::ParseFloatError),
+    /// The q-value is invalid.
+    InvalidQValue(num::ParseFloatError),
+    /// The peak is invalid.
+    InvalidPeak(num::ParseIntError),
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidFieldCount(n) => write!(
+                f,
+                "invalid field count: expected {}, got {}",
+                FIELD_COUNT, n
+            ),
+            Self::InvalidSignalValue(e) => write!(f, "invalid signal value: {}", e),
+            Self::InvalidPValue(e) => write!(f, "invalid p-value: {}", e),
+            Self::InvalidQValue(e) => write!(f, "invalid q-value: {}", e),
+            Self::InvalidPeak(e) => write!(f, "invalid peak: {}", e),
+        }
+    }
+}
+
+impl TryFrom<&OptionalFields> for NarrowPeak {
+    type Error = ParseError;
+
+    fn try_from(fields: &OptionalFields) -> Result<Self, Self::Error> {
+        let (raw_signal_value, raw_p_value, raw_q_value, raw_peak) = match &fields[..] {
+            [a, b, c, d] => (a, b, c, d),
+            _ => return Err(ParseError::InvalidFieldCount(fields.len())),
+        };
+
+        let signal_value = raw_signal_value
+            .parse()
+            .map_err(ParseError::InvalidSignalValue)?;
+
+        let p_value = parse_optional_float(raw_p_value).map_err(ParseError::InvalidPValue)?;
+        let q_value = parse_optional_float(raw_q_value).map_err(ParseError::InvalidQValue)?;
+
+        let peak = match raw_peak.as_str() {
+            MISSING_VALUE => None,
+            s => s.parse().map(Some).map_err(ParseError::InvalidPeak)?,
+        };
+
+        Ok(Self {
+            signal_value,
+            p_value,
+            q_value,
+            peak,
+        })
+    }
+}
+
+fn parse_optional_float(s: &str) -> Result<Option<f64>, num::ParseFloatError> {
+    s.parse()
+        .map(|n: f64| if n == -1.0 { None } else { Some(n) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_optional_fields(fields: &[&str]) -> OptionalFields {
+        OptionalFields::from(fields.iter().map(|&s| s.into()).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_try_from_optional_fields_for_narrow_peak() {
+        let fields = build_optional_fields(&["182", "5.0945", "-1", "50"]);
+
+        assert_eq!(
+            NarrowPeak::try_from(&fields),
+            Ok(NarrowPeak {
+                signal_value: 182.0,
+                p_value: Some(5.0945),
+                q_value: None,
+                peak: Some(50),
+            })
+        );
+
+        let fields = build_optional_fields(&["182", "5.0945", "-1"]);
+        assert_eq!(
+            NarrowPeak::try_from(&fields),
+            Err(ParseError::InvalidFieldCount(3))
+        );
+
+        let fields = build_optional_fields(&["ndls", "5.0945", "-1", "50"]);
+        assert!(matches!(
+            NarrowPeak::try_from(&fields),
+            Err(ParseError::InvalidSignalValue(_))
+        ));
+
+        let fields = build_optional_fields(&["182", "5.0945", "-1", "-2"]);
+        assert!(matches!(
+            NarrowPeak::try_from(&fields),
+            Err(ParseError::InvalidPeak(_))
+        ));
+    }
+}
@@ -0,0 +1,190 @@
+//! BED record sorting.
+
+use std::cmp::Ordering;
+
+use super::{record::BedN, Record};
+
+/// The order of reference sequence names when sorting records.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ChromosomeOrder {
+    /// Natural order, where runs of digits are compared numerically (e.g., `chr2` < `chr10`).
+    Natural,
+    /// Lexicographic order (e.g., `chr10` < `chr2`).
+    Lexicographic,
+}
+
+impl Default for ChromosomeOrder {
+    fn default() -> Self {
+        Self::Natural
+    }
+}
+
+/// Sorts BED records by reference sequence name, start position, and end position.
+///
+/// Reference sequence names are compared using the given chromosome order. The sort is stable.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bed::{self as bed, sort::ChromosomeOrder};
+///
+/// let mut records: Vec<bed::Record<3>> = vec![
+///     "chr10\t0\t8".parse()?,
+///     "chr2\t5\t13".parse()?,
+///     "chr2\t0\t8".parse()?,
+/// ];
+///
+/// bed::sort(&mut records, ChromosomeOrder::Natural);
+///
+/// let actual: Vec<_> = records.iter().map(|r| r.to_string()).collect();
+/// assert_eq!(actual, ["chr2\t0\t8", "chr2\t5\t13", "chr10\t0\t8"]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn sort<const N: u8>(records: &mut [Record<N>], chromosome_order: ChromosomeOrder)
+where
+    Record<N>: BedN<3>,
+{
+    records.sort_by(|a, b| {
+        compare_reference_sequence_names(
+            chromosome_order,
+            a.reference_sequence_name(),
+            b.reference_sequence_name(),
+        )
+        .then_with(|| a.start_position().cmp(&b.start_position()))
+        .then_with(|| a.end_position().cmp(&b.end_position()))
+    });
+}
+
+fn compare_reference_sequence_names(
+    chromosome_order: ChromosomeOrder,
+    a: &str,
+    b: &str,
+) -> Ordering {
+    match chromosome_order {
+        ChromosomeOrder::Natural => natural_cmp(a, b).then_with(|| a.cmp(b)),
+        ChromosomeOrder::Lexicographic => a.cmp(b),
+    }
+}
+
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a_chunks = Chunks(a);
+    let mut b_chunks = Chunks(b);
+
+    loop {
+        match (a_chunks.next(), b_chunks.next()) {
+            (Some(x), Some(y)) => {
+                let ordering = match (is_digits(x), is_digits(y)) {
+                    (true, true) => numeric_cmp(x, y),
+                    _ => x.cmp(y),
+                };
+
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (None, None) => return Ordering::Equal,
+        }
+    }
+}
+
+fn is_digits(s: &str) -> bool {
+    s.bytes().all(|b| b.is_ascii_digit())
+}
+
+// Compares two runs of ASCII digits by value without parsing, which avoids overflow.
+fn numeric_cmp(a: &str, b: &str) -> Ordering {
+    let a = a.trim_start_matches('0');
+    let b = b.trim_start_matches('0');
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+// An iterator over alternating runs of digits and non-digits.
+struct Chunks<'a>(&'a str);
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.0.bytes().next()?;
+        let is_digit = first.is_ascii_digit();
+
+        let i = self
+            .0
+            .bytes()
+            .position(|b| b.is_ascii_digit() != is_digit)
+            .unwrap_or(self.0.len());
+
+        let (chunk, rest) = self.0.split_at(i);
+        self.0 = rest;
+
+        Some(chunk)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_records() -> Result<Vec<Record<3>>, Box<dyn std::error::Error>> {
+        [
+            "chr10\t0\t8",
+            "chr2\t5\t13",
+            "chr1\t8\t21",
+            "chr2\t0\t13",
+            "chr1\t8\t13",
+            "chr2\t0\t8",
+        ]
+        .iter()
+        .map(|s| s.parse().map_err(Into::into))
+        .collect()
+    }
+
+    #[test]
+    fn test_sort() -> Result<(), Box<dyn std::error::Error>> {
+        let mut records = build_records()?;
+        sort(&mut records, ChromosomeOrder::Natural);
+
+        let actual: Vec<_> = records.iter().map(|r| r.to_string()).collect();
+        let expected = [
+            "chr1\t8\t13",
+            "chr1\t8\t21",
+            "chr2\t0\t8",
+            "chr2\t0\t13",
+            "chr2\t5\t13",
+            "chr10\t0\t8",
+        ];
+        assert_eq!(actual, expected);
+
+        let mut records = build_records()?;
+        sort(&mut records, ChromosomeOrder::Lexicographic);
+
+        let actual: Vec<_> = records.iter().map(|r| r.to_string()).collect();
+        let expected = [
+            "chr1\t8\t13",
+            "chr1\t8\t21",
+            "chr10\t0\t8",
+            "chr2\t0\t8",
+            "chr2\t0\t13",
+            "chr2\t5\t13",
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_natural_cmp() {
+        assert_eq!(natural_cmp("chr2", "chr10"), Ordering::Less);
+        assert_eq!(natural_cmp("chr10", "chr2"), Ordering::Greater);
+        assert_eq!(natural_cmp("chr1", "chr1"), Ordering::Equal);
+        assert_eq!(natural_cmp("chr1", "chr1_random"), Ordering::Less);
+        assert_eq!(natural_cmp("chr9", "chrX"), Ordering::Less);
+        assert_eq!(natural_cmp("chr01", "chr1"), Ordering::Equal);
+        assert_eq!(
+            natural_cmp("sq99999999999999999999", "sq100000000000000000000"),
+            Ordering::Less
+        );
+    }
+}
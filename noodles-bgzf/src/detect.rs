@@ -0,0 +1,170 @@
+use std::io::{self, Cursor, Read};
+
+use super::gz;
+
+// ID1 (1) + ID2 (1) + CM (1) + FLG (1) + MTIME (4) + XFL (1) + OS (1) + XLEN (2) + SI1 (1) +
+// SI2 (1)
+const MAGIC_LEN: usize = 14;
+
+/// A detected compression method of a stream.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Compression {
+    /// The stream is BGZF-compressed.
+    Bgzf,
+    /// The stream is gzip-compressed but not a BGZF stream.
+    Gzip,
+}
+
+/// Detects whether a stream is BGZF- or gzip-compressed.
+///
+/// A single read may return fewer bytes than a block header, so the start of the stream is read
+/// until the block header is complete or the stream ends. The returned reader yields the entire
+/// stream, including the bytes read for detection.
+///
+/// The compression is `None` if the stream does not start with a gzip header.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Read, Write};
+/// use noodles_bgzf::{self as bgzf, Compression};
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"noodles")?;
+/// let data = writer.finish()?;
+///
+/// let (compression, reader) = bgzf::detect_compression(&data[..])?;
+/// assert_eq!(compression, Some(Compression::Bgzf));
+///
+/// let mut buf = Vec::new();
+/// bgzf::Reader::new(reader).read_to_end(&mut buf)?;
+/// assert_eq!(buf, b"noodles");
+///
+/// let (compression, _) = bgzf::detect_compression(&b"noodles"[..])?;
+/// assert!(compression.is_none());
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn detect_compression<R>(mut reader: R) -> io::Result<(Option<Compression>, impl Read)>
+where
+    R: Read,
+{
+    let mut magic = [0; MAGIC_LEN];
+    let len = read_magic(&mut reader, &mut magic)?;
+
+    let compression = if is_bgzf(&magic[..len]) {
+        Some(Compression::Bgzf)
+    } else if is_gzip(&magic[..len]) {
+        Some(Compression::Gzip)
+    } else {
+        None
+    };
+
+    let reader = Cursor::new(magic).take(len as u64).chain(reader);
+
+    Ok((compression, reader))
+}
+
+// Reads into the buffer until it is full or the stream ends.
+fn read_magic<R>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize>
+where
+    R: Read,
+{
+    let mut len = 0;
+
+    while len < buf.len() {
+        match reader.read(&mut buf[len..]) {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(len)
+}
+
+fn is_gzip(buf: &[u8]) -> bool {
+    buf.starts_with(&gz::MAGIC_NUMBER)
+}
+
+// Checks whether the buffer starts with a BGZF block header, i.e., a gzip header with the extra
+// field flag set and a `BC` subfield.
+fn is_bgzf(buf: &[u8]) -> bool {
+    const FLG_FEXTRA: u8 = 0x04;
+    const BGZF_SUBFIELD_ID: [u8; 2] = [b'B', b'C'];
+
+    buf.len() >= MAGIC_LEN
+        && is_gzip(buf)
+        && buf[2] == gz::CompressionMethod::Deflate as u8
+        && buf[3] & FLG_FEXTRA != 0
+        && buf[12..14] == BGZF_SUBFIELD_ID
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::write::GzEncoder;
+
+    use super::*;
+
+    #[test]
+    fn test_detect_compression() -> io::Result<()> {
+        // a stream that returns at most 1 byte per read
+        struct ByteReader<'a>(&'a [u8]);
+
+        impl Read for ByteReader<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                let len = self.0.len().min(buf.len()).min(1);
+                let (head, tail) = self.0.split_at(len);
+                buf[..len].copy_from_slice(head);
+                self.0 = tail;
+                Ok(len)
+            }
+        }
+
+        fn t(data: &[u8], expected: Option<Compression>) -> io::Result<()> {
+            let (actual, mut reader) = detect_compression(ByteReader(data))?;
+            assert_eq!(actual, expected);
+
+            let mut buf = Vec::new();
+            reader.read_to_end(&mut buf)?;
+            assert_eq!(buf, data);
+
+            Ok(())
+        }
+
+        let data = b"noodles";
+
+        let mut writer = crate::Writer::new(Vec::new());
+        writer.write_all(data)?;
+        let bgzf_data = writer.finish()?;
+
+        let mut writer = GzEncoder::new(Vec::new(), flate2::Compression::default());
+        writer.write_all(data)?;
+        let gzip_data = writer.finish()?;
+
+        t(&bgzf_data, Some(Compression::Bgzf))?;
+        t(&gzip_data, Some(Compression::Gzip))?;
+        t(data, None)?;
+        t(&[], None)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_bgzf() {
+        assert!(is_bgzf(&[
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1b, 0x00,
+        ]));
+
+        // gzip without an extra field
+        assert!(!is_bgzf(&[
+            0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+        ]));
+
+        assert!(!is_bgzf(b"##fileformat=VCFv4.3\n"));
+        assert!(!is_bgzf(&[]));
+    }
+}
@@ -38,12 +38,18 @@
 mod r#async;
 
 mod block;
+mod detect;
 mod gz;
-mod reader;
+pub mod reader;
 pub mod virtual_position;
 pub mod writer;
 
-pub use self::{reader::Reader, virtual_position::VirtualPosition, writer::Writer};
+pub use self::{
+    detect::{detect_compression, Compression},
+    reader::Reader,
+    virtual_position::VirtualPosition,
+    writer::Writer,
+};
 
 #[cfg(feature = "async")]
 pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
@@ -1,3 +1,9 @@
+//! BGZF reader.
+
+mod take_until;
+
+pub use self::take_until::TakeUntil;
+
 use std::{
     cmp,
     io::{self, BufRead, Read, Seek, SeekFrom},
@@ -125,6 +131,31 @@ where
     pub fn virtual_position(&self) -> VirtualPosition {
         self.block.virtual_position()
     }
+
+    /// Creates an adapter that reads until the given virtual position.
+    ///
+    /// The adapter reads from the current virtual position of the reader up to but not including
+    /// the end virtual position. Blocks that start at or after the end are not read.
+    ///
+    /// This is typically used with [`Self::seek`] to read the uncompressed data of an index
+    /// chunk.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Read};
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let mut reader = bgzf::Reader::new(&data[..]);
+    ///
+    /// let mut buf = Vec::new();
+    /// reader.take_until(bgzf::VirtualPosition::from(0)).read_to_end(&mut buf)?;
+    /// assert!(buf.is_empty());
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn take_until(&mut self, end: VirtualPosition) -> TakeUntil<'_, R> {
+        TakeUntil::new(self, end)
+    }
 }
 
 impl<R> Reader<R>
@@ -423,6 +454,50 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_take_until() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Write;
+
+        let mut writer = crate::Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        writer.write_all(b"-")?;
+        writer.flush()?;
+        writer.write_all(b"bgzf")?;
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(Cursor::new(data));
+
+        let mut buf = [0; 8];
+        reader.read_exact(&mut buf)?;
+        let block_2_start = reader.virtual_position();
+        assert_eq!(block_2_start.uncompressed(), 0);
+
+        let start = VirtualPosition::try_from((0, 3))?;
+        let end = VirtualPosition::try_from((block_2_start.compressed(), 2))?;
+
+        reader.seek(start)?;
+
+        let mut take_until = reader.take_until(end);
+        let mut buf = Vec::new();
+        take_until.read_to_end(&mut buf)?;
+
+        assert_eq!(buf, b"dles-bg");
+        assert!(take_until.is_done());
+        assert_eq!(reader.virtual_position(), end);
+
+        // The end is at the start of a block.
+        reader.seek(start)?;
+
+        buf.clear();
+        reader.take_until(block_2_start).read_to_end(&mut buf)?;
+
+        assert_eq!(buf, b"dles-");
+        assert_eq!(reader.virtual_position(), block_2_start);
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_header() -> io::Result<()> {
         let mut reader = BGZF_EOF;
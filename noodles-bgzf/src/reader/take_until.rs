@@ -0,0 +1,85 @@
+use std::{
+    cmp,
+    io::{self, BufRead, Read},
+};
+
+use super::Reader;
+use crate::VirtualPosition;
+
+/// A BGZF reader adapter that stops reading at a virtual position.
+///
+/// This is created by calling [`Reader::take_until`].
+pub struct TakeUntil<'r, R> {
+    reader: &'r mut Reader<R>,
+    end: VirtualPosition,
+}
+
+impl<'r, R> TakeUntil<'r, R>
+where
+    R: Read,
+{
+    pub(super) fn new(reader: &'r mut Reader<R>, end: VirtualPosition) -> Self {
+        Self { reader, end }
+    }
+
+    /// Returns the end virtual position.
+    pub fn end(&self) -> VirtualPosition {
+        self.end
+    }
+
+    /// Returns whether the reader reached the end virtual position.
+    pub fn is_done(&self) -> bool {
+        self.reader.virtual_position() >= self.end
+    }
+}
+
+impl<'r, R> Read for TakeUntil<'r, R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let bytes_read = {
+            let mut src = self.fill_buf()?;
+            src.read(buf)?
+        };
+
+        self.consume(bytes_read);
+
+        Ok(bytes_read)
+    }
+}
+
+impl<'r, R> BufRead for TakeUntil<'r, R>
+where
+    R: Read,
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        // The virtual position of an exhausted block is the start of the next block, so the next
+        // block is not read if it starts at or after the end.
+        if self.is_done() {
+            return Ok(&[]);
+        }
+
+        self.reader.fill_buf()?;
+
+        let virtual_position = self.reader.virtual_position();
+        let buf = self.reader.block.buffer();
+
+        // Blocks before the end block are read in full. The end block is read up to the
+        // uncompressed position of the end.
+        let len = if virtual_position >= self.end {
+            0
+        } else if virtual_position.compressed() == self.end.compressed() {
+            let n = self.end.uncompressed() - virtual_position.uncompressed();
+            cmp::min(buf.len(), usize::from(n))
+        } else {
+            buf.len()
+        };
+
+        Ok(&buf[..len])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.reader.consume(amt);
+    }
+}
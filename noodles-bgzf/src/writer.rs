@@ -70,6 +70,7 @@ where
     position: u64,
     buf: Vec<u8>,
     compression_level: CompressionLevelImpl,
+    is_finished: bool,
 }
 
 impl<W> Writer<W>
@@ -173,12 +174,15 @@ where
 
         self.buf.clear();
 
+        self.is_finished = false;
+
         Ok(())
     }
 
     /// Attempts to finish the output stream by flushing any remaining buffers.
     ///
-    /// This then appends the final BGZF EOF block.
+    /// This then appends the final BGZF EOF block. The EOF block is only written once, i.e.,
+    /// finishing an already finished stream only flushes it.
     ///
     /// # Examples
     ///
@@ -195,12 +199,17 @@ where
     pub fn try_finish(&mut self) -> io::Result<()> {
         self.flush()?;
 
+        if self.is_finished {
+            return Ok(());
+        }
+
         let inner = self.inner.as_mut().unwrap();
-        let result = inner.write_all(BGZF_EOF);
+        inner.write_all(BGZF_EOF)?;
 
         self.position += BGZF_EOF.len() as u64;
+        self.is_finished = true;
 
-        result
+        Ok(())
     }
 
     /// Returns the underlying writer after finishing the output stream.
@@ -378,4 +387,31 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_try_finish() -> io::Result<()> {
+        let mut buf = Vec::new();
+
+        {
+            let mut writer = Writer::new(&mut buf);
+            writer.write_all(b"noodles")?;
+            writer.try_finish()?;
+            writer.try_finish()?;
+            assert_eq!(writer.position() as usize, writer.get_ref().len());
+        }
+
+        assert!(buf.ends_with(BGZF_EOF));
+        assert!(!buf[..buf.len() - BGZF_EOF.len()].ends_with(BGZF_EOF));
+
+        let mut writer = Writer::new(Vec::new());
+        writer.try_finish()?;
+        writer.write_all(b"noodles")?;
+        writer.try_finish()?;
+
+        let data = writer.finish()?;
+        assert!(data.starts_with(BGZF_EOF));
+        assert!(data.ends_with(BGZF_EOF));
+
+        Ok(())
+    }
 }
@@ -54,6 +54,7 @@ where
             position: 0,
             buf: Vec::with_capacity(block::MAX_UNCOMPRESSED_DATA_LENGTH),
             compression_level: compression_level.into(),
+            is_finished: false,
         }
     }
 }
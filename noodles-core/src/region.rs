@@ -209,6 +209,51 @@ fn parse_interval(s: &str) -> Result<Interval, ParseError> {
     Ok((start, end))
 }
 
+/// Returns whether a feature overlaps an interval.
+///
+/// The feature is given by its start and end, which are both inclusive, e.g., the alignment span
+/// of a record. The interval bounds are respected as given. A feature that only touches an
+/// included bound of the interval, e.g., a feature that ends at the start of the interval, is an
+/// overlap.
+///
+/// This is used to filter records read from an indexed query, as index bins only approximate
+/// record positions.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::{region, Position};
+///
+/// let start = Position::try_from(5)?;
+/// let end = Position::try_from(8)?;
+///
+/// let interval = Position::try_from(8)?..=Position::try_from(13)?;
+/// assert!(region::overlaps(start, end, interval));
+///
+/// let interval = Position::try_from(9)?..=Position::try_from(13)?;
+/// assert!(!region::overlaps(start, end, interval));
+/// # Ok::<_, noodles_core::position::TryFromIntError>(())
+/// ```
+pub fn overlaps<T, B>(start: T, end: T, interval: B) -> bool
+where
+    T: PartialOrd,
+    B: RangeBounds<T>,
+{
+    let a = match interval.start_bound() {
+        Bound::Included(s) => *s <= end,
+        Bound::Excluded(s) => *s < end,
+        Bound::Unbounded => true,
+    };
+
+    let b = match interval.end_bound() {
+        Bound::Included(e) => start <= *e,
+        Bound::Excluded(e) => start < *e,
+        Bound::Unbounded => true,
+    };
+
+    a && b
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -241,4 +286,57 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_overlaps() -> Result<(), crate::position::TryFromIntError> {
+        fn p(n: usize) -> Result<Position, crate::position::TryFromIntError> {
+            Position::try_from(n)
+        }
+
+        let start = p(5)?;
+        let end = p(8)?;
+
+        assert!(overlaps(start, end, p(4)?..p(7)?));
+        assert!(overlaps(start, end, p(4)?..=p(6)?));
+
+        assert!(overlaps(start, end, p(6)?..p(8)?));
+        assert!(overlaps(start, end, p(6)?..=p(7)?));
+
+        assert!(overlaps(start, end, p(7)?..p(10)?));
+        assert!(overlaps(start, end, p(7)?..=p(9)?));
+
+        assert!(overlaps(start, end, p(4)?..p(10)?));
+        assert!(overlaps(start, end, p(4)?..=p(9)?));
+
+        assert!(overlaps(start, end, p(4)?..));
+        assert!(overlaps(start, end, ..));
+
+        assert!(overlaps(start, end, ..p(6)?));
+        assert!(overlaps(start, end, ..p(10)?));
+
+        assert!(overlaps(start, end, ..=p(5)?));
+        assert!(overlaps(start, end, ..=p(9)?));
+
+        assert!(!overlaps(start, end, p(2)?..p(5)?));
+        assert!(!overlaps(start, end, p(2)?..=p(4)?));
+
+        assert!(!overlaps(start, end, p(9)?..p(12)?));
+        assert!(!overlaps(start, end, p(9)?..=p(11)?));
+
+        assert!(!overlaps(start, end, p(9)?..));
+
+        assert!(!overlaps(start, end, ..p(5)?));
+        assert!(!overlaps(start, end, ..=p(4)?));
+
+        // The feature touches the interval.
+        assert!(overlaps(start, end, p(8)?..=p(13)?));
+        assert!(overlaps(start, end, p(1)?..=p(5)?));
+        assert!(!overlaps(
+            start,
+            end,
+            (Bound::Excluded(p(8)?), Bound::Unbounded)
+        ));
+
+        Ok(())
+    }
 }
@@ -5,12 +5,14 @@ mod num;
 use bytes::BytesMut;
 use tokio::io::{self, AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, SeekFrom};
 
-use crate::{file_definition::Version, DataContainer, FileDefinition};
+use crate::{file_definition::Version, reader::validate_version, DataContainer, FileDefinition};
 
 /// An async CRAM reader.
 pub struct Reader<R> {
     inner: R,
     buf: BytesMut,
+    version: Version,
+    allow_zstd_compression: bool,
 }
 
 impl<R> Reader<R>
@@ -30,15 +32,52 @@ where
         Self {
             inner,
             buf: BytesMut::new(),
+            version: Version::default(),
+            allow_zstd_compression: false,
         }
     }
 
+    /// Returns whether blocks compressed with zstd are accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let data = [];
+    /// let reader = cram::AsyncReader::new(&data[..]);
+    /// assert!(!reader.allow_zstd_compression());
+    /// ```
+    pub fn allow_zstd_compression(&self) -> bool {
+        self.allow_zstd_compression
+    }
+
+    /// Sets whether blocks compressed with zstd are accepted.
+    ///
+    /// zstd (method ID 9) is not part of the CRAM specification, so blocks that use it are
+    /// rejected by default. See [`crate::Reader::set_allow_zstd_compression`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let data = [];
+    /// let mut reader = cram::AsyncReader::new(&data[..]);
+    /// reader.set_allow_zstd_compression(true);
+    /// assert!(reader.allow_zstd_compression());
+    /// ```
+    pub fn set_allow_zstd_compression(&mut self, allow_zstd_compression: bool) {
+        self.allow_zstd_compression = allow_zstd_compression;
+    }
+
     /// Reads the CRAM file definition.
     ///
     /// This also checks the magic number.
     ///
     /// The position of the stream is expected to be at the start.
     ///
+    /// The file definition version determines how the subsequent containers are read. If this is
+    /// not called, CRAM 3.0 is assumed. This returns an error if the version is not supported.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -57,8 +96,12 @@ where
         read_magic_number(&mut self.inner).await?;
 
         let format = read_format(&mut self.inner).await?;
+        validate_version(format)?;
+
         let file_id = read_file_id(&mut self.inner).await?;
 
+        self.version = format;
+
         Ok(FileDefinition::new(format, file_id))
     }
 
@@ -70,6 +113,9 @@ where
     /// This returns the raw SAM header as a [`String`]. It can subsequently be parsed as a
     /// [`noodles_sam::Header`].
     ///
+    /// If the header container has no blocks or the SAM header block is empty, this returns an
+    /// empty string, which parses as an empty SAM header.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -91,15 +137,18 @@ where
         use self::container::read_container;
         use crate::reader::read_file_header_block;
 
-        let container = read_container(&mut self.inner, &mut self.buf).await?;
+        let container = read_container(
+            &mut self.inner,
+            &mut self.buf,
+            self.version,
+            self.allow_zstd_compression,
+        )
+        .await?;
 
         if let Some(block) = container.blocks().first() {
             read_file_header_block(block)
         } else {
-            Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                "invalid header container: missing block for SAM header",
-            ))
+            Ok(String::new())
         }
     }
 
@@ -131,7 +180,13 @@ where
     pub async fn read_data_container(&mut self) -> io::Result<Option<DataContainer>> {
         use self::data_container::read_data_container;
 
-        read_data_container(&mut self.inner, &mut self.buf).await
+        read_data_container(
+            &mut self.inner,
+            &mut self.buf,
+            self.version,
+            self.allow_zstd_compression,
+        )
+        .await
     }
 }
 
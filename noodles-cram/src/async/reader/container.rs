@@ -5,13 +5,18 @@ pub use self::header::read_header;
 use bytes::BytesMut;
 use tokio::io::{self, AsyncRead, AsyncReadExt};
 
-use crate::{reader::container::read_block, Container};
-
-pub async fn read_container<R>(reader: &mut R, buf: &mut BytesMut) -> io::Result<Container>
+use crate::{file_definition::Version, reader::container::read_block, Container};
+
+pub async fn read_container<R>(
+    reader: &mut R,
+    buf: &mut BytesMut,
+    version: Version,
+    allow_zstd: bool,
+) -> io::Result<Container>
 where
     R: AsyncRead + Unpin,
 {
-    let header = read_header(reader).await?;
+    let header = read_header(reader, version).await?;
 
     buf.resize(header.len(), 0);
     reader.read_exact(buf).await?;
@@ -21,7 +26,7 @@ where
     let mut blocks = Vec::with_capacity(blocks_len);
 
     for _ in 0..blocks_len {
-        let block = read_block(&mut buf)?;
+        let block = read_block(&mut buf, version, allow_zstd)?;
         blocks.push(block);
     }
 
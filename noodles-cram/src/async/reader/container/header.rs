@@ -3,10 +3,11 @@ use tokio::io::{self, AsyncRead, AsyncReadExt};
 
 use crate::{
     container::{self, ReferenceSequenceId},
+    file_definition::Version,
     r#async::reader::num::{read_itf8, read_ltf8},
 };
 
-pub async fn read_header<R>(reader: &mut R) -> io::Result<container::Header>
+pub async fn read_header<R>(reader: &mut R, version: Version) -> io::Result<container::Header>
 where
     R: AsyncRead + Unpin,
 {
@@ -36,7 +37,13 @@ where
     })?;
 
     let landmarks = read_landmarks(reader).await?;
-    let crc32 = reader.read_u32_le().await?;
+
+    // CRAM 2.x container headers do not have a CRC32 checksum.
+    let crc32 = if version.major() >= 3 {
+        reader.read_u32_le().await?
+    } else {
+        0
+    };
 
     let mut builder = container::Header::builder()
         .set_length(length)
@@ -99,7 +106,7 @@ mod tests {
         ];
 
         let mut reader = &data[..];
-        let actual = read_header(&mut reader).await?;
+        let actual = read_header(&mut reader, Version::default()).await?;
 
         let expected = container::Header::builder()
             .set_length(144)
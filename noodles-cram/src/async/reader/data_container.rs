@@ -3,17 +3,20 @@ use tokio::io::{self, AsyncRead, AsyncReadExt};
 
 use crate::{
     data_container::DataContainer,
+    file_definition::Version,
     reader::data_container::{read_compression_header_from_block, read_slice},
 };
 
 pub async fn read_data_container<R>(
     reader: &mut R,
     buf: &mut BytesMut,
+    version: Version,
+    allow_zstd: bool,
 ) -> io::Result<Option<DataContainer>>
 where
     R: AsyncRead + Unpin,
 {
-    let header = super::container::read_header(reader).await?;
+    let header = super::container::read_header(reader, version).await?;
 
     if header.is_eof() {
         return Ok(None);
@@ -23,13 +26,13 @@ where
     reader.read_exact(buf).await?;
     let mut buf = buf.split().freeze();
 
-    let compression_header = read_compression_header_from_block(&mut buf)?;
+    let compression_header = read_compression_header_from_block(&mut buf, version, allow_zstd)?;
 
     let slice_count = header.landmarks().len();
     let mut slices = Vec::with_capacity(slice_count);
 
     for _ in 0..slice_count {
-        let slice = read_slice(&mut buf)?;
+        let slice = read_slice(&mut buf, version, allow_zstd)?;
         slices.push(slice);
     }
 
@@ -61,4 +61,44 @@ mod tests {
         assert_eq!(reader.read_u32(6)?, 0b110100);
         Ok(())
     }
+
+    #[test]
+    fn test_read_u32_with_bit_writer_round_trip() -> io::Result<()> {
+        use crate::BitWriter;
+
+        let values = [(0b1, 1), (0x1ff, 9), (0b0, 3), (0xabcd, 16), (0b10, 2)];
+
+        let mut writer = BitWriter::new(Vec::new());
+
+        for &(value, len) in &values {
+            writer.write_u32(value, len)?;
+        }
+
+        let data = writer.finish()?;
+        let mut reader = BitReader::new(&data[..]);
+
+        for &(value, len) in &values {
+            assert_eq!(reader.read_u32(len as u32)?, value);
+        }
+
+        // The remaining bits are padding.
+        assert_eq!(reader.read_u32(1)?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_u32_with_eof() -> io::Result<()> {
+        let data = [0x00];
+        let mut reader = BitReader::new(&data[..]);
+
+        assert_eq!(reader.read_u32(8)?, 0);
+
+        assert!(matches!(
+            reader.read_u32(1),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
 }
@@ -21,6 +21,9 @@ where
         }
     }
 
+    /// Pads the final partial byte, if any, with zeros.
+    ///
+    /// After this, the writer is byte-aligned.
     pub fn try_finish(&mut self) -> io::Result<()> {
         if self.i > 0 {
             self.write_u32(0, 8 - self.i)
@@ -29,6 +32,7 @@ where
         }
     }
 
+    /// Byte-aligns the stream and returns the underlying writer.
     pub fn finish(mut self) -> io::Result<W> {
         self.try_finish()?;
         Ok(self.inner)
@@ -87,6 +91,24 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_finish() -> io::Result<()> {
+        let mut writer = BitWriter::new(Vec::new());
+        writer.write_u32(0b101, 3)?;
+        writer.write_u32(0b1, 1)?;
+        assert_eq!(writer.finish()?, [0b10110000]);
+
+        let mut writer = BitWriter::new(Vec::new());
+        writer.write_u32(0b1010, 4)?;
+        writer.write_u32(0b0101, 4)?;
+        assert_eq!(writer.finish()?, [0b10100101]);
+
+        let writer = BitWriter::new(Vec::new());
+        assert!(writer.finish()?.is_empty());
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_u32_with_0_len() -> io::Result<()> {
         let mut writer = BitWriter::new(Vec::new());
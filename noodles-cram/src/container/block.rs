@@ -117,6 +117,7 @@ impl Block {
                 let data: Vec<_> = names.into_iter().flat_map(|s| s.into_bytes()).collect();
                 Ok(Bytes::from(data))
             }
+            CompressionMethod::Zstd => zstd_decode(self.data()).map(Bytes::from),
         }
     }
 
@@ -134,6 +135,19 @@ impl Block {
     }
 }
 
+#[cfg(feature = "zstd")]
+fn zstd_decode(src: &[u8]) -> io::Result<Vec<u8>> {
+    zstd::stream::decode_all(src)
+}
+
+#[cfg(not(feature = "zstd"))]
+fn zstd_decode(_: &[u8]) -> io::Result<Vec<u8>> {
+    Err(io::Error::new(
+        io::ErrorKind::InvalidData,
+        "zstd block decompression requires the zstd feature",
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -150,4 +164,30 @@ mod tests {
 
         assert_eq!(block.len(), 16);
     }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_decompressed_data_with_zstd() -> io::Result<()> {
+        // xorshift32
+        let mut state = 0x6e6f6f64u32;
+        let data: Vec<u8> = (0..8192)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state as u8
+            })
+            .collect();
+
+        let block = Block::builder()
+            .set_content_type(ContentType::ExternalData)
+            .compress_and_set_data(data.clone(), CompressionMethod::Zstd)?
+            .build();
+
+        assert_eq!(block.compression_method(), CompressionMethod::Zstd);
+        assert_eq!(block.uncompressed_len(), data.len());
+        assert_eq!(&block.decompressed_data()?[..], &data[..]);
+
+        Ok(())
+    }
 }
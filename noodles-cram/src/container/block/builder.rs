@@ -8,6 +8,8 @@ use flate2::write::GzEncoder;
 use xz2::write::XzEncoder;
 
 const DEFAULT_LZMA_COMPRESSION_LEVEL: u32 = 6;
+#[cfg(feature = "zstd")]
+const DEFAULT_ZSTD_COMPRESSION_LEVEL: i32 = 0;
 
 #[derive(Debug, Default)]
 pub struct Builder {
@@ -79,6 +81,7 @@ impl Builder {
                 encoder.write_all(&data)?;
                 encoder.finish()?
             }
+            CompressionMethod::Zstd => zstd_encode(&data)?,
             _ => unimplemented!(
                 "compress_and_set_data: unhandled compression method: {:?}",
                 compression_method
@@ -101,3 +104,16 @@ impl Builder {
         }
     }
 }
+
+#[cfg(feature = "zstd")]
+fn zstd_encode(src: &[u8]) -> io::Result<Vec<u8>> {
+    zstd::stream::encode_all(src, DEFAULT_ZSTD_COMPRESSION_LEVEL)
+}
+
+#[cfg(not(feature = "zstd"))]
+fn zstd_encode(_: &[u8]) -> io::Result<Vec<u8>> {
+    Err(io::Error::new(
+        io::ErrorKind::InvalidInput,
+        "zstd block compression requires the zstd feature",
+    ))
+}
@@ -26,6 +26,14 @@ pub enum CompressionMethod {
     Fqzcomp,
     /// Name tokenization codec.
     NameTokenizer,
+    /// Zstandard (zstd).
+    ///
+    /// This is not part of the CRAM specification. It is assigned the next unused method ID (9),
+    /// and blocks compressed with it cannot be read by other CRAM implementations. It is only
+    /// written when enabled with [`crate::writer::Builder::allow_zstd_compression`] and only read
+    /// when enabled with [`crate::Reader::set_allow_zstd_compression`]. Compressing and
+    /// decompressing zstd blocks requires the `zstd` feature.
+    Zstd,
 }
 
 impl Default for CompressionMethod {
@@ -43,7 +51,7 @@ impl fmt::Display for TryFromByteError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "invalid compression method: expected 0..=8, got {}",
+            "invalid compression method: expected 0..=9, got {}",
             self.0
         )
     }
@@ -63,6 +71,7 @@ impl TryFrom<u8> for CompressionMethod {
             6 => Ok(Self::AdaptiveArithmeticCoding),
             7 => Ok(Self::Fqzcomp),
             8 => Ok(Self::NameTokenizer),
+            9 => Ok(Self::Zstd),
             _ => Err(TryFromByteError(b)),
         }
     }
@@ -80,6 +89,7 @@ impl From<CompressionMethod> for u8 {
             CompressionMethod::AdaptiveArithmeticCoding => 6,
             CompressionMethod::Fqzcomp => 7,
             CompressionMethod::NameTokenizer => 8,
+            CompressionMethod::Zstd => 9,
         }
     }
 }
@@ -119,7 +129,8 @@ mod tests {
             CompressionMethod::try_from(8),
             Ok(CompressionMethod::NameTokenizer)
         );
-        assert_eq!(CompressionMethod::try_from(9), Err(TryFromByteError(9)));
+        assert_eq!(CompressionMethod::try_from(9), Ok(CompressionMethod::Zstd));
+        assert_eq!(CompressionMethod::try_from(10), Err(TryFromByteError(10)));
     }
 
     #[test]
@@ -133,5 +144,6 @@ mod tests {
         assert_eq!(u8::from(CompressionMethod::AdaptiveArithmeticCoding), 6);
         assert_eq!(u8::from(CompressionMethod::Fqzcomp), 7);
         assert_eq!(u8::from(CompressionMethod::NameTokenizer), 8);
+        assert_eq!(u8::from(CompressionMethod::Zstd), 9);
     }
 }
@@ -12,6 +12,7 @@ const EOF_START_POSITION: usize = 4_542_278;
 const EOF_BLOCK_COUNT: usize = 1;
 const EOF_CRC32: u32 = 0x4f_d9_bd_05;
 
+/// A CRAM container header.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Header {
     length: usize,
@@ -28,6 +29,7 @@ pub struct Header {
 
 #[allow(clippy::len_without_is_empty)]
 impl Header {
+    /// Returns a builder to create a container header.
     pub fn builder() -> Builder {
         Builder::default()
     }
@@ -43,42 +45,52 @@ impl Header {
         }
     }
 
+    /// Returns the length of the container payload, i.e., the size of its blocks.
     pub fn len(&self) -> usize {
         self.length
     }
 
+    /// Returns the reference sequence ID of the records in the container.
     pub fn reference_sequence_id(&self) -> ReferenceSequenceId {
         self.reference_sequence_id
     }
 
+    /// Returns the alignment start position.
     pub fn start_position(&self) -> Option<Position> {
         self.start_position
     }
 
+    /// Returns the alignment span.
     pub fn alignment_span(&self) -> usize {
         self.alignment_span
     }
 
+    /// Returns the number of records in the container.
     pub fn record_count(&self) -> i32 {
         self.record_count
     }
 
+    /// Returns the 0-based index of the first record in the container.
     pub fn record_counter(&self) -> i64 {
         self.record_counter
     }
 
+    /// Returns the number of read bases in the container.
     pub fn base_count(&self) -> i64 {
         self.base_count
     }
 
+    /// Returns the number of blocks in the container.
     pub fn block_count(&self) -> usize {
         self.block_count
     }
 
+    /// Returns the byte offsets of the slices, relative to the start of the container payload.
     pub fn landmarks(&self) -> &[usize] {
         &self.landmarks
     }
 
+    /// Returns whether this is the EOF container header.
     pub fn is_eof(&self) -> bool {
         self.length == EOF_LEN
             && self.reference_sequence_id.is_none()
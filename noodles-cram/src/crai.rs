@@ -12,7 +12,7 @@ pub use self::{reader::Reader, record::Record, writer::Writer};
 #[cfg(feature = "async")]
 pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
 
-use std::{fs::File, io, path::Path};
+use std::{cmp, fs::File, io, path::Path};
 
 /// A CRAM index.
 pub type Index = Vec<Record>;
@@ -56,6 +56,172 @@ pub fn write<P>(dst: P, index: &[Record]) -> io::Result<()>
 where
     P: AsRef<Path>,
 {
-    let mut writer = File::open(dst).map(Writer::new)?;
-    writer.write_index(index)
+    let mut writer = File::create(dst).map(Writer::new)?;
+    writer.write_index(index)?;
+    writer.finish()?;
+    Ok(())
+}
+
+/// Sorts a CRAM index by reference sequence ID and alignment start.
+///
+/// Records with no reference sequence ID (unmapped, written as `-1`) are sorted last. Ties are
+/// ordered by container offset and slice landmark.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_cram::crai;
+///
+/// let mut index = vec![
+///     crai::Record::new(None, None, 0, 283, 0, 21),
+///     crai::Record::new(Some(0), Position::new(8), 5, 144, 0, 21),
+///     crai::Record::new(Some(0), Position::new(1), 3, 144, 0, 21),
+/// ];
+///
+/// crai::sort(&mut index);
+///
+/// assert_eq!(index[0].alignment_start(), Position::new(1));
+/// assert_eq!(index[1].alignment_start(), Position::new(8));
+/// assert!(index[2].reference_sequence_id().is_none());
+/// ```
+pub fn sort(index: &mut [Record]) {
+    index.sort_by_key(|record| {
+        (
+            record.reference_sequence_id().is_none(),
+            record.reference_sequence_id(),
+            record.alignment_start(),
+            record.offset(),
+            record.landmark(),
+        )
+    });
+}
+
+/// Merges adjacent records of a sorted CRAM index.
+///
+/// Two consecutive records are merged when they have the same reference sequence ID and container
+/// offset and the second slice directly follows the first. The merged record covers the union of
+/// both alignment intervals and both slices. Queries using a merged record read every slice in
+/// its slice range (see [`crate::Reader::query`]).
+///
+/// The index is expected to be sorted (see [`sort`]).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_cram::crai;
+///
+/// let index = vec![
+///     crai::Record::new(Some(0), Position::new(1), 5, 144, 0, 21),
+///     crai::Record::new(Some(0), Position::new(3), 8, 144, 21, 34),
+/// ];
+///
+/// let index = crai::merge(index);
+///
+/// assert_eq!(
+///     index,
+///     [crai::Record::new(Some(0), Position::new(1), 10, 144, 0, 55)]
+/// );
+/// ```
+pub fn merge(index: Index) -> Index {
+    let mut merged: Index = Vec::with_capacity(index.len());
+
+    for record in index {
+        if let Some(prev) = merged.last_mut() {
+            if let Some(r) = try_merge(prev, &record) {
+                *prev = r;
+                continue;
+            }
+        }
+
+        merged.push(record);
+    }
+
+    merged
+}
+
+fn try_merge(a: &Record, b: &Record) -> Option<Record> {
+    if a.reference_sequence_id() != b.reference_sequence_id()
+        || a.offset() != b.offset()
+        || a.landmark() + a.slice_length() != b.landmark()
+    {
+        return None;
+    }
+
+    let (alignment_start, alignment_span) = match (a.alignment_start(), b.alignment_start()) {
+        (Some(a_start), Some(b_start)) => {
+            let start = cmp::min(a_start, b_start);
+
+            let a_end = usize::from(a_start) + a.alignment_span();
+            let b_end = usize::from(b_start) + b.alignment_span();
+            let end = cmp::max(a_end, b_end);
+
+            (Some(start), end - usize::from(start))
+        }
+        (None, None) => (None, 0),
+        _ => return None,
+    };
+
+    Some(Record::new(
+        a.reference_sequence_id(),
+        alignment_start,
+        alignment_span,
+        a.offset(),
+        a.landmark(),
+        a.slice_length() + b.slice_length(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+
+    #[test]
+    fn test_sort() {
+        let mut index = vec![
+            Record::new(None, None, 0, 987, 0, 34),
+            Record::new(Some(1), Position::new(5), 8, 610, 0, 21),
+            Record::new(Some(0), Position::new(13), 21, 377, 0, 21),
+            Record::new(Some(0), Position::new(2), 3, 233, 0, 21),
+            Record::new(Some(0), Position::new(2), 3, 144, 21, 21),
+            Record::new(Some(0), Position::new(2), 3, 144, 0, 21),
+        ];
+
+        sort(&mut index);
+
+        let expected = [
+            Record::new(Some(0), Position::new(2), 3, 144, 0, 21),
+            Record::new(Some(0), Position::new(2), 3, 144, 21, 21),
+            Record::new(Some(0), Position::new(2), 3, 233, 0, 21),
+            Record::new(Some(0), Position::new(13), 21, 377, 0, 21),
+            Record::new(Some(1), Position::new(5), 8, 610, 0, 21),
+            Record::new(None, None, 0, 987, 0, 34),
+        ];
+
+        assert_eq!(index, expected);
+    }
+
+    #[test]
+    fn test_merge() {
+        let index = vec![
+            Record::new(Some(0), Position::new(2), 3, 144, 0, 21),
+            Record::new(Some(0), Position::new(4), 8, 144, 21, 34),
+            Record::new(Some(0), Position::new(13), 21, 233, 0, 21),
+            Record::new(Some(1), Position::new(5), 8, 233, 21, 21),
+            Record::new(None, None, 0, 377, 0, 34),
+            Record::new(None, None, 0, 377, 34, 55),
+        ];
+
+        let expected = [
+            Record::new(Some(0), Position::new(2), 10, 144, 0, 55),
+            Record::new(Some(0), Position::new(13), 21, 233, 0, 21),
+            Record::new(Some(1), Position::new(5), 8, 233, 21, 21),
+            Record::new(None, None, 0, 377, 0, 89),
+        ];
+
+        assert_eq!(merge(index), expected);
+    }
 }
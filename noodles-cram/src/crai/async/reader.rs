@@ -60,6 +60,7 @@ where
 {
     let mut index = Vec::new();
     let mut buf = String::new();
+    let mut line_number = 0;
 
     loop {
         buf.clear();
@@ -67,9 +68,14 @@ where
         match read_line(reader, &mut buf).await {
             Ok(0) => break,
             Ok(_) => {
-                let record = buf
-                    .parse()
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                line_number += 1;
+
+                let record = buf.parse().map_err(|e| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("invalid record at line {}: {}", line_number, e),
+                    )
+                })?;
 
                 index.push(record);
             }
@@ -44,6 +44,7 @@ where
     pub fn read_index(&mut self) -> io::Result<Index> {
         let mut buf = String::new();
         let mut index = Vec::new();
+        let mut line_number = 0;
 
         loop {
             buf.clear();
@@ -51,9 +52,14 @@ where
             match read_line(&mut self.inner, &mut buf) {
                 Ok(0) => break,
                 Ok(_) => {
-                    let record = buf
-                        .parse()
-                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                    line_number += 1;
+
+                    let record = buf.parse().map_err(|e| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("invalid record at line {}: {}", line_number, e),
+                        )
+                    })?;
 
                     index.push(record);
                 }
@@ -69,10 +75,15 @@ fn read_line<R>(reader: &mut R, buf: &mut String) -> io::Result<usize>
 where
     R: BufRead,
 {
+    const LINE_FEED: char = '\n';
+
     match reader.read_line(buf) {
         Ok(0) => Ok(0),
         Ok(n) => {
-            buf.pop();
+            if buf.ends_with(LINE_FEED) {
+                buf.pop();
+            }
+
             Ok(n)
         }
         Err(e) => Err(e),
@@ -114,4 +125,50 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_index_without_final_line_feed() -> Result<(), Box<dyn std::error::Error>> {
+        let data = b"0\t10946\t6765\t17711\t233\t317811";
+
+        let mut writer = GzEncoder::new(Vec::new(), Default::default());
+        writer.write_all(data)?;
+        let compressed_data = writer.finish()?;
+
+        let mut reader = Reader::new(&compressed_data[..]);
+        let actual = reader.read_index()?;
+
+        let expected = vec![Record::new(
+            Some(0),
+            Position::new(10946),
+            6765,
+            17711,
+            233,
+            317811,
+        )];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_index_with_invalid_record() -> Result<(), Box<dyn std::error::Error>> {
+        let data = b"\
+0\t10946\t6765\t17711\t233\t317811
+0\t17711\tnoodles\t317811\t233\t317811
+";
+
+        let mut writer = GzEncoder::new(Vec::new(), Default::default());
+        writer.write_all(data)?;
+        let compressed_data = writer.finish()?;
+
+        let mut reader = Reader::new(&compressed_data[..]);
+
+        assert!(matches!(
+            reader.read_index(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData && e.to_string().starts_with("invalid record at line 2")
+        ));
+
+        Ok(())
+    }
 }
@@ -4,7 +4,10 @@ pub(crate) mod builder;
 pub(crate) mod compression_header;
 pub(crate) mod slice;
 
-pub use self::{compression_header::CompressionHeader, slice::Slice};
+pub use self::{
+    compression_header::{data_series_encoding_map::DataSeries, CompressionHeader},
+    slice::Slice,
+};
 
 pub(crate) use self::builder::Builder;
 
@@ -1,12 +1,17 @@
-use std::{io, mem};
+use std::{cmp, io, iter, mem, panic, sync::Arc, thread};
 
 use noodles_fasta as fasta;
 use noodles_sam as sam;
 
-use super::{slice, CompressionHeader, DataContainer, Slice};
-use crate::{writer::Options, Record};
-
-const MAX_SLICE_COUNT: usize = 1;
+use super::{
+    slice::{self, builder::EncodedSlice},
+    CompressionHeader, DataContainer, Slice,
+};
+use crate::{
+    container::block::CompressionMethod,
+    writer::{Options, Statistics},
+    Record,
+};
 
 #[derive(Debug)]
 pub struct Builder {
@@ -40,12 +45,12 @@ impl Builder {
         self.base_count
     }
 
-    pub fn add_record(&mut self, record: Record) -> Result<(), AddRecordError> {
-        if self.slice_builders.len() >= MAX_SLICE_COUNT {
+    pub fn add_record(&mut self, options: &Options, record: Record) -> Result<(), AddRecordError> {
+        if self.slice_builders.len() >= options.slices_per_container {
             return Err(AddRecordError::ContainerFull(record));
         }
 
-        match self.slice_builder.add_record(record) {
+        match self.slice_builder.add_record(options, record) {
             Ok(r) => {
                 self.base_count += r.read_length() as i64;
                 Ok(())
@@ -56,6 +61,8 @@ impl Builder {
                     self.slice_builders.push(slice_builder);
                     Err(AddRecordError::SliceFull(r))
                 }
+                // A mismatch only occurs when multi-reference slices are not allowed, in which case
+                // the record starts a new container.
                 slice::builder::AddRecordError::ReferenceSequenceIdMismatch(r) => {
                     Err(AddRecordError::ContainerFull(r))
                 }
@@ -63,6 +70,28 @@ impl Builder {
         }
     }
 
+    /// Estimates the number of bytes each data series and tag would be written as if the container
+    /// were built now.
+    ///
+    /// This uses the same compression header as [`Self::build`] but does not build any blocks.
+    /// Records are encoded in the order they were added.
+    pub fn estimate(&self, options: &Options) -> io::Result<Statistics> {
+        let slice_builders = self
+            .slice_builders
+            .iter()
+            .chain(iter::once(&self.slice_builder));
+
+        let compression_header = build_compression_header(options, slice_builders.clone());
+
+        let mut statistics = Statistics::default();
+
+        for slice_builder in slice_builders {
+            slice_builder.estimate(&compression_header, &mut statistics)?;
+        }
+
+        Ok(statistics)
+    }
+
     pub fn build(
         mut self,
         options: &Options,
@@ -73,21 +102,45 @@ impl Builder {
             self.slice_builders.push(self.slice_builder);
         }
 
+        if options.sort_records_by_alignment_start {
+            for slice_builder in &mut self.slice_builders {
+                slice_builder.sort_records_by_alignment_start();
+            }
+        }
+
         let compression_header = build_compression_header(options, &self.slice_builders);
 
-        let record_counter = self.record_counter;
-        let slices = self
-            .slice_builders
-            .into_iter()
-            .map(|builder| {
-                builder.build(
-                    reference_sequence_repository,
-                    header,
-                    &compression_header,
-                    record_counter,
-                )
-            })
-            .collect::<Result<_, _>>()?;
+        let block_compression_method = if options.allow_zstd_compression {
+            CompressionMethod::Zstd
+        } else {
+            CompressionMethod::Gzip
+        };
+
+        let encoded_slices = if options.worker_count > 1 && self.slice_builders.len() > 1 {
+            encode_slices_concurrently(
+                options.worker_count,
+                &compression_header,
+                block_compression_method,
+                self.slice_builders,
+            )?
+        } else {
+            self.slice_builders
+                .into_iter()
+                .map(|builder| builder.encode(&compression_header, block_compression_method))
+                .collect::<io::Result<Vec<_>>>()?
+        };
+
+        // Each slice continues the record counter from the end of the previous slice.
+        let mut record_counter = self.record_counter;
+        let mut slices = Vec::with_capacity(encoded_slices.len());
+
+        for encoded_slice in encoded_slices {
+            let record_count = encoded_slice.record_count() as i64;
+            let slice =
+                encoded_slice.finish(reference_sequence_repository, header, record_counter)?;
+            slices.push(slice);
+            record_counter += record_count;
+        }
 
         Ok(DataContainer {
             compression_header,
@@ -96,13 +149,25 @@ impl Builder {
     }
 }
 
-fn build_compression_header(
-    options: &Options,
-    slice_builders: &[slice::Builder],
-) -> CompressionHeader {
+fn build_compression_header<'a, I>(options: &Options, slice_builders: I) -> CompressionHeader
+where
+    I: IntoIterator<Item = &'a slice::Builder>,
+    I::IntoIter: Clone,
+{
+    let slice_builders = slice_builders.into_iter();
+
     let mut compression_header_builder = CompressionHeader::builder();
     compression_header_builder.apply_options(options);
 
+    // Alignment starts in multi-reference slices are not relative to a single reference sequence,
+    // so they are always written as absolute positions.
+    if slice_builders
+        .clone()
+        .any(|slice_builder| slice_builder.is_multi_reference())
+    {
+        compression_header_builder.set_ap_data_series_delta(false);
+    }
+
     for slice_builder in slice_builders {
         for record in slice_builder.records() {
             compression_header_builder.update(record);
@@ -111,3 +176,45 @@ fn build_compression_header(
 
     compression_header_builder.build()
 }
+
+// Slices are split into contiguous chunks, one per worker, so the results can be concatenated in
+// worker order to preserve the order of the slices.
+fn encode_slices_concurrently(
+    worker_count: usize,
+    compression_header: &CompressionHeader,
+    block_compression_method: CompressionMethod,
+    slice_builders: Vec<slice::Builder>,
+) -> io::Result<Vec<EncodedSlice>> {
+    let slice_count = slice_builders.len();
+    let worker_count = cmp::min(worker_count, slice_count);
+    let chunk_size = (slice_count + worker_count - 1) / worker_count;
+
+    let compression_header = Arc::new(compression_header.clone());
+    let mut slice_builders = slice_builders.into_iter();
+
+    let handles: Vec<_> = (0..worker_count)
+        .map(|_| {
+            let chunk: Vec<_> = slice_builders.by_ref().take(chunk_size).collect();
+            let compression_header = Arc::clone(&compression_header);
+
+            thread::spawn(move || {
+                chunk
+                    .into_iter()
+                    .map(|builder| builder.encode(&compression_header, block_compression_method))
+                    .collect::<io::Result<Vec<_>>>()
+            })
+        })
+        .collect();
+
+    let mut encoded_slices = Vec::with_capacity(slice_count);
+
+    for handle in handles {
+        let chunk = handle
+            .join()
+            .unwrap_or_else(|payload| panic::resume_unwind(payload))?;
+
+        encoded_slices.extend(chunk);
+    }
+
+    Ok(encoded_slices)
+}
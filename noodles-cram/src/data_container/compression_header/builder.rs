@@ -1,12 +1,10 @@
-use super::{
-    data_series_encoding_map::DataSeriesEncodingMap, preservation_map, tag_encoding_map,
-    CompressionHeader,
-};
+use super::{data_series_encoding_map, preservation_map, tag_encoding_map, CompressionHeader};
 use crate::{writer::Options, Record};
 
 #[derive(Debug, Default)]
 pub struct Builder {
     preservation_map_builder: preservation_map::Builder,
+    data_series_encoding_map_selector: data_series_encoding_map::Selector,
     tag_encoding_map_builder: tag_encoding_map::Builder,
 }
 
@@ -15,14 +13,20 @@ impl Builder {
         self.preservation_map_builder.apply_options(options);
     }
 
+    pub fn set_ap_data_series_delta(&mut self, ap_data_series_delta: bool) {
+        self.preservation_map_builder
+            .set_ap_data_series_delta(ap_data_series_delta);
+    }
+
     pub fn update(&mut self, record: &Record) {
         self.preservation_map_builder.update(record);
+        self.data_series_encoding_map_selector.update(record);
         self.tag_encoding_map_builder.update(record);
     }
 
     pub fn build(self) -> CompressionHeader {
         let preservation_map = self.preservation_map_builder.build();
-        let data_series_encoding_map = DataSeriesEncodingMap::default();
+        let data_series_encoding_map = self.data_series_encoding_map_selector.build();
         let tag_encoding_map = self.tag_encoding_map_builder.build();
         CompressionHeader::new(preservation_map, data_series_encoding_map, tag_encoding_map)
     }
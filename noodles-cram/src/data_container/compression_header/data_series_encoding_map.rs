@@ -1,7 +1,8 @@
 mod builder;
 pub mod data_series;
+mod selector;
 
-pub use self::{builder::Builder, data_series::DataSeries};
+pub use self::{builder::Builder, data_series::DataSeries, selector::Selector};
 
 use super::Encoding;
 
@@ -252,6 +253,88 @@ impl DataSeriesEncodingMap {
     pub fn quality_scores_encoding(&self) -> Option<&Encoding> {
         self.quality_scores_encoding.as_ref()
     }
+
+    pub fn get(&self, data_series: DataSeries) -> Option<&Encoding> {
+        match data_series {
+            DataSeries::BamBitFlags => Some(self.bam_bit_flags_encoding()),
+            DataSeries::CramBitFlags => Some(self.cram_bit_flags_encoding()),
+            DataSeries::ReferenceId => self.reference_id_encoding(),
+            DataSeries::ReadLengths => Some(self.read_lengths_encoding()),
+            DataSeries::InSeqPositions => Some(self.in_seq_positions_encoding()),
+            DataSeries::ReadGroups => Some(self.read_groups_encoding()),
+            DataSeries::ReadNames => self.read_names_encoding(),
+            DataSeries::NextMateBitFlags => self.next_mate_bit_flags_encoding(),
+            DataSeries::NextFragmentReferenceSequenceId => {
+                self.next_fragment_reference_sequence_id_encoding()
+            }
+            DataSeries::NextMateAlignmentStart => self.next_mate_alignment_start_encoding(),
+            DataSeries::TemplateSize => self.template_size_encoding(),
+            DataSeries::DistanceToNextFragment => self.distance_to_next_fragment_encoding(),
+            DataSeries::TagIds => Some(self.tag_ids_encoding()),
+            DataSeries::NumberOfReadFeatures => self.number_of_read_features_encoding(),
+            DataSeries::ReadFeaturesCodes => self.read_features_codes_encoding(),
+            DataSeries::InReadPositions => self.in_read_positions_encoding(),
+            DataSeries::DeletionLengths => self.deletion_lengths_encoding(),
+            DataSeries::StretchesOfBases => self.stretches_of_bases_encoding(),
+            DataSeries::StretchesOfQualityScores => self.stretches_of_quality_scores_encoding(),
+            DataSeries::BaseSubstitutionCodes => self.base_substitution_codes_encoding(),
+            DataSeries::Insertion => self.insertion_encoding(),
+            DataSeries::ReferenceSkipLength => self.reference_skip_length_encoding(),
+            DataSeries::Padding => self.padding_encoding(),
+            DataSeries::HardClip => self.hard_clip_encoding(),
+            DataSeries::SoftClip => self.soft_clip_encoding(),
+            DataSeries::MappingQualities => self.mapping_qualities_encoding(),
+            DataSeries::Bases => self.bases_encoding(),
+            DataSeries::QualityScores => self.quality_scores_encoding(),
+            DataSeries::ReservedTc | DataSeries::ReservedTn => None,
+        }
+    }
+
+    pub fn insert(&mut self, data_series: DataSeries, encoding: Encoding) {
+        match data_series {
+            DataSeries::BamBitFlags => self.bam_bit_flags_encoding = encoding,
+            DataSeries::CramBitFlags => self.cram_bit_flags_encoding = encoding,
+            DataSeries::ReferenceId => self.reference_id_encoding = Some(encoding),
+            DataSeries::ReadLengths => self.read_lengths_encoding = encoding,
+            DataSeries::InSeqPositions => self.in_seq_positions_encoding = encoding,
+            DataSeries::ReadGroups => self.read_groups_encoding = encoding,
+            DataSeries::ReadNames => self.read_names_encoding = Some(encoding),
+            DataSeries::NextMateBitFlags => self.next_mate_bit_flags_encoding = Some(encoding),
+            DataSeries::NextFragmentReferenceSequenceId => {
+                self.next_fragment_reference_sequence_id_encoding = Some(encoding)
+            }
+            DataSeries::NextMateAlignmentStart => {
+                self.next_mate_alignment_start_encoding = Some(encoding)
+            }
+            DataSeries::TemplateSize => self.template_size_encoding = Some(encoding),
+            DataSeries::DistanceToNextFragment => {
+                self.distance_to_next_fragment_encoding = Some(encoding)
+            }
+            DataSeries::TagIds => self.tag_ids_encoding = encoding,
+            DataSeries::NumberOfReadFeatures => {
+                self.number_of_read_features_encoding = Some(encoding)
+            }
+            DataSeries::ReadFeaturesCodes => self.read_features_codes_encoding = Some(encoding),
+            DataSeries::InReadPositions => self.in_read_positions_encoding = Some(encoding),
+            DataSeries::DeletionLengths => self.deletion_lengths_encoding = Some(encoding),
+            DataSeries::StretchesOfBases => self.stretches_of_bases_encoding = Some(encoding),
+            DataSeries::StretchesOfQualityScores => {
+                self.stretches_of_quality_scores_encoding = Some(encoding)
+            }
+            DataSeries::BaseSubstitutionCodes => {
+                self.base_substitution_codes_encoding = Some(encoding)
+            }
+            DataSeries::Insertion => self.insertion_encoding = Some(encoding),
+            DataSeries::ReferenceSkipLength => self.reference_skip_length_encoding = Some(encoding),
+            DataSeries::Padding => self.padding_encoding = Some(encoding),
+            DataSeries::HardClip => self.hard_clip_encoding = Some(encoding),
+            DataSeries::SoftClip => self.soft_clip_encoding = Some(encoding),
+            DataSeries::MappingQualities => self.mapping_qualities_encoding = Some(encoding),
+            DataSeries::Bases => self.bases_encoding = Some(encoding),
+            DataSeries::QualityScores => self.quality_scores_encoding = Some(encoding),
+            DataSeries::ReservedTc | DataSeries::ReservedTn => {}
+        }
+    }
 }
 
 impl Default for DataSeriesEncodingMap {
@@ -1,5 +1,6 @@
 use std::{error, fmt};
 
+/// A CRAM container compression header data series.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum DataSeries {
     /// BAM bit flags (`BF`).
@@ -71,6 +72,38 @@ pub enum DataSeries {
 impl DataSeries {
     /// The number of data series variants.
     pub(crate) const LEN: usize = 28;
+
+    /// All data series variants, excluding the legacy CRAM 1.0 reserved data series.
+    pub(crate) const ALL: [Self; Self::LEN] = [
+        Self::BamBitFlags,
+        Self::CramBitFlags,
+        Self::ReferenceId,
+        Self::ReadLengths,
+        Self::InSeqPositions,
+        Self::ReadGroups,
+        Self::ReadNames,
+        Self::NextMateBitFlags,
+        Self::NextFragmentReferenceSequenceId,
+        Self::NextMateAlignmentStart,
+        Self::TemplateSize,
+        Self::DistanceToNextFragment,
+        Self::TagIds,
+        Self::NumberOfReadFeatures,
+        Self::ReadFeaturesCodes,
+        Self::InReadPositions,
+        Self::DeletionLengths,
+        Self::StretchesOfBases,
+        Self::StretchesOfQualityScores,
+        Self::BaseSubstitutionCodes,
+        Self::Insertion,
+        Self::ReferenceSkipLength,
+        Self::Padding,
+        Self::HardClip,
+        Self::SoftClip,
+        Self::MappingQualities,
+        Self::Bases,
+        Self::QualityScores,
+    ];
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
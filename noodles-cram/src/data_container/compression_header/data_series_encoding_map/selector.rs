@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use noodles_sam::{self as sam, AlignmentRecord};
+
+use super::{DataSeries, DataSeriesEncodingMap};
+use crate::{data_container::compression_header::Encoding, huffman, Record};
+
+// Data series with a symbol alphabet no larger than this are Huffman encoded.
+const MAX_HUFFMAN_ALPHABET_SIZE: usize = 16;
+
+// Huffman code lengths are capped so that rare values in a skewed distribution do not get long
+// codes. This must be able to represent `MAX_HUFFMAN_ALPHABET_SIZE` symbols.
+const MAX_HUFFMAN_CODE_LEN: u32 = 8;
+
+// Data series with larger alphabets but a value range that fits in at most this many bits are
+// beta encoded.
+const MAX_BETA_BIT_LEN: u32 = 6;
+
+// § 10.2 "CRAM positional data" (2021-10-15): "-1 for no group".
+const MISSING_READ_GROUP: i32 = -1;
+
+/// A data series encoding selector.
+///
+/// This observes the values written for a set of integer data series and chooses an encoding
+/// for each based on their distribution. Data series that are not observed keep their default
+/// encoding.
+#[derive(Debug, Default)]
+pub struct Selector {
+    frequencies: HashMap<DataSeries, HashMap<i32, u64>>,
+}
+
+impl Selector {
+    pub fn update(&mut self, record: &Record) {
+        let bam_bit_flags = i32::from(u16::from(record.bam_flags()));
+        self.add(DataSeries::BamBitFlags, bam_bit_flags);
+
+        self.add(DataSeries::ReadLengths, record.read_length() as i32);
+
+        let read_group = record
+            .read_group_id()
+            .map(|id| id as i32)
+            .unwrap_or(MISSING_READ_GROUP);
+        self.add(DataSeries::ReadGroups, read_group);
+
+        if !record.bam_flags().is_unmapped() {
+            let mapping_quality = record
+                .mapping_quality()
+                .map(u8::from)
+                .unwrap_or(sam::record::mapping_quality::MISSING);
+
+            self.add(DataSeries::MappingQualities, i32::from(mapping_quality));
+        }
+    }
+
+    pub fn build(self) -> DataSeriesEncodingMap {
+        let mut data_series_encoding_map = DataSeriesEncodingMap::default();
+
+        for (data_series, frequencies) in self.frequencies {
+            if let Some(encoding) = select_encoding(&frequencies) {
+                data_series_encoding_map.insert(data_series, encoding);
+            }
+        }
+
+        data_series_encoding_map
+    }
+
+    fn add(&mut self, data_series: DataSeries, value: i32) {
+        *self
+            .frequencies
+            .entry(data_series)
+            .or_default()
+            .entry(value)
+            .or_default() += 1;
+    }
+}
+
+fn select_encoding(frequencies: &HashMap<i32, u64>) -> Option<Encoding> {
+    if frequencies.is_empty() {
+        return None;
+    }
+
+    if frequencies.len() <= MAX_HUFFMAN_ALPHABET_SIZE {
+        return huffman::build_with_max_len(frequencies, MAX_HUFFMAN_CODE_LEN)
+            .ok()
+            .map(|(alphabet, bit_lens)| Encoding::Huffman(alphabet, bit_lens));
+    }
+
+    let min = frequencies.keys().copied().min()?;
+    let max = frequencies.keys().copied().max()?;
+    let range = i64::from(max) - i64::from(min);
+    let len = u64::BITS - (range as u64).leading_zeros();
+
+    if len <= MAX_BETA_BIT_LEN {
+        Some(Encoding::Beta(-min, len))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::record::{Flags, MappingQuality};
+
+    use super::*;
+
+    #[test]
+    fn test_build() -> Result<(), Box<dyn std::error::Error>> {
+        let mut selector = Selector::default();
+
+        for mapping_quality in [60, 60, 60, 0, 60, 60, 12, 60] {
+            let record = Record::builder()
+                .set_bam_flags(Flags::empty())
+                .set_read_length(100)
+                .set_mapping_quality(MappingQuality::try_from(mapping_quality)?)
+                .build();
+
+            selector.update(&record);
+        }
+
+        let data_series_encoding_map = selector.build();
+
+        assert!(matches!(
+            data_series_encoding_map.mapping_qualities_encoding(),
+            Some(Encoding::Huffman(alphabet, bit_lens)) if alphabet == &[0, 12, 60] && bit_lens == &[2, 2, 1]
+        ));
+
+        assert_eq!(
+            data_series_encoding_map.read_lengths_encoding(),
+            &Encoding::Huffman(vec![100], vec![0])
+        );
+
+        // Unobserved data series keep their default encodings.
+        assert_eq!(
+            data_series_encoding_map.quality_scores_encoding(),
+            DataSeriesEncodingMap::default().quality_scores_encoding()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_encoding() {
+        assert_eq!(select_encoding(&HashMap::new()), None);
+
+        let frequencies = [(-1, 3), (0, 1)].into_iter().collect();
+        assert_eq!(
+            select_encoding(&frequencies),
+            Some(Encoding::Huffman(vec![-1, 0], vec![1, 1]))
+        );
+
+        // Fibonacci frequencies produce the deepest possible tree, i.e., a max code length of 11.
+        let frequencies: HashMap<_, _> = [1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89, 144]
+            .into_iter()
+            .enumerate()
+            .map(|(i, f)| (i as i32, f))
+            .collect();
+        assert!(matches!(
+            select_encoding(&frequencies),
+            Some(Encoding::Huffman(alphabet, bit_lens))
+                if alphabet.len() == 12 && bit_lens.iter().all(|&len| len <= MAX_HUFFMAN_CODE_LEN)
+        ));
+
+        let frequencies = (8..40).map(|n| (n, 1)).collect();
+        assert_eq!(select_encoding(&frequencies), Some(Encoding::Beta(-8, 5)));
+
+        let frequencies = (0..1000).step_by(10).map(|n| (n, 1)).collect();
+        assert_eq!(select_encoding(&frequencies), None);
+    }
+}
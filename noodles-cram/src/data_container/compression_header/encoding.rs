@@ -2,7 +2,7 @@ mod kind;
 
 pub use self::kind::Kind;
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Encoding {
     Null,
     // block_content_id
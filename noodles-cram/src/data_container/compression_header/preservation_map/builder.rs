@@ -16,6 +16,10 @@ impl Builder {
         self.ap_data_series_delta = options.encode_alignment_start_positions_as_deltas;
     }
 
+    pub fn set_ap_data_series_delta(&mut self, ap_data_series_delta: bool) {
+        self.ap_data_series_delta = ap_data_series_delta;
+    }
+
     pub fn update(&mut self, record: &Record) {
         self.substitution_matrix_builder.update(record);
         self.tag_ids_dictionary_builder.update(record);
@@ -91,6 +91,10 @@ impl TryFrom<[u8; 5]> for SubstitutionMatrix {
     type Error = TryFromByteArrayError;
 
     fn try_from(b: [u8; 5]) -> Result<Self, Self::Error> {
+        if !b.iter().all(|&codes| is_permutation(codes)) {
+            return Err(TryFromByteArrayError(b));
+        }
+
         let mut matrix = Self::default();
 
         set_substitutions(
@@ -132,6 +136,24 @@ impl TryFrom<[u8; 5]> for SubstitutionMatrix {
     }
 }
 
+// Each byte packs four 2-bit substitution codes, one for each of the other bases. The codes must
+// be distinct, i.e., each reference base maps to four distinct read bases.
+fn is_permutation(codes: u8) -> bool {
+    let mut seen = [false; 4];
+
+    for shift in [6, 4, 2, 0] {
+        let code = usize::from((codes >> shift) & 0x03);
+
+        if seen[code] {
+            return false;
+        }
+
+        seen[code] = true;
+    }
+
+    true
+}
+
 fn set_substitutions(
     reference_base: Base,
     codes: u8,
@@ -214,6 +236,17 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_try_from_u8_slice_with_invalid_codes() {
+        // 0x1f = 0b00_01_11_11
+        let codes = [0x1b, 0x1b, 0x1f, 0x1b, 0x1b];
+
+        assert_eq!(
+            SubstitutionMatrix::try_from(codes),
+            Err(TryFromByteArrayError(codes))
+        );
+    }
+
     #[test]
     fn test_from_substitution_matrix_for_5_byte_array() {
         let matrix = SubstitutionMatrix {
@@ -5,6 +5,7 @@ pub use self::{builder::Builder, header::Header};
 
 use std::io;
 
+use bytes::Bytes;
 use noodles_core::Position;
 use noodles_fasta as fasta;
 use noodles_sam::{self as sam, AlignmentRecord};
@@ -74,17 +75,28 @@ impl Slice {
     /// # Ok::<_, io::Error>(())
     /// ```
     pub fn records(&self, compression_header: &CompressionHeader) -> io::Result<Vec<Record>> {
+        self.records_with_decompressor(compression_header, None, |block| block.decompressed_data())
+    }
+
+    // Reads records using the given function to decompress the core data and external blocks.
+    // Records with a read length greater than `max_read_length` fail to read.
+    pub(crate) fn records_with_decompressor<F>(
+        &self,
+        compression_header: &CompressionHeader,
+        max_read_length: Option<usize>,
+        mut decompress: F,
+    ) -> io::Result<Vec<Record>>
+    where
+        F: FnMut(&Block) -> io::Result<Bytes>,
+    {
         use crate::reader::record::ExternalDataReaders;
 
-        let core_data_reader = self
-            .core_data_block
-            .decompressed_data()
-            .map(BitReader::new)?;
+        let core_data_reader = decompress(&self.core_data_block).map(BitReader::new)?;
 
         let mut external_data_readers = ExternalDataReaders::new();
 
         for block in self.external_blocks() {
-            let reader = block.decompressed_data()?;
+            let reader = decompress(block)?;
             external_data_readers.insert(block.content_id(), reader);
         }
 
@@ -96,6 +108,8 @@ impl Slice {
             self.header.alignment_start(),
         );
 
+        record_reader.set_max_read_length(max_read_length);
+
         let record_count = self.header().record_count();
         let mut records = Vec::with_capacity(record_count);
 
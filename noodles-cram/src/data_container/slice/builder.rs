@@ -1,7 +1,7 @@
 use std::{
     cmp,
     collections::{HashMap, HashSet},
-    io,
+    io::{self, Write},
 };
 
 use md5::{Digest, Md5};
@@ -16,7 +16,11 @@ use crate::{
     },
     data_container::{compression_header::data_series_encoding_map::DataSeries, CompressionHeader},
     record::Flags,
-    writer, BitWriter, Record,
+    writer::{
+        self,
+        statistics::{build_data_series_block_content_ids, Statistics},
+    },
+    BitWriter, Record,
 };
 
 use super::{Header, Slice};
@@ -45,7 +49,11 @@ impl Builder {
         &self.records
     }
 
-    pub fn add_record(&mut self, record: Record) -> Result<&Record, AddRecordError> {
+    pub fn add_record(
+        &mut self,
+        options: &writer::Options,
+        record: Record,
+    ) -> Result<&Record, AddRecordError> {
         if self.records.len() >= MAX_RECORD_COUNT {
             return Err(AddRecordError::SliceFull(record));
         }
@@ -54,7 +62,9 @@ impl Builder {
             self.slice_reference_sequence_id = record.reference_sequence_id();
         }
 
-        if record.reference_sequence_id() == self.slice_reference_sequence_id {
+        if options.allow_multi_reference_slices
+            || record.reference_sequence_id() == self.slice_reference_sequence_id
+        {
             self.records.push(record);
             Ok(self.records.last().unwrap())
         } else {
@@ -62,13 +72,91 @@ impl Builder {
         }
     }
 
-    pub fn build(
+    /// Stably sorts the records in the slice by alignment start.
+    ///
+    /// Multi-reference slices are not sorted.
+    pub fn sort_records_by_alignment_start(&mut self) {
+        if !self.is_multi_reference() {
+            self.records.sort_by_key(|record| record.alignment_start());
+        }
+    }
+
+    /// Returns whether the records in the slice have more than one reference sequence ID.
+    pub fn is_multi_reference(&self) -> bool {
+        !self.records.is_empty() && find_slice_reference_sequence_id(&self.records).is_many()
+    }
+
+    /// Adds an estimate of the number of bytes each data series and tag would be written as using
+    /// the given compression header to the given statistics.
+    ///
+    /// This encodes the records without building any blocks. Lengths are uncompressed sizes.
+    pub fn estimate(
+        &self,
+        compression_header: &CompressionHeader,
+        statistics: &mut Statistics,
+    ) -> io::Result<()> {
+        if self.records.is_empty() {
+            return Ok(());
+        }
+
+        let slice_reference_sequence_id = find_slice_reference_sequence_id(&self.records);
+
+        let slice_alignment_start = if slice_reference_sequence_id.is_some() {
+            find_slice_alignment_positions(&self.records).map(|(start, _)| start)?
+        } else {
+            None
+        };
+
+        let mut core_data_writer = BitWriter::new(ByteCounter::default());
+        let mut external_data_writers: HashMap<_, ByteCounter> =
+            build_external_data_writers(compression_header);
+
+        let mut record_writer = writer::record::Writer::new(
+            compression_header,
+            &mut core_data_writer,
+            &mut external_data_writers,
+            slice_reference_sequence_id,
+            slice_alignment_start,
+        );
+
+        for record in &self.records {
+            let mut record = record.clone();
+            prepare_record(&mut record);
+            record_writer.write_record(&record)?;
+        }
+
+        let core_data_len = core_data_writer.finish()?.len;
+        statistics.add_core_data_len(core_data_len);
+
+        let data_series_block_content_ids = build_data_series_block_content_ids(compression_header);
+
+        for (block_content_id, counter) in external_data_writers {
+            if counter.len == 0 {
+                continue;
+            }
+
+            statistics.add_external_data_len(
+                compression_header,
+                &data_series_block_content_ids,
+                block_content_id,
+                counter.len,
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Encodes the records in the slice using the given compression header.
+    ///
+    /// The core and external data blocks are compressed with the given block compression method.
+    ///
+    /// This does not depend on the reference sequence repository, so slices of the same container
+    /// can be encoded independently of each other.
+    pub fn encode(
         mut self,
-        reference_sequence_repostitory: &fasta::repository::Repository,
-        header: &sam::Header,
         compression_header: &CompressionHeader,
-        record_counter: i64,
-    ) -> io::Result<Slice> {
+        block_compression_method: CompressionMethod,
+    ) -> io::Result<EncodedSlice> {
         let slice_reference_sequence_id = find_slice_reference_sequence_id(&self.records);
 
         let (slice_alignment_start, slice_alignment_end) = if slice_reference_sequence_id.is_some()
@@ -82,9 +170,52 @@ impl Builder {
             compression_header,
             slice_reference_sequence_id,
             slice_alignment_start,
+            block_compression_method,
             &mut self.records,
         )?;
 
+        Ok(EncodedSlice {
+            record_count: self.records.len(),
+            slice_reference_sequence_id,
+            slice_alignment_start,
+            slice_alignment_end,
+            core_data_block,
+            external_blocks,
+        })
+    }
+}
+
+/// The encoded blocks of a slice that does not yet have a header.
+#[derive(Debug)]
+pub struct EncodedSlice {
+    record_count: usize,
+    slice_reference_sequence_id: ReferenceSequenceId,
+    slice_alignment_start: Option<Position>,
+    slice_alignment_end: Option<Position>,
+    core_data_block: Block,
+    external_blocks: Vec<Block>,
+}
+
+impl EncodedSlice {
+    pub fn record_count(&self) -> usize {
+        self.record_count
+    }
+
+    pub fn finish(
+        self,
+        reference_sequence_repostitory: &fasta::repository::Repository,
+        header: &sam::Header,
+        record_counter: i64,
+    ) -> io::Result<Slice> {
+        let Self {
+            record_count,
+            slice_reference_sequence_id,
+            slice_alignment_start,
+            slice_alignment_end,
+            core_data_block,
+            external_blocks,
+        } = self;
+
         let mut block_content_ids = Vec::with_capacity(external_blocks.len() + 1);
         block_content_ids.push(core_data_block.content_id());
 
@@ -102,14 +233,27 @@ impl Builder {
                     .reference_sequences()
                     .get_index(id as usize)
                     .map(|(_, rs)| rs.name())
-                    .expect("invalid reference sequence ID");
+                    .ok_or_else(|| {
+                        io::Error::new(io::ErrorKind::InvalidInput, "invalid reference sequence ID")
+                    })?;
 
                 let reference_sequence = reference_sequence_repostitory
                     .get(reference_sequence_name)
-                    .expect("missing reference sequence")
-                    .expect("invalid reference sequence");
+                    .transpose()?
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            format!("missing reference sequence: {}", reference_sequence_name),
+                        )
+                    })?;
+
+                let sequence = reference_sequence.get(start..=end).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "slice alignment is out of reference sequence bounds",
+                    )
+                })?;
 
-                let sequence = &reference_sequence[start..=end];
                 calculate_normalized_sequence_digest(sequence)
             }
             _ => [0; 16],
@@ -117,7 +261,7 @@ impl Builder {
 
         let mut builder = Header::builder()
             .set_reference_sequence_id(slice_reference_sequence_id)
-            .set_record_count(self.records.len())
+            .set_record_count(record_count)
             .set_record_counter(record_counter)
             .set_block_count(block_content_ids.len())
             .set_block_content_ids(block_content_ids)
@@ -181,21 +325,15 @@ fn write_records(
     compression_header: &CompressionHeader,
     slice_reference_sequence_id: ReferenceSequenceId,
     slice_alignment_start: Option<Position>,
+    block_compression_method: CompressionMethod,
     records: &mut [Record],
 ) -> io::Result<(Block, Vec<Block>)> {
     let mut core_data_writer = BitWriter::new(Vec::new());
+    let mut external_data_writers: HashMap<_, Vec<u8>> =
+        build_external_data_writers(compression_header);
 
-    let mut external_data_writers = HashMap::new();
-
-    for i in 0..DataSeries::LEN {
-        let block_content_id = (i + 1) as i32;
-        external_data_writers.insert(block_content_id, Vec::new());
-    }
-
-    for &block_content_id in compression_header.tag_encoding_map().keys() {
-        external_data_writers.insert(block_content_id, Vec::new());
-    }
-
+    // Each slice gets a new record writer, which resets the previous alignment start used for
+    // AP data series deltas to the slice alignment start.
     let mut record_writer = writer::record::Writer::new(
         compression_header,
         &mut core_data_writer,
@@ -205,11 +343,7 @@ fn write_records(
     );
 
     for record in records {
-        // FIXME: For simplicity, all records are written as detached.
-        record.cram_bit_flags.insert(Flags::DETACHED);
-        record.cram_bit_flags.remove(Flags::HAS_MATE_DOWNSTREAM);
-        record.distance_to_next_fragment = None;
-
+        prepare_record(record);
         record_writer.write_record(record)?;
     }
 
@@ -217,27 +351,88 @@ fn write_records(
         Block::builder()
             .set_content_type(block::ContentType::CoreData)
             .set_content_id(CORE_DATA_BLOCK_CONTENT_ID)
-            .compress_and_set_data(buf, CompressionMethod::Gzip)
+            .compress_and_set_data(buf, block_compression_method)
             .map(|builder| builder.build())
     })?;
 
-    let external_blocks: Vec<_> = external_data_writers
+    let mut external_blocks: Vec<_> = external_data_writers
         .into_iter()
         .filter(|(_, buf)| !buf.is_empty())
         .map(|(block_content_id, buf)| {
             Block::builder()
                 .set_content_type(block::ContentType::ExternalData)
                 .set_content_id(block_content_id)
-                .compress_and_set_data(buf, CompressionMethod::Gzip)
+                .compress_and_set_data(buf, block_compression_method)
                 .map(|builder| builder.build())
         })
         .collect::<Result<_, _>>()?;
 
+    // The external data writers are not ordered, so the blocks are sorted to make the output
+    // deterministic.
+    external_blocks.sort_by_key(|block| block.content_id());
+
     Ok((core_data_block, external_blocks))
 }
 
+fn build_external_data_writers<X>(compression_header: &CompressionHeader) -> HashMap<i32, X>
+where
+    X: Default,
+{
+    let mut external_data_writers = HashMap::new();
+
+    for i in 0..DataSeries::LEN {
+        let block_content_id = (i + 1) as i32;
+        external_data_writers.insert(block_content_id, X::default());
+    }
+
+    for &block_content_id in compression_header.tag_encoding_map().keys() {
+        external_data_writers.insert(block_content_id, X::default());
+    }
+
+    external_data_writers
+}
+
+fn prepare_record(record: &mut Record) {
+    // FIXME: For simplicity, all records with mate data are written as detached.
+    if has_mate_data(record) {
+        record.cram_bit_flags.insert(Flags::DETACHED);
+    } else {
+        record.cram_bit_flags.remove(Flags::DETACHED);
+    }
+
+    record.cram_bit_flags.remove(Flags::HAS_MATE_DOWNSTREAM);
+    record.distance_to_next_fragment = None;
+}
+
+// Records without mate data are not detached, so their read names can be omitted when read names
+// are not preserved.
+fn has_mate_data(record: &Record) -> bool {
+    record.bam_bit_flags.is_segmented()
+        || !record.next_mate_bit_flags.is_empty()
+        || record.next_fragment_reference_sequence_id.is_some()
+        || record.next_mate_alignment_start.is_some()
+        || record.template_size != 0
+}
+
+// A writer that discards its input and only counts the number of bytes written.
+#[derive(Debug, Default)]
+struct ByteCounter {
+    len: u64,
+}
+
+impl Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.len += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
 // _Sequence Alignment/Map Format Specification_ (2021-06-03) § 1.3.2 "Reference MD5 calculation"
-fn calculate_normalized_sequence_digest(sequence: &[u8]) -> [u8; 16] {
+pub(crate) fn calculate_normalized_sequence_digest(sequence: &[u8]) -> [u8; 16] {
     let mut hasher = Md5::new();
 
     for &b in sequence {
@@ -254,6 +449,166 @@ fn calculate_normalized_sequence_digest(sequence: &[u8]) -> [u8; 16] {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::data_container::compression_header::Encoding;
+
+    #[test]
+    fn test_encode_with_core_data_encodings() -> Result<(), Box<dyn std::error::Error>> {
+        let mut builder = Builder::default();
+        let mut compression_header_builder = CompressionHeader::builder();
+
+        for (read_length, read_group_id) in [(4, Some(0)), (4, None), (4, Some(1)), (4, Some(0))] {
+            let mut record_builder = Record::builder()
+                .set_read_length(read_length)
+                .set_bases("ACGT".parse()?);
+
+            if let Some(id) = read_group_id {
+                record_builder = record_builder.set_read_group_id(id);
+            }
+
+            let record = record_builder.build();
+
+            compression_header_builder.update(&record);
+            assert!(builder
+                .add_record(&writer::Options::default(), record)
+                .is_ok());
+        }
+
+        let compression_header = compression_header_builder.build();
+
+        assert!(matches!(
+            compression_header
+                .data_series_encoding_map()
+                .read_groups_encoding(),
+            Encoding::Huffman(..)
+        ));
+
+        let expected: Vec<_> = builder
+            .records()
+            .iter()
+            .map(|record| (record.read_length(), record.read_group_id()))
+            .collect();
+
+        let slice = builder
+            .encode(&compression_header, CompressionMethod::Gzip)?
+            .finish(&fasta::Repository::default(), &sam::Header::default(), 0)?;
+
+        let actual: Vec<_> = slice
+            .records(&compression_header)?
+            .iter()
+            .map(|record| (record.read_length(), record.read_group_id()))
+            .collect();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_with_alignment_start_deltas_across_slices(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_fasta::record::{Definition, Sequence};
+        use noodles_sam::header::ReferenceSequence;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 16)?)
+            .build();
+
+        let reference_sequence_repository = fasta::Repository::new(vec![fasta::Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"ACGTACGTACGTACGT".to_vec()),
+        )]);
+
+        let slice_alignment_starts = [[1, 5], [9, 13]];
+
+        let mut compression_header_builder = CompressionHeader::builder();
+        let mut builders = Vec::new();
+
+        for alignment_starts in slice_alignment_starts {
+            let mut builder = Builder::default();
+
+            for alignment_start in alignment_starts {
+                let record = Record::builder()
+                    .set_bam_flags(sam::record::Flags::empty())
+                    .set_reference_sequence_id(0)
+                    .set_alignment_start(Position::try_from(alignment_start)?)
+                    .set_read_length(4)
+                    .set_bases("ACGT".parse()?)
+                    .build();
+
+                compression_header_builder.update(&record);
+                assert!(builder
+                    .add_record(&writer::Options::default(), record)
+                    .is_ok());
+            }
+
+            builders.push(builder);
+        }
+
+        let compression_header = compression_header_builder.build();
+        assert!(compression_header.preservation_map().ap_data_series_delta());
+
+        for (builder, expected) in builders.into_iter().zip(slice_alignment_starts) {
+            let slice = builder
+                .encode(&compression_header, CompressionMethod::Gzip)?
+                .finish(&reference_sequence_repository, &header, 0)?;
+
+            let actual: Vec<_> = slice
+                .records(&compression_header)?
+                .iter()
+                .map(|record| record.alignment_start().map(usize::from))
+                .collect();
+
+            let expected: Vec<_> = expected.into_iter().map(Some).collect();
+
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_with_next_mate_flags() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::NextMateFlags;
+
+        let mut builder = Builder::default();
+        let mut compression_header_builder = CompressionHeader::builder();
+
+        let expected = [
+            NextMateFlags::empty(),
+            NextMateFlags::ON_NEGATIVE_STRAND,
+            NextMateFlags::UNMAPPED,
+            NextMateFlags::ON_NEGATIVE_STRAND | NextMateFlags::UNMAPPED,
+        ];
+
+        for &next_mate_flags in &expected {
+            let record = Record::builder()
+                .set_next_mate_flags(next_mate_flags)
+                .build();
+
+            compression_header_builder.update(&record);
+            assert!(builder
+                .add_record(&writer::Options::default(), record)
+                .is_ok());
+        }
+
+        let compression_header = compression_header_builder.build();
+
+        let slice = builder
+            .encode(&compression_header, CompressionMethod::Gzip)?
+            .finish(&fasta::Repository::default(), &sam::Header::default(), 0)?;
+
+        let records = slice.records(&compression_header)?;
+        let actual: Vec<_> = records.iter().map(|r| r.next_mate_flags()).collect();
+
+        assert_eq!(actual, expected);
+
+        assert!(!actual[0].is_on_negative_strand() && !actual[0].is_unmapped());
+        assert!(actual[1].is_on_negative_strand() && !actual[1].is_unmapped());
+        assert!(!actual[2].is_on_negative_strand() && actual[2].is_unmapped());
+        assert!(actual[3].is_on_negative_strand() && actual[3].is_unmapped());
+
+        Ok(())
+    }
 
     #[test]
     fn test_calculate_normalized_sequence_digest() {
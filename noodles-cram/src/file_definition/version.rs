@@ -1,7 +1,9 @@
+use std::fmt;
+
 /// A CRAM file definition version.
 ///
 /// This is also called the format number.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Version {
     major: u8,
     minor: u8,
@@ -16,7 +18,7 @@ impl Version {
     /// use noodles_cram::file_definition::Version;
     /// let version = Version::new(3, 0);
     /// ```
-    pub fn new(major: u8, minor: u8) -> Self {
+    pub const fn new(major: u8, minor: u8) -> Self {
         Self { major, minor }
     }
 
@@ -53,6 +55,12 @@ impl Default for Version {
     }
 }
 
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -61,4 +69,16 @@ mod tests {
     fn test_default() {
         assert_eq!(Version::default(), Version::new(3, 0));
     }
+
+    #[test]
+    fn test_cmp() {
+        assert!(Version::new(2, 1) < Version::new(3, 0));
+        assert!(Version::new(3, 0) < Version::new(3, 1));
+    }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Version::new(2, 1).to_string(), "2.1");
+        assert_eq!(Version::new(3, 0).to_string(), "3.0");
+    }
 }
@@ -1,8 +1,12 @@
-use std::{collections::HashMap, io};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    io::{self, Write},
+};
 
 use bytes::Buf;
 
-use crate::BitReader;
+use crate::{BitReader, BitWriter};
 
 type CodeBook = HashMap<i32, (i32, u32)>;
 
@@ -60,6 +64,122 @@ impl CanonicalHuffmanDecoder {
     }
 }
 
+pub struct CanonicalHuffmanEncoder {
+    code_book: CodeBook,
+}
+
+impl CanonicalHuffmanEncoder {
+    pub fn new(alphabet: &[i32], bit_lens: &[u32]) -> Self {
+        let code_book = build_canonical_code_book(alphabet, bit_lens);
+        Self { code_book }
+    }
+
+    pub fn encode<W>(&self, writer: &mut BitWriter<W>, symbol: i32) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let (code, len) = self.code_book.get(&symbol).copied().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("symbol not in alphabet: {}", symbol),
+            )
+        })?;
+
+        writer.write_u32(code as u32, len as usize)
+    }
+}
+
+/// Builds canonical Huffman code lengths from a symbol frequency histogram.
+///
+/// This returns the alphabet, sorted by symbol, and the bit length of the code for each symbol.
+/// The result can be used as the parameters of a Huffman encoding. An alphabet with a single
+/// symbol is assigned a bit length of 0.
+pub fn build(frequencies: &HashMap<i32, u64>) -> (Vec<i32>, Vec<u32>) {
+    let mut alphabet: Vec<_> = frequencies.keys().copied().collect();
+    alphabet.sort_unstable();
+
+    let weights: Vec<_> = alphabet.iter().map(|symbol| frequencies[symbol]).collect();
+    let bit_lens = build_bit_lens(&weights);
+
+    (alphabet, bit_lens)
+}
+
+/// Builds canonical Huffman code lengths from a symbol frequency histogram, limiting the code
+/// lengths to at most `max_len` bits.
+///
+/// When the optimal code exceeds the limit, the frequencies are repeatedly flattened (halved,
+/// rounding up) and the code rebuilt. This is not optimal but converges to a balanced code.
+///
+/// This returns an error if the number of symbols cannot be represented with `max_len` bits.
+pub fn build_with_max_len(
+    frequencies: &HashMap<i32, u64>,
+    max_len: u32,
+) -> io::Result<(Vec<i32>, Vec<u32>)> {
+    let n = frequencies.len();
+
+    if max_len < 32 && n > (1 << max_len) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "cannot represent {} symbols with a max code length of {}",
+                n, max_len
+            ),
+        ));
+    }
+
+    let (alphabet, mut bit_lens) = build(frequencies);
+    let mut weights: Vec<_> = alphabet.iter().map(|symbol| frequencies[symbol]).collect();
+
+    while bit_lens.iter().any(|&len| len > max_len) {
+        for weight in &mut weights {
+            *weight = (*weight + 1) / 2;
+        }
+
+        bit_lens = build_bit_lens(&weights);
+    }
+
+    Ok((alphabet, bit_lens))
+}
+
+fn build_bit_lens(weights: &[u64]) -> Vec<u32> {
+    if weights.len() <= 1 {
+        return vec![0; weights.len()];
+    }
+
+    // Leaves are nodes 0..n, and internal nodes are appended as they are created.
+    let mut parents: Vec<Option<usize>> = vec![None; weights.len()];
+    let mut heap: BinaryHeap<_> = weights
+        .iter()
+        .enumerate()
+        .map(|(i, &weight)| Reverse((weight, i)))
+        .collect();
+
+    while let (Some(Reverse((a_weight, a))), Some(Reverse((b_weight, b)))) =
+        (heap.pop(), heap.pop())
+    {
+        let node = parents.len();
+        parents.push(None);
+
+        parents[a] = Some(node);
+        parents[b] = Some(node);
+
+        heap.push(Reverse((a_weight + b_weight, node)));
+    }
+
+    (0..weights.len())
+        .map(|mut i| {
+            let mut len = 0;
+
+            while let Some(parent) = parents[i] {
+                len += 1;
+                i = parent;
+            }
+
+            len
+        })
+        .collect()
+}
+
 fn build_canonical_code_book(alphabet: &[i32], bit_lens: &[u32]) -> CodeBook {
     let sorted_alphabet = {
         let mut pairs: Vec<_> = alphabet.iter().zip(bit_lens.iter()).collect();
@@ -107,6 +227,100 @@ mod tests {
         Ok(())
     }
 
+    fn round_trip(alphabet: &[i32], bit_lens: &[u32], symbols: &[i32]) -> io::Result<Vec<i32>> {
+        let encoder = CanonicalHuffmanEncoder::new(alphabet, bit_lens);
+        let mut writer = BitWriter::new(Vec::new());
+
+        for &symbol in symbols {
+            encoder.encode(&mut writer, symbol)?;
+        }
+
+        let data = writer.finish()?;
+        let mut reader = BitReader::new(&data[..]);
+        let decoder = CanonicalHuffmanDecoder::new(alphabet, bit_lens);
+
+        symbols
+            .iter()
+            .map(|_| decoder.decode(&mut reader))
+            .collect()
+    }
+
+    #[test]
+    fn test_build() -> io::Result<()> {
+        let frequencies = [(60, 100), (0, 10), (255, 5), (30, 3), (20, 1)]
+            .into_iter()
+            .collect();
+
+        let (alphabet, bit_lens) = build(&frequencies);
+
+        assert_eq!(alphabet, [0, 20, 30, 60, 255]);
+        assert_eq!(bit_lens, [2, 4, 4, 1, 3]);
+
+        let symbols = [60, 60, 0, 255, 60, 30, 20, 60];
+        assert_eq!(round_trip(&alphabet, &bit_lens, &symbols)?, symbols);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_one_symbol() -> io::Result<()> {
+        let frequencies = [(60, 8)].into_iter().collect();
+        let (alphabet, bit_lens) = build(&frequencies);
+
+        assert_eq!(alphabet, [60]);
+        assert_eq!(bit_lens, [0]);
+
+        assert_eq!(round_trip(&alphabet, &bit_lens, &[60, 60])?, [60, 60]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_max_len() -> io::Result<()> {
+        // Fibonacci frequencies produce the deepest possible tree.
+        let frequencies: HashMap<_, _> = [1, 1, 2, 3, 5, 8, 13, 21]
+            .into_iter()
+            .enumerate()
+            .map(|(i, f)| (i as i32, f))
+            .collect();
+
+        let (_, bit_lens) = build(&frequencies);
+        assert_eq!(bit_lens.iter().max(), Some(&7));
+
+        let (alphabet, bit_lens) = build_with_max_len(&frequencies, 4)?;
+        assert!(bit_lens.iter().all(|&len| len <= 4));
+
+        let symbols = [0, 7, 3, 7, 1, 6];
+        assert_eq!(round_trip(&alphabet, &bit_lens, &symbols)?, symbols);
+
+        assert!(matches!(
+            build_with_max_len(&frequencies, 2),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode() -> io::Result<()> {
+        let encoder = CanonicalHuffmanEncoder::new(&[0x4e, 0x44, 0x4c], &[1, 2, 2]);
+        let mut writer = BitWriter::new(Vec::new());
+
+        encoder.encode(&mut writer, 0x4e)?;
+        encoder.encode(&mut writer, 0x44)?;
+        encoder.encode(&mut writer, 0x4c)?;
+        encoder.encode(&mut writer, 0x4e)?;
+
+        assert_eq!(writer.finish()?, [0b01011000]);
+
+        assert!(matches!(
+            encoder.encode(&mut BitWriter::new(Vec::new()), 0x00),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_build_canonical_code_book() {
         let symbols = [65, 66, 67, 68, 69, 70];
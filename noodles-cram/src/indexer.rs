@@ -1,4 +1,10 @@
-use std::{cmp, collections::HashMap, fs::File, io, path::Path};
+use std::{
+    cmp,
+    collections::HashMap,
+    fs::File,
+    io::{self, Read, Seek},
+    path::Path,
+};
 
 use noodles_core::Position;
 use noodles_sam::AlignmentRecord;
@@ -24,6 +30,13 @@ where
     P: AsRef<Path>,
 {
     let mut reader = File::open(src).map(Reader::new)?;
+    index_reader(&mut reader)
+}
+
+pub(crate) fn index_reader<R>(reader: &mut Reader<R>) -> io::Result<crai::Index>
+where
+    R: Read + Seek,
+{
     reader.read_file_definition()?;
     reader.read_file_header()?;
 
@@ -39,14 +52,20 @@ where
         let slice_count = landmarks.len();
 
         for (i, slice) in data_container.slices().iter().enumerate() {
-            let landmark = landmarks[i];
+            let landmark = landmarks.get(i).copied().ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "missing slice landmark")
+            })?;
 
-            let slice_length = if i < slice_count - 1 {
-                landmarks[i + 1] - landmark
+            let slice_end = if i + 1 < slice_count {
+                landmarks[i + 1]
             } else {
-                container_len - landmark
+                container_len
             };
 
+            let slice_length = slice_end.checked_sub(landmark).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "invalid slice landmark")
+            })?;
+
             push_index_records(
                 &mut index,
                 data_container.compression_header(),
@@ -140,13 +159,7 @@ fn push_index_records_for_multi_reference_slice(
     for reference_sequence_id in sorted_reference_sequence_ids {
         let (alignment_start, alignment_span) = if reference_sequence_id.is_some() {
             let range = &reference_sequence_ids[&reference_sequence_id];
-
-            if let (Some(start), Some(end)) = (range.start, range.end) {
-                let span = usize::from(end) - usize::from(start) + 1;
-                (Some(start), span)
-            } else {
-                todo!("unhandled interval: {:?}", range);
-            }
+            resolve_alignment_range(range).map(|(start, span)| (Some(start), span))?
         } else {
             (None, 0)
         };
@@ -166,6 +179,23 @@ fn push_index_records_for_multi_reference_slice(
     Ok(())
 }
 
+// Returns the alignment start and span of the range. Both the start and end must be set, i.e.,
+// every record in the range must have an alignment start and end.
+fn resolve_alignment_range(
+    range: &SliceReferenceSequenceAlignmentRangeInclusive,
+) -> io::Result<(Position, usize)> {
+    match (range.start, range.end) {
+        (Some(start), Some(end)) if start <= end => {
+            let span = usize::from(end) - usize::from(start) + 1;
+            Ok((start, span))
+        }
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid slice alignment range: {:?}", range),
+        )),
+    }
+}
+
 fn push_index_record_for_single_reference_slice(
     index: &mut crai::Index,
     slice_header: &slice::Header,
@@ -190,7 +220,12 @@ fn push_index_record_for_single_reference_slice(
             (reference_sequence_id, alignment_start, alignment_span)
         }
         ReferenceSequenceId::None => (None, None, 0),
-        ReferenceSequenceId::Many => unreachable!(),
+        ReferenceSequenceId::Many => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "expected a single reference slice",
+            ))
+        }
     };
 
     let record = crai::Record::new(
@@ -206,3 +241,93 @@ fn push_index_record_for_single_reference_slice(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use noodles_sam as sam;
+
+    use super::*;
+    use crate::{Record, Writer};
+
+    #[test]
+    fn test_index_reader_with_crai_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        for _ in 0..3 {
+            writer.write_record(&header, Record::default())?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let mut reader = Reader::new(Cursor::new(writer.get_ref().clone()));
+        let index = index_reader(&mut reader)?;
+
+        assert_eq!(index.len(), 1);
+        assert!(index[0].reference_sequence_id().is_none());
+
+        let mut crai_writer = crai::Writer::new(Vec::new());
+        crai_writer.write_index(&index)?;
+        let data = crai_writer.finish()?;
+
+        let mut crai_reader = crai::Reader::new(&data[..]);
+        let actual = crai_reader.read_index()?;
+
+        assert_eq!(actual, index);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_alignment_range() -> Result<(), Box<dyn std::error::Error>> {
+        let range = SliceReferenceSequenceAlignmentRangeInclusive {
+            start: Position::new(8),
+            end: Position::new(13),
+        };
+        assert_eq!(
+            resolve_alignment_range(&range)?,
+            (Position::try_from(8)?, 6)
+        );
+
+        let range = SliceReferenceSequenceAlignmentRangeInclusive {
+            start: Position::new(8),
+            end: None,
+        };
+        assert!(matches!(
+            resolve_alignment_range(&range),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let range = SliceReferenceSequenceAlignmentRangeInclusive {
+            start: None,
+            end: Position::new(13),
+        };
+        assert!(matches!(
+            resolve_alignment_range(&range),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_push_index_record_for_single_reference_slice_with_many_reference_sequences() {
+        use crate::container::ReferenceSequenceId;
+
+        let slice_header = slice::Header::builder()
+            .set_reference_sequence_id(ReferenceSequenceId::Many)
+            .build();
+
+        let mut index = Vec::new();
+
+        assert!(matches!(
+            push_index_record_for_single_reference_slice(&mut index, &slice_header, 0, 0, 0),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+}
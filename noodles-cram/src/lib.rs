@@ -17,11 +17,16 @@ mod indexer;
 mod num;
 pub mod reader;
 pub mod record;
-pub(crate) mod writer;
+pub mod reference_sequence_resolver;
+pub mod writer;
+
+#[cfg(test)]
+mod test_util;
 
 pub use self::{
-    data_container::DataContainer, file_definition::FileDefinition, indexer::index, reader::Reader,
-    record::Record, writer::Writer,
+    container::Header as ContainerHeader, data_container::DataContainer,
+    file_definition::FileDefinition, indexer::index, reader::Reader, record::Record,
+    reference_sequence_resolver::ReferenceSequenceResolver, writer::Writer,
 };
 
 #[cfg(feature = "async")]
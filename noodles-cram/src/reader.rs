@@ -1,12 +1,17 @@
 //! CRAM reader and record iterator.
 
+pub mod block_cache;
 pub(crate) mod container;
 pub(crate) mod data_container;
 pub(crate) mod num;
+mod query;
+mod raw_containers;
 pub(crate) mod record;
 mod records;
 
-pub use self::records::Records;
+pub use self::{
+    block_cache::BlockCache, query::Query, raw_containers::RawContainers, records::Records,
+};
 
 use std::{
     io::{self, Read, Seek, SeekFrom},
@@ -14,12 +19,13 @@ use std::{
 };
 
 use byteorder::{LittleEndian, ReadBytesExt};
-use bytes::BytesMut;
+use bytes::{Bytes, BytesMut};
+use noodles_core::Region;
 use noodles_fasta as fasta;
 use noodles_sam as sam;
 
-use self::container::read_container;
-use super::{container::Block, file_definition::Version, FileDefinition, MAGIC_NUMBER};
+use self::container::{read_container, read_raw_container};
+use super::{container::Block, crai, file_definition::Version, FileDefinition, MAGIC_NUMBER};
 use crate::data_container::DataContainer;
 
 /// A CRAM reader.
@@ -54,6 +60,10 @@ where
 {
     inner: R,
     buf: BytesMut,
+    block_cache: Option<BlockCache>,
+    max_read_length: Option<usize>,
+    version: Version,
+    allow_zstd_compression: bool,
 }
 
 impl<R> Reader<R>
@@ -74,9 +84,119 @@ where
         Self {
             inner: reader,
             buf: BytesMut::new(),
+            block_cache: None,
+            max_read_length: None,
+            version: Version::default(),
+            allow_zstd_compression: false,
+        }
+    }
+
+    /// Creates a CRAM reader with a cache of decompressed slice blocks.
+    ///
+    /// The cache holds at most `capacity` blocks. It is used by queries, which avoids
+    /// decompressing the same slice blocks again when querying overlapping regions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let data = [];
+    /// let reader = cram::Reader::with_block_cache_capacity(&data[..], 64);
+    /// assert_eq!(reader.block_cache().map(|cache| cache.capacity()), Some(64));
+    /// ```
+    pub fn with_block_cache_capacity(reader: R, capacity: usize) -> Self {
+        Self {
+            inner: reader,
+            buf: BytesMut::new(),
+            block_cache: Some(BlockCache::new(capacity)),
+            max_read_length: None,
+            version: Version::default(),
+            allow_zstd_compression: false,
         }
     }
 
+    /// Returns the cache of decompressed slice blocks, if enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let data = [];
+    /// let reader = cram::Reader::new(&data[..]);
+    /// assert!(reader.block_cache().is_none());
+    /// ```
+    pub fn block_cache(&self) -> Option<&BlockCache> {
+        self.block_cache.as_ref()
+    }
+
+    /// Returns the maximum read length of a record, if set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let data = [];
+    /// let reader = cram::Reader::new(&data[..]);
+    /// assert!(reader.max_read_length().is_none());
+    /// ```
+    pub fn max_read_length(&self) -> Option<usize> {
+        self.max_read_length
+    }
+
+    /// Sets the maximum read length of a record.
+    ///
+    /// Reading a record that declares a larger read length fails with an
+    /// [`io::ErrorKind::InvalidData`] error before its bases or quality scores are allocated. This
+    /// guards against malformed input. By default, there is no maximum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let data = [];
+    /// let mut reader = cram::Reader::new(&data[..]);
+    /// reader.set_max_read_length(1 << 20);
+    /// assert_eq!(reader.max_read_length(), Some(1 << 20));
+    /// ```
+    pub fn set_max_read_length(&mut self, max_read_length: usize) {
+        self.max_read_length = Some(max_read_length);
+    }
+
+    /// Returns whether blocks compressed with zstd are accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let data = [];
+    /// let reader = cram::Reader::new(&data[..]);
+    /// assert!(!reader.allow_zstd_compression());
+    /// ```
+    pub fn allow_zstd_compression(&self) -> bool {
+        self.allow_zstd_compression
+    }
+
+    /// Sets whether blocks compressed with zstd are accepted.
+    ///
+    /// zstd (method ID 9) is not part of the CRAM specification, so blocks that use it are
+    /// rejected with an [`io::ErrorKind::InvalidData`] error by default, regardless of the file
+    /// definition version. Enable this to read CRAM files written with
+    /// [`crate::writer::Builder::allow_zstd_compression`]. Decompressing such blocks also
+    /// requires the `zstd` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let data = [];
+    /// let mut reader = cram::Reader::new(&data[..]);
+    /// reader.set_allow_zstd_compression(true);
+    /// assert!(reader.allow_zstd_compression());
+    /// ```
+    pub fn set_allow_zstd_compression(&mut self, allow_zstd_compression: bool) {
+        self.allow_zstd_compression = allow_zstd_compression;
+    }
+
     /// Returns a reference to the underlying reader.
     ///
     /// # Examples
@@ -125,6 +245,13 @@ where
     ///
     /// The position of the stream is expected to be at the start.
     ///
+    /// The file definition version determines how the subsequent containers are read, e.g., CRAM
+    /// 2.x containers and blocks do not have CRC32 checksums, and compression methods added in CRAM
+    /// 3.0 or 3.1 are rejected in older versions. If this is not called, CRAM 3.0 is assumed.
+    ///
+    /// This returns an error if the version is not supported, i.e., a version outside of CRAM 2.0
+    /// to 3.1.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -138,8 +265,12 @@ where
         read_magic_number(&mut self.inner)?;
 
         let format = read_format(&mut self.inner)?;
+        validate_version(format)?;
+
         let file_id = read_file_id(&mut self.inner)?;
 
+        self.version = format;
+
         Ok(FileDefinition::new(format, file_id))
     }
 
@@ -151,6 +282,9 @@ where
     /// This returns the raw SAM header as a [`String`]. It can subsequently be parsed as a
     /// [`noodles_sam::Header`].
     ///
+    /// If the header container has no blocks or the SAM header block is empty, this returns an
+    /// empty string, which parses as an empty SAM header.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -164,15 +298,17 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn read_file_header(&mut self) -> io::Result<String> {
-        let container = read_container(&mut self.inner, &mut self.buf)?;
+        let container = read_container(
+            &mut self.inner,
+            &mut self.buf,
+            self.version,
+            self.allow_zstd_compression,
+        )?;
 
         if let Some(block) = container.blocks().first() {
             read_file_header_block(block)
         } else {
-            Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                "invalid header container: missing block for SAM header",
-            ))
+            Ok(String::new())
         }
     }
 
@@ -180,7 +316,12 @@ where
         &mut self,
     ) -> io::Result<Option<(crate::container::Header, DataContainer)>> {
         use self::data_container::read_data_container_with_container_header;
-        read_data_container_with_container_header(&mut self.inner, &mut self.buf)
+        read_data_container_with_container_header(
+            &mut self.inner,
+            &mut self.buf,
+            self.version,
+            self.allow_zstd_compression,
+        )
     }
 
     /// Reads a data container.
@@ -206,7 +347,64 @@ where
     pub fn read_data_container(&mut self) -> io::Result<Option<DataContainer>> {
         use self::data_container::read_data_container;
 
-        read_data_container(&mut self.inner, &mut self.buf)
+        read_data_container(
+            &mut self.inner,
+            &mut self.buf,
+            self.version,
+            self.allow_zstd_compression,
+        )
+    }
+
+    /// Reads a container without decoding its blocks.
+    ///
+    /// This returns the container header and its undecoded payload, i.e., the raw blocks of the
+    /// container. `None` is returned if the container header is the EOF container header, which
+    /// signals the end of the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::Reader::new)?;
+    /// reader.read_file_definition()?;
+    /// reader.read_file_header()?;
+    ///
+    /// while let Some((header, payload)) = reader.read_raw_container()? {
+    ///     // ...
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_raw_container(&mut self) -> io::Result<Option<(crate::ContainerHeader, Bytes)>> {
+        read_raw_container(&mut self.inner, &mut self.buf, self.version)
+    }
+
+    /// Returns an iterator over raw containers starting from the current stream position.
+    ///
+    /// Each item is a container header and its undecoded payload. This is useful for copying or
+    /// indexing containers without decoding their slices.
+    ///
+    /// The stream is expected to be at the start of a data container.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::Reader::new)?;
+    /// reader.read_file_definition()?;
+    /// reader.read_file_header()?;
+    ///
+    /// for result in reader.raw_containers() {
+    ///     let (header, payload) = result?;
+    ///     println!("{} {}", header.record_count(), payload.len());
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn raw_containers(&mut self) -> RawContainers<'_, R> {
+        RawContainers::new(self)
     }
 
     /// Returns a iterator over records starting from the current stream position.
@@ -281,6 +479,62 @@ where
     pub fn position(&mut self) -> io::Result<u64> {
         self.inner.seek(SeekFrom::Current(0))
     }
+
+    /// Returns an iterator over records that intersect the given region.
+    ///
+    /// The index is used to find the slices that may contain records in the region. If the block
+    /// cache is enabled (see [`Self::with_block_cache_capacity`]), decompressed slice blocks are
+    /// reused across queries.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_cram::{self as cram, crai};
+    /// use noodles_fasta as fasta;
+    /// use noodles_sam as sam;
+    ///
+    /// let repository = fasta::Repository::default();
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::Reader::new)?;
+    /// reader.read_file_definition()?;
+    /// let header: sam::Header = reader.read_file_header()?.parse()?;
+    ///
+    /// let index = crai::read("sample.cram.crai")?;
+    /// let region = "sq0:8-13".parse()?;
+    /// let query = reader.query(&repository, &header, &index, &region)?;
+    ///
+    /// for result in query {
+    ///     let record = result?;
+    ///     println!("{:?}", record);
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query<'a>(
+        &'a mut self,
+        reference_sequence_repository: &'a fasta::Repository,
+        header: &'a sam::Header,
+        index: &crai::Index,
+        region: &Region,
+    ) -> io::Result<Query<'a, R>> {
+        let reference_sequence_id = resolve_region(header.reference_sequences(), region)?;
+        let interval = region.interval();
+
+        let index_records = index
+            .iter()
+            .filter(|r| query::index_record_intersects(r, reference_sequence_id, interval))
+            .cloned()
+            .collect();
+
+        Ok(Query::new(
+            self,
+            reference_sequence_repository,
+            header,
+            index_records,
+            reference_sequence_id,
+            interval,
+        ))
+    }
 }
 
 impl<R> sam::AlignmentReader for Reader<R>
@@ -336,6 +590,23 @@ where
     Ok(Version::new(buf[0], buf[1]))
 }
 
+pub(crate) fn validate_version(version: Version) -> io::Result<()> {
+    const MIN_VERSION: Version = Version::new(2, 0);
+    const MAX_VERSION: Version = Version::new(3, 1);
+
+    if (MIN_VERSION..=MAX_VERSION).contains(&version) {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "unsupported CRAM version: expected {} to {}, got {}",
+                MIN_VERSION, MAX_VERSION, version
+            ),
+        ))
+    }
+}
+
 fn read_file_id<R>(reader: &mut R) -> io::Result<[u8; 20]>
 where
     R: Read,
@@ -345,6 +616,23 @@ where
     Ok(buf)
 }
 
+fn resolve_region(
+    reference_sequences: &sam::header::ReferenceSequences,
+    region: &Region,
+) -> io::Result<usize> {
+    reference_sequences
+        .get_index_of(region.name())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "region reference sequence does not exist in reference sequences: {:?}",
+                    region
+                ),
+            )
+        })
+}
+
 pub(crate) fn read_file_header_block(block: &Block) -> io::Result<String> {
     use crate::container::block::ContentType;
 
@@ -360,6 +648,11 @@ pub(crate) fn read_file_header_block(block: &Block) -> io::Result<String> {
     }
 
     let data = block.decompressed_data()?;
+
+    if data.is_empty() {
+        return Ok(String::new());
+    }
+
     let mut reader = &data[..];
 
     let _header_len = reader.read_i32::<LittleEndian>()?;
@@ -371,7 +664,7 @@ pub(crate) fn read_file_header_block(block: &Block) -> io::Result<String> {
 
 #[cfg(test)]
 mod tests {
-    use crate::container::block::ContentType;
+    use crate::{container::block::ContentType, test_util::build_header_and_repository};
 
     use super::*;
 
@@ -395,6 +688,879 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_file_definition_with_cram_2_1() -> Result<(), Box<dyn std::error::Error>> {
+        let data = [
+            0x43, 0x52, 0x41, 0x4d, // magic number = b"CRAM"
+            0x02, 0x01, // format version = (2, 1)
+            0x00, 0x68, 0xac, 0xf3, 0x06, 0x4d, 0xaa, 0x1e, 0x29, 0xa4, 0xa0, 0x8c, 0x56, 0xee,
+            0x91, 0x9b, 0x91, 0x04, 0x21, 0x1f, // file ID
+            // container header
+            0x14, 0x00, 0x00, 0x00, // length = 20
+            0x00, // reference sequence ID = 0
+            0x00, // starting position on the reference = 0
+            0x00, // alignment span = 0
+            0x00, // number of records = 0
+            0x00, // record counter = 0
+            0x00, // bases = 0
+            0x01, // number of blocks = 1
+            0x00, // landmarks.len = 0
+            // file header block
+            0x00, // compression method = none (0)
+            0x00, // content type = file header (0)
+            0x00, // block content ID = 0
+            0x0f, // size in bytes = 15 bytes
+            0x0f, // raw size in bytes = 15 bytes
+            0x0b, 0x00, 0x00, 0x00, // header length = 11
+            b'@', b'H', b'D', b'\t', b'V', b'N', b':', b'1', b'.', b'6', b'\n', // header
+        ];
+
+        let mut reader = Reader::new(&data[..]);
+        let file_definition = reader.read_file_definition()?;
+        assert_eq!(file_definition.version(), Version::new(2, 1));
+
+        let header = reader.read_file_header()?;
+        assert_eq!(header, "@HD\tVN:1.6\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_file_definition_with_unsupported_version() {
+        let data = [
+            0x43, 0x52, 0x41, 0x4d, // magic number = b"CRAM"
+            0x04, 0x00, // format version = (4, 0)
+            0x00, 0x68, 0xac, 0xf3, 0x06, 0x4d, 0xaa, 0x1e, 0x29, 0xa4, 0xa0, 0x8c, 0x56, 0xee,
+            0x91, 0x9b, 0x91, 0x04, 0x21, 0x1f, // file ID
+        ];
+
+        let mut reader = Reader::new(&data[..]);
+
+        assert!(matches!(
+            reader.read_file_definition(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_raw_containers() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{Record, Writer};
+
+        let header = sam::Header::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        // This is greater than the maximum number of records per slice, which forces a second
+        // container.
+        for _ in 0..10241 {
+            writer.write_record(&header, Record::default())?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let mut raw_container_count = 0;
+        let mut record_count = 0;
+
+        for result in reader.raw_containers() {
+            let (container_header, payload) = result?;
+            assert_eq!(payload.len(), container_header.len());
+            raw_container_count += 1;
+            record_count += container_header.record_count();
+        }
+
+        assert_eq!(record_count, 10241);
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let mut data_container_count = 0;
+
+        while reader.read_data_container()?.is_some() {
+            data_container_count += 1;
+        }
+
+        assert_eq!(raw_container_count, 2);
+        assert_eq!(raw_container_count, data_container_count);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_reverse_complemented_read() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_sam::{
+            record::{Flags, QualityScores, Sequence},
+            AlignmentRecord,
+        };
+
+        use crate::{record, Record, Writer};
+
+        let header = sam::Header::default();
+
+        // The read as sequenced.
+        let read_bases: Sequence = "AACGN".parse()?;
+        let read_quality_scores: QualityScores = "NDLS!".parse()?;
+
+        // SAM and CRAM store reverse-strand reads on the forward strand.
+        let mut bases = read_bases.clone();
+        bases.reverse_complement();
+        let mut quality_scores = read_quality_scores.clone();
+        quality_scores.reverse();
+
+        let record = Record::builder()
+            .set_bam_flags(Flags::UNMAPPED | Flags::REVERSE_COMPLEMENTED)
+            .set_flags(record::Flags::QUALITY_SCORES_STORED_AS_ARRAY)
+            .set_read_length(bases.len())
+            .set_bases(bases.clone())
+            .set_quality_scores(quality_scores.clone())
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+        writer.write_record(&header, record)?;
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let repository = fasta::Repository::default();
+        let records: Vec<_> = reader
+            .records(&repository, &header)
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(records.len(), 1);
+
+        let actual = &records[0];
+        assert!(actual.bam_flags().is_reverse_complemented());
+        assert_eq!(actual.bases(), &bases);
+        assert_eq!(actual.quality_scores(), &quality_scores);
+
+        let mut actual_read_bases = actual.bases().clone();
+        actual_read_bases.reverse_complement();
+        assert_eq!(actual_read_bases, read_bases);
+
+        let mut actual_read_quality_scores = actual.quality_scores().clone();
+        actual_read_quality_scores.reverse();
+        assert_eq!(actual_read_quality_scores, read_quality_scores);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_unknown_sequence() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_sam::{record::Flags, AlignmentRecord};
+
+        use crate::{record, Record, Writer};
+
+        let header = sam::Header::default();
+
+        let record = Record::builder()
+            .set_bam_flags(Flags::UNMAPPED)
+            .set_flags(record::Flags::DECODE_SEQUENCE_AS_UNKNOWN)
+            .set_read_length(4)
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+        writer.write_record(&header, record)?;
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let repository = fasta::Repository::default();
+        let records: Vec<_> = reader
+            .records(&repository, &header)
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(records.len(), 1);
+
+        let actual = &records[0];
+        assert!(actual.cram_flags().decode_sequence_as_unknown());
+        assert_eq!(actual.read_length(), 4);
+        assert!(actual.bases().is_empty());
+
+        let sam_record = actual.try_into_sam_record(&header)?;
+        assert!(sam_record.sequence().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_missing_quality_scores() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_sam::{record::Flags, AlignmentRecord};
+
+        use crate::{record, Record, Writer};
+
+        let header = sam::Header::default();
+
+        let record = Record::builder()
+            .set_bam_flags(Flags::UNMAPPED)
+            .set_flags(record::Flags::QUALITY_SCORES_STORED_AS_ARRAY)
+            .set_read_length(4)
+            .set_bases("ACGT".parse()?)
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+        writer.write_record(&header, record)?;
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let repository = fasta::Repository::default();
+        let records: Vec<_> = reader
+            .records(&repository, &header)
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(records.len(), 1);
+
+        let actual = &records[0];
+        assert!(actual.cram_flags().are_quality_scores_stored_as_array());
+        assert!(actual.quality_scores().is_empty());
+
+        let sam_record = actual.try_into_sam_record(&header)?;
+        assert!(sam_record.quality_scores().is_empty());
+        assert_eq!(
+            sam_record.to_string(),
+            "*\t4\t*\t0\t255\t*\t*\t0\t0\tACGT\t*"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_read_length_greater_than_max() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_sam::record::Flags;
+
+        use crate::{reader::record::ReadRecordError, record, Record, Writer};
+
+        const READ_LENGTH: usize = 1 << 30;
+
+        let header = sam::Header::default();
+
+        // The sequence is unknown, so no bases or quality scores are written for the read length.
+        let record = Record::builder()
+            .set_bam_flags(Flags::UNMAPPED)
+            .set_flags(record::Flags::DECODE_SEQUENCE_AS_UNKNOWN)
+            .set_read_length(READ_LENGTH)
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+        writer.write_record(&header, record)?;
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+        let repository = fasta::Repository::default();
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+        let records: Vec<_> = reader
+            .records(&repository, &header)
+            .collect::<io::Result<_>>()?;
+        assert_eq!(records[0].read_length(), READ_LENGTH);
+
+        let mut reader = Reader::new(&data[..]);
+        reader.set_max_read_length(1 << 20);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let result: io::Result<Vec<_>> = reader.records(&repository, &header).collect();
+
+        match result {
+            Err(e) => {
+                assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+                assert_eq!(
+                    e.into_inner()
+                        .and_then(|e| e.downcast::<ReadRecordError>().ok())
+                        .map(|e| *e),
+                    Some(ReadRecordError::ReadLengthTooLarge {
+                        max: 1 << 20,
+                        actual: READ_LENGTH,
+                    })
+                );
+            }
+            Ok(_) => panic!("expected an error"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_without_preserved_read_names() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_sam::{record::Flags, AlignmentRecord};
+
+        use crate::{Record, Writer};
+
+        let header = sam::Header::default();
+
+        let records = [
+            Record::builder()
+                .set_bam_flags(Flags::UNMAPPED)
+                .set_read_name("r0".parse()?)
+                .build(),
+            Record::builder()
+                .set_bam_flags(Flags::SEGMENTED | Flags::UNMAPPED | Flags::MATE_UNMAPPED)
+                .set_read_name("r1".parse()?)
+                .build(),
+        ];
+
+        let mut writer = Writer::builder(Vec::new())
+            .preserve_read_names(false)
+            .build();
+
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        for record in records {
+            writer.write_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let repository = fasta::Repository::default();
+        let records: Vec<_> = reader
+            .records(&repository, &header)
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(records.len(), 2);
+
+        assert!(!records[0].cram_flags().is_detached());
+        assert!(records[0].read_name().is_none());
+
+        assert!(records[1].cram_flags().is_detached());
+        assert_eq!(records[1].read_name(), Some(&"r1".parse()?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_without_alignment_start_deltas() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+        use noodles_sam::AlignmentRecord;
+
+        use crate::{Record, Writer};
+
+        const REFERENCE_SEQUENCE: &[u8] = b"ACGTACGTACGTACGT";
+
+        let (header, repository) = build_header_and_repository(&["sq0"], REFERENCE_SEQUENCE)?;
+
+        let alignment_starts = [
+            Position::try_from(9)?,
+            Position::try_from(3)?,
+            Position::try_from(13)?,
+            Position::try_from(1)?,
+        ];
+
+        let mut writer = Writer::builder(Vec::new())
+            .set_reference_sequence_repository(repository.clone())
+            .encode_alignment_start_positions_as_deltas(false)
+            .build();
+
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        for &alignment_start in &alignment_starts {
+            let record = Record::builder()
+                .set_bam_flags(sam::record::Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(alignment_start)
+                .set_read_length(4)
+                .build();
+
+            writer.write_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let data_container = reader
+            .read_data_container()?
+            .expect("missing data container");
+
+        assert!(!data_container
+            .compression_header()
+            .preservation_map()
+            .ap_data_series_delta());
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let actual: Vec<_> = reader
+            .records(&repository, &header)
+            .map(|result| result.map(|record| record.alignment_start()))
+            .collect::<io::Result<_>>()?;
+
+        let expected: Vec<_> = alignment_starts.into_iter().map(Some).collect();
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    fn write_records_on_two_reference_sequences(
+        allow_multi_reference_slices: bool,
+    ) -> Result<(sam::Header, fasta::Repository, Vec<u8>), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+
+        use crate::{Record, Writer};
+
+        const REFERENCE_SEQUENCE: &[u8] = b"ACGTACGTACGTACGT";
+
+        let (header, repository) =
+            build_header_and_repository(&["sq0", "sq1"], REFERENCE_SEQUENCE)?;
+
+        let mut writer = Writer::builder(Vec::new())
+            .set_reference_sequence_repository(repository.clone())
+            .allow_multi_reference_slices(allow_multi_reference_slices)
+            .build();
+
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        for (reference_sequence_id, alignment_start) in [(0, 5), (1, 3)] {
+            let record = Record::builder()
+                .set_bam_flags(sam::record::Flags::empty())
+                .set_reference_sequence_id(reference_sequence_id)
+                .set_alignment_start(Position::try_from(alignment_start)?)
+                .set_read_length(4)
+                .build();
+
+            writer.write_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        Ok((header, repository, writer.get_ref().clone()))
+    }
+
+    #[test]
+    fn test_read_data_container_with_single_reference_slices(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use crate::container::ReferenceSequenceId;
+
+        let (_, _, data) = write_records_on_two_reference_sequences(false)?;
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let mut actual = Vec::new();
+
+        while let Some(data_container) = reader.read_data_container()? {
+            let reference_sequence_ids: Vec<_> = data_container
+                .slices()
+                .iter()
+                .map(|slice| slice.header().reference_sequence_id())
+                .collect();
+
+            actual.push(reference_sequence_ids);
+        }
+
+        let expected = [
+            vec![ReferenceSequenceId::Some(0)],
+            vec![ReferenceSequenceId::Some(1)],
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_raw_container_record_counters() -> Result<(), Box<dyn std::error::Error>> {
+        let (_, _, data) = write_records_on_two_reference_sequences(false)?;
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let mut actual = Vec::new();
+
+        while let Some((header, _)) = reader.read_raw_container()? {
+            if header.is_eof() {
+                break;
+            }
+
+            actual.push((header.record_counter(), header.record_count()));
+        }
+
+        assert_eq!(actual, [(0, 1), (1, 1)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_container_with_zstd_block() -> Result<(), Box<dyn std::error::Error>> {
+        let (_, _, mut data) = write_records_on_two_reference_sequences(false)?;
+
+        let compression_method_position = {
+            let mut reader = Reader::new(&data[..]);
+            reader.read_file_definition()?;
+            reader.read_file_header()?;
+
+            let mut src = *reader.get_ref();
+            container::read_header(&mut src, Version::new(3, 0))?;
+
+            data.len() - src.len()
+        };
+
+        // Sets the compression header block compression method to zstd (9).
+        data[compression_method_position] = 0x09;
+
+        let mut reader = Reader::new(&data[..]);
+        assert_eq!(reader.read_file_definition()?.version(), Version::new(3, 0));
+        reader.read_file_header()?;
+
+        assert!(matches!(
+            reader.read_data_container(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_container_with_multi_reference_slice(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+        use noodles_sam::AlignmentRecord;
+
+        use crate::container::ReferenceSequenceId;
+
+        let (header, repository, data) = write_records_on_two_reference_sequences(true)?;
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let data_container = reader
+            .read_data_container()?
+            .expect("missing data container");
+
+        assert!(!data_container
+            .compression_header()
+            .preservation_map()
+            .ap_data_series_delta());
+
+        let reference_sequence_ids: Vec<_> = data_container
+            .slices()
+            .iter()
+            .map(|slice| slice.header().reference_sequence_id())
+            .collect();
+
+        assert_eq!(reference_sequence_ids, [ReferenceSequenceId::Many]);
+        assert!(reader.read_data_container()?.is_none());
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let actual: Vec<_> = reader
+            .records(&repository, &header)
+            .map(|result| {
+                result.map(|record| (record.reference_sequence_id(), record.alignment_start()))
+            })
+            .collect::<io::Result<_>>()?;
+
+        let expected = [
+            (Some(0), Some(Position::try_from(5)?)),
+            (Some(1), Some(Position::try_from(3)?)),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_container_with_reference_md5() -> Result<(), Box<dyn std::error::Error>> {
+        use md5::{Digest, Md5};
+        use noodles_core::Position;
+
+        use crate::{Record, Writer};
+
+        const REFERENCE_SEQUENCE: &[u8] = b"ACGTACGTACGTACGT";
+
+        let (header, repository) = build_header_and_repository(&["sq0"], REFERENCE_SEQUENCE)?;
+
+        let mut writer = Writer::builder(Vec::new())
+            .set_reference_sequence_repository(repository)
+            .build();
+
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        for alignment_start in [3, 5] {
+            let record = Record::builder()
+                .set_bam_flags(sam::record::Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(alignment_start)?)
+                .set_read_length(4)
+                .build();
+
+            writer.write_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let data_container = reader
+            .read_data_container()?
+            .expect("missing data container");
+
+        let slice_header = data_container.slices()[0].header();
+
+        // The slice spans the reference sequence positions 3 to 8, inclusive.
+        let expected: [u8; 16] = Md5::digest(&REFERENCE_SEQUENCE[2..8]).into();
+        assert_eq!(slice_header.reference_md5(), expected);
+        assert_ne!(slice_header.reference_md5(), [0; 16]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_reference_sequence_resolver() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{env, fs, process};
+
+        use md5::{Digest, Md5};
+        use noodles_core::Position;
+        use noodles_sam::header::reference_sequence::Md5Checksum;
+
+        use crate::{
+            reference_sequence_resolver::{Adapter, FileSystemCache},
+            Record, Writer,
+        };
+
+        const REFERENCE_SEQUENCE: &[u8] = b"ACGTACGTACGTACGT";
+
+        let md5_checksum = Md5Checksum::from(<[u8; 16]>::from(Md5::digest(REFERENCE_SEQUENCE)));
+
+        let (header, repository) = build_header_and_repository(&["sq0"], REFERENCE_SEQUENCE)?;
+
+        let mut writer = Writer::builder(Vec::new())
+            .set_reference_sequence_repository(repository)
+            .build();
+
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        let record = Record::builder()
+            .set_bam_flags(sam::record::Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(3)?)
+            .set_read_length(4)
+            .build();
+
+        writer.write_record(&header, record)?;
+        writer.try_finish(&header)?;
+
+        let cache_dir = env::temp_dir().join(format!("noodles-cram-{}", process::id()));
+        fs::create_dir_all(&cache_dir)?;
+        fs::write(cache_dir.join(md5_checksum.to_string()), REFERENCE_SEQUENCE)?;
+
+        let read_bases = |repository: &fasta::Repository| -> io::Result<Vec<String>> {
+            let data = writer.get_ref();
+            let mut reader = Reader::new(&data[..]);
+            reader.read_file_definition()?;
+            reader.read_file_header()?;
+
+            reader
+                .records(repository, &header)
+                .map(|result| result.map(|record| record.bases().to_string()))
+                .collect()
+        };
+
+        let repository =
+            fasta::Repository::new(Adapter::new(&header, FileSystemCache::new(&cache_dir)));
+        let result = read_bases(&repository);
+
+        // The fallback repository is empty, so the reference sequence is resolved by MD5
+        // checksum.
+        let repository = fasta::Repository::new(Adapter::with_repository(
+            fasta::Repository::default(),
+            &header,
+            FileSystemCache::new(&cache_dir),
+        ));
+        let fallback_result = read_bases(&repository);
+
+        fs::remove_dir_all(&cache_dir)?;
+
+        assert_eq!(result?, ["GTAC"]);
+        assert_eq!(fallback_result?, ["GTAC"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_with_block_cache() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Cursor;
+
+        use noodles_core::Position;
+        use noodles_sam::AlignmentRecord;
+
+        use crate::{indexer::index_reader, Record, Writer};
+
+        const REFERENCE_SEQUENCE: &[u8] = b"ACGTACGTACGTACGTACGTACGT";
+
+        let (header, repository) = build_header_and_repository(&["sq0"], REFERENCE_SEQUENCE)?;
+
+        let mut writer = Writer::builder(Vec::new())
+            .set_reference_sequence_repository(repository.clone())
+            .build();
+
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        for alignment_start in [1, 5, 9, 13, 17] {
+            let record = Record::builder()
+                .set_bam_flags(sam::record::Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(alignment_start)?)
+                .set_read_length(4)
+                .build();
+
+            writer.write_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref().clone();
+
+        let index = index_reader(&mut Reader::new(Cursor::new(&data)))?;
+        assert_eq!(index.len(), 1);
+
+        let mut reader = Reader::new(Cursor::new(&data));
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+        let (_, data_container) = reader
+            .read_data_container_with_container_header()?
+            .expect("missing data container");
+        let block_count = data_container.slices()[0].external_blocks().len() + 1;
+
+        let mut reader = Reader::with_block_cache_capacity(Cursor::new(&data), 64);
+
+        let region = "sq0:6-10".parse()?;
+        let alignment_starts: Vec<_> = reader
+            .query(&repository, &header, &index, &region)?
+            .map(|result| result.map(|record| record.alignment_start()))
+            .collect::<io::Result<_>>()?;
+        assert_eq!(alignment_starts, [Position::new(5), Position::new(9)]);
+
+        let block_cache = reader.block_cache().expect("missing block cache");
+        assert_eq!(block_cache.misses(), block_count as u64);
+        assert_eq!(block_cache.hits(), 0);
+
+        let region = "sq0:8-14".parse()?;
+        let alignment_starts: Vec<_> = reader
+            .query(&repository, &header, &index, &region)?
+            .map(|result| result.map(|record| record.alignment_start()))
+            .collect::<io::Result<_>>()?;
+        assert_eq!(
+            alignment_starts,
+            [Position::new(5), Position::new(9), Position::new(13)]
+        );
+
+        let block_cache = reader.block_cache().expect("missing block cache");
+        assert_eq!(block_cache.misses(), block_count as u64);
+        assert_eq!(block_cache.hits(), block_count as u64);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_with_merged_index() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Cursor;
+
+        use noodles_core::Position;
+        use noodles_sam::AlignmentRecord;
+
+        use crate::{crai, indexer::index_reader, Record, Writer};
+
+        const REFERENCE_SEQUENCE: &[u8] = b"ACGTACGTACGTACGTACGTACGT";
+
+        // 1 full slice and 1 partial slice in the same container
+        const RECORD_COUNT: usize = 10240 + 4;
+
+        let (header, repository) = build_header_and_repository(&["sq0"], REFERENCE_SEQUENCE)?;
+
+        let mut writer = Writer::builder(Vec::new())
+            .set_reference_sequence_repository(repository.clone())
+            .set_slices_per_container(2)
+            .build();
+
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        for i in 0..RECORD_COUNT {
+            let alignment_start = if i < 10240 { 1 } else { 17 };
+
+            let record = Record::builder()
+                .set_bam_flags(sam::record::Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(alignment_start)?)
+                .set_read_length(4)
+                .build();
+
+            writer.write_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref().clone();
+
+        let index = index_reader(&mut Reader::new(Cursor::new(&data)))?;
+        assert_eq!(index.len(), 2);
+
+        let index = crai::merge(index);
+        assert_eq!(index.len(), 1);
+
+        let mut reader = Reader::new(Cursor::new(&data));
+
+        let region = "sq0:17-20".parse()?;
+        let alignment_starts: Vec<_> = reader
+            .query(&repository, &header, &index, &region)?
+            .map(|result| result.map(|record| record.alignment_start()))
+            .collect::<io::Result<_>>()?;
+        assert_eq!(alignment_starts, [Position::new(17); 4]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_file_header_block() -> io::Result<()> {
         use bytes::BufMut;
@@ -421,6 +1587,21 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_file_header_block_with_empty_data() -> Result<(), Box<dyn std::error::Error>> {
+        let block = Block::builder()
+            .set_content_type(ContentType::FileHeader)
+            .build();
+
+        let actual = read_file_header_block(&block)?;
+        assert!(actual.is_empty());
+
+        let header: noodles_sam::Header = actual.parse()?;
+        assert!(header.is_empty());
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_file_header_block_with_invalid_content_type() {
         let block = Block::builder()
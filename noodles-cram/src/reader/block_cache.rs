@@ -0,0 +1,185 @@
+//! CRAM reader block cache.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    io,
+};
+
+use bytes::Bytes;
+
+use crate::container::Block;
+
+/// A block cache key.
+///
+/// This is the container position, the slice landmark (the position of the slice relative to
+/// the container data), and the block content ID. The content ID is `None` for the core data
+/// block.
+pub(crate) type Key = (u64, usize, Option<i32>);
+
+struct Entry {
+    data: Bytes,
+    last_used: u64,
+}
+
+/// A least recently used (LRU) cache of decompressed slice blocks.
+///
+/// Blocks are keyed by their container position, slice, and content ID. When a query reads the
+/// same slice more than once, cached blocks are not decompressed again.
+pub struct BlockCache {
+    capacity: usize,
+    entries: HashMap<Key, Entry>,
+    // Keys ordered by last use, oldest first.
+    recency: BTreeMap<u64, Key>,
+    clock: u64,
+    hits: u64,
+    misses: u64,
+}
+
+impl BlockCache {
+    /// Creates a block cache that holds at most `capacity` decompressed blocks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::reader::BlockCache;
+    /// let cache = BlockCache::new(64);
+    /// assert_eq!(cache.capacity(), 64);
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            clock: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Returns the maximum number of blocks the cache holds.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of cached blocks.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the number of lookups that were found in the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Returns the number of lookups that were not found in the cache.
+    ///
+    /// Each miss decompresses a block.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Removes all cached blocks.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+
+    pub(crate) fn get_or_decompress(&mut self, key: Key, block: &Block) -> io::Result<Bytes> {
+        self.clock += 1;
+
+        if let Some(entry) = self.entries.get_mut(&key) {
+            self.recency.remove(&entry.last_used);
+            self.recency.insert(self.clock, key);
+            entry.last_used = self.clock;
+            self.hits += 1;
+            return Ok(entry.data.clone());
+        }
+
+        self.misses += 1;
+
+        let data = block.decompressed_data()?;
+
+        if self.capacity > 0 {
+            if self.entries.len() >= self.capacity {
+                self.evict();
+            }
+
+            self.entries.insert(
+                key,
+                Entry {
+                    data: data.clone(),
+                    last_used: self.clock,
+                },
+            );
+
+            self.recency.insert(self.clock, key);
+        }
+
+        Ok(data)
+    }
+
+    fn evict(&mut self) {
+        let lru = self
+            .recency
+            .iter()
+            .next()
+            .map(|(last_used, key)| (*last_used, *key));
+
+        if let Some((last_used, key)) = lru {
+            self.recency.remove(&last_used);
+            self.entries.remove(&key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::container::block::{CompressionMethod, ContentType};
+
+    fn build_block(content_id: i32, data: &[u8]) -> Block {
+        Block::builder()
+            .set_compression_method(CompressionMethod::None)
+            .set_content_type(ContentType::ExternalData)
+            .set_content_id(content_id)
+            .set_uncompressed_len(data.len())
+            .set_data(Bytes::copy_from_slice(data))
+            .build()
+    }
+
+    #[test]
+    fn test_get_or_decompress() -> io::Result<()> {
+        let mut cache = BlockCache::new(2);
+
+        let a = build_block(1, b"a");
+        let b = build_block(2, b"b");
+        let c = build_block(3, b"c");
+
+        assert_eq!(&cache.get_or_decompress((0, 0, Some(1)), &a)?[..], b"a");
+        assert_eq!(&cache.get_or_decompress((0, 0, Some(2)), &b)?[..], b"b");
+        assert_eq!(&cache.get_or_decompress((0, 0, Some(1)), &a)?[..], b"a");
+        assert_eq!((cache.hits(), cache.misses()), (1, 2));
+
+        // This evicts the least recently used block (2).
+        cache.get_or_decompress((0, 0, Some(3)), &c)?;
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.recency.len(), 2);
+
+        cache.get_or_decompress((0, 0, Some(1)), &a)?;
+        assert_eq!((cache.hits(), cache.misses()), (2, 3));
+
+        cache.get_or_decompress((0, 0, Some(2)), &b)?;
+        assert_eq!((cache.hits(), cache.misses()), (2, 4));
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert!(cache.recency.is_empty());
+
+        Ok(())
+    }
+}
@@ -5,15 +5,20 @@ pub use self::{block::read_block, header::read_header};
 
 use std::io::{self, Read};
 
-use bytes::BytesMut;
+use bytes::{Bytes, BytesMut};
 
-use crate::Container;
+use crate::{container::Header, file_definition::Version, Container};
 
-pub fn read_container<R>(reader: &mut R, buf: &mut BytesMut) -> io::Result<Container>
+pub fn read_container<R>(
+    reader: &mut R,
+    buf: &mut BytesMut,
+    version: Version,
+    allow_zstd: bool,
+) -> io::Result<Container>
 where
     R: Read,
 {
-    let header = read_header(reader)?;
+    let header = read_header(reader, version)?;
 
     buf.resize(header.len(), 0);
     reader.read_exact(buf)?;
@@ -23,9 +28,30 @@ where
     let mut blocks = Vec::with_capacity(blocks_len);
 
     for _ in 0..blocks_len {
-        let block = read_block(&mut buf)?;
+        let block = read_block(&mut buf, version, allow_zstd)?;
         blocks.push(block);
     }
 
     Ok(Container::new(header, blocks))
 }
+
+pub fn read_raw_container<R>(
+    reader: &mut R,
+    buf: &mut BytesMut,
+    version: Version,
+) -> io::Result<Option<(Header, Bytes)>>
+where
+    R: Read,
+{
+    let header = read_header(reader, version)?;
+
+    if header.is_eof() {
+        return Ok(None);
+    }
+
+    buf.resize(header.len(), 0);
+    reader.read_exact(buf)?;
+    let buf = buf.split().freeze();
+
+    Ok(Some((header, buf)))
+}
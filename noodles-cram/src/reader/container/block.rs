@@ -7,10 +7,11 @@ use crate::{
         block::{CompressionMethod, ContentType},
         Block,
     },
+    file_definition::Version,
     reader::num::get_itf8,
 };
 
-pub fn read_block(src: &mut Bytes) -> io::Result<Block> {
+pub fn read_block(src: &mut Bytes, version: Version, allow_zstd: bool) -> io::Result<Block> {
     if !src.has_remaining() {
         return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
     }
@@ -18,6 +19,8 @@ pub fn read_block(src: &mut Bytes) -> io::Result<Block> {
     let method = CompressionMethod::try_from(src.get_u8())
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
+    validate_compression_method(method, version, allow_zstd)?;
+
     if !src.has_remaining() {
         return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
     }
@@ -41,11 +44,16 @@ pub fn read_block(src: &mut Bytes) -> io::Result<Block> {
 
     let data = src.split_to(size_in_bytes);
 
-    if src.remaining() < mem::size_of::<u32>() {
-        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
-    }
+    // CRAM 2.x blocks do not have a CRC32 checksum.
+    let crc32 = if version.major() >= 3 {
+        if src.remaining() < mem::size_of::<u32>() {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+        }
 
-    let crc32 = src.get_u32_le();
+        src.get_u32_le()
+    } else {
+        0
+    };
 
     Ok(Block::builder()
         .set_compression_method(method)
@@ -57,6 +65,41 @@ pub fn read_block(src: &mut Bytes) -> io::Result<Block> {
         .build())
 }
 
+fn validate_compression_method(
+    method: CompressionMethod,
+    version: Version,
+    allow_zstd: bool,
+) -> io::Result<()> {
+    let min_version = match method {
+        // zstd is not part of the CRAM specification and is only accepted when explicitly allowed.
+        CompressionMethod::Zstd if allow_zstd => return Ok(()),
+        CompressionMethod::Zstd => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid block compression method: zstd is not part of the CRAM specification",
+            ))
+        }
+        CompressionMethod::Lzma | CompressionMethod::Rans4x8 => Version::new(3, 0),
+        CompressionMethod::RansNx16
+        | CompressionMethod::AdaptiveArithmeticCoding
+        | CompressionMethod::Fqzcomp
+        | CompressionMethod::NameTokenizer => Version::new(3, 1),
+        _ => return Ok(()),
+    };
+
+    if version >= min_version {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "invalid block compression method: {:?} requires CRAM {}, got {}",
+                method, min_version, version
+            ),
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use bytes::Bytes;
@@ -74,7 +117,7 @@ mod tests {
             0x6e, 0x64, 0x6c, 0x73, // data = b"ndls",
             0xfd, 0x38, 0x27, 0xb5, // CRC32
         ]);
-        let actual = read_block(&mut data)?;
+        let actual = read_block(&mut data, Version::default(), false)?;
 
         let expected = Block::builder()
             .set_compression_method(CompressionMethod::None)
@@ -89,4 +132,86 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_block_with_cram_2_1() -> io::Result<()> {
+        let mut data = Bytes::from_static(&[
+            0x00, // compression method = none (0)
+            0x04, // content type = external data (4)
+            0x01, // block content ID = 1
+            0x04, // size in bytes = 4 bytes
+            0x04, // raw size in bytes = 4 bytes
+            0x6e, 0x64, 0x6c, 0x73, // data = b"ndls",
+        ]);
+        let actual = read_block(&mut data, Version::new(2, 1), false)?;
+
+        let expected = Block::builder()
+            .set_compression_method(CompressionMethod::None)
+            .set_content_type(ContentType::ExternalData)
+            .set_content_id(1)
+            .set_uncompressed_len(4)
+            .set_data(Bytes::from_static(b"ndls"))
+            .build();
+
+        assert_eq!(actual, expected);
+        assert!(!data.has_remaining());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_block_with_unsupported_compression_method() {
+        let data = Bytes::from_static(&[
+            0x05, // compression method = rANS Nx16 (5)
+            0x04, // content type = external data (4)
+            0x01, // block content ID = 1
+            0x00, // size in bytes = 0 bytes
+            0x00, // raw size in bytes = 0 bytes
+            0x00, 0x00, 0x00, 0x00, // CRC32
+        ]);
+
+        assert!(matches!(
+            read_block(&mut data.clone(), Version::new(3, 0), false),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(read_block(&mut data.clone(), Version::new(3, 1), false).is_ok());
+
+        let data = Bytes::from_static(&[
+            0x04, // compression method = rANS 4x8 (4)
+            0x04, // content type = external data (4)
+            0x01, // block content ID = 1
+            0x00, // size in bytes = 0 bytes
+            0x00, // raw size in bytes = 0 bytes
+        ]);
+
+        assert!(matches!(
+            read_block(&mut data.clone(), Version::new(2, 1), false),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_read_block_with_zstd_compression_method() {
+        let data = Bytes::from_static(&[
+            0x09, // compression method = zstd (9)
+            0x04, // content type = external data (4)
+            0x01, // block content ID = 1
+            0x00, // size in bytes = 0 bytes
+            0x00, // raw size in bytes = 0 bytes
+            0x00, 0x00, 0x00, 0x00, // CRC32
+        ]);
+
+        assert!(matches!(
+            read_block(&mut data.clone(), Version::new(3, 0), false),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(matches!(
+            read_block(&mut data.clone(), Version::new(3, 1), false),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(read_block(&mut data.clone(), Version::new(3, 0), true).is_ok());
+    }
 }
@@ -6,10 +6,11 @@ use byteorder::{LittleEndian, ReadBytesExt};
 
 use crate::{
     container::{Header, ReferenceSequenceId},
+    file_definition::Version,
     reader::num::{read_itf8, read_ltf8},
 };
 
-pub fn read_header<R>(reader: &mut R) -> io::Result<Header>
+pub fn read_header<R>(reader: &mut R, version: Version) -> io::Result<Header>
 where
     R: Read,
 {
@@ -38,7 +39,13 @@ where
     })?;
 
     let landmarks = read_landmarks(reader)?;
-    let crc32 = reader.read_u32::<LittleEndian>()?;
+
+    // CRAM 2.x container headers do not have a CRC32 checksum.
+    let crc32 = if version.major() >= 3 {
+        reader.read_u32::<LittleEndian>()?
+    } else {
+        0
+    };
 
     let mut builder = Header::builder()
         .set_length(length)
@@ -100,7 +107,7 @@ mod tests {
             0xb4, 0x9f, 0x9c, 0xda, // CRC32
         ];
         let mut reader = &data[..];
-        let actual = read_header(&mut reader)?;
+        let actual = read_header(&mut reader, Version::default())?;
 
         let expected = Header::builder()
             .set_length(144)
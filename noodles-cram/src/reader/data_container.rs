@@ -8,16 +8,21 @@ use std::io::{self, Read};
 use bytes::{Bytes, BytesMut};
 
 use super::container;
-use crate::{container::block::ContentType, data_container::CompressionHeader, DataContainer};
+use crate::{
+    container::block::ContentType, data_container::CompressionHeader, file_definition::Version,
+    DataContainer,
+};
 
 pub fn read_data_container<R>(
     reader: &mut R,
     buf: &mut BytesMut,
+    version: Version,
+    allow_zstd: bool,
 ) -> io::Result<Option<DataContainer>>
 where
     R: Read,
 {
-    let header = container::read_header(reader)?;
+    let header = container::read_header(reader, version)?;
 
     if header.is_eof() {
         return Ok(None);
@@ -27,13 +32,13 @@ where
     reader.read_exact(buf)?;
     let mut buf = buf.split().freeze();
 
-    let compression_header = read_compression_header_from_block(&mut buf)?;
+    let compression_header = read_compression_header_from_block(&mut buf, version, allow_zstd)?;
 
     let slice_count = header.landmarks().len();
     let mut slices = Vec::with_capacity(slice_count);
 
     for _ in 0..slice_count {
-        let slice = read_slice(&mut buf)?;
+        let slice = read_slice(&mut buf, version, allow_zstd)?;
         slices.push(slice);
     }
 
@@ -43,11 +48,13 @@ where
 pub fn read_data_container_with_container_header<R>(
     reader: &mut R,
     buf: &mut BytesMut,
+    version: Version,
+    allow_zstd: bool,
 ) -> io::Result<Option<(crate::container::Header, DataContainer)>>
 where
     R: Read,
 {
-    let header = container::read_header(reader)?;
+    let header = container::read_header(reader, version)?;
 
     if header.is_eof() {
         return Ok(None);
@@ -57,13 +64,13 @@ where
     reader.read_exact(buf)?;
     let mut buf = buf.split().freeze();
 
-    let compression_header = read_compression_header_from_block(&mut buf)?;
+    let compression_header = read_compression_header_from_block(&mut buf, version, allow_zstd)?;
 
     let slice_count = header.landmarks().len();
     let mut slices = Vec::with_capacity(slice_count);
 
     for _ in 0..slice_count {
-        let slice = read_slice(&mut buf)?;
+        let slice = read_slice(&mut buf, version, allow_zstd)?;
         slices.push(slice);
     }
 
@@ -72,10 +79,14 @@ where
     Ok(Some((header, data_container)))
 }
 
-pub(crate) fn read_compression_header_from_block(src: &mut Bytes) -> io::Result<CompressionHeader> {
+pub(crate) fn read_compression_header_from_block(
+    src: &mut Bytes,
+    version: Version,
+    allow_zstd: bool,
+) -> io::Result<CompressionHeader> {
     use super::container::read_block;
 
-    let block = read_block(src)?;
+    let block = read_block(src, version, allow_zstd)?;
 
     if block.content_type() != ContentType::CompressionHeader {
         return Err(io::Error::new(
@@ -237,4 +237,28 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_get_substitution_matrix() -> io::Result<()> {
+        let mut data = &[0x1b, 0x1b, 0x1b, 0x1b, 0x1b][..];
+        assert_eq!(
+            get_substitution_matrix(&mut data)?,
+            SubstitutionMatrix::default()
+        );
+
+        let mut data = &[0x1b, 0x1b, 0x1b, 0x1b][..];
+        assert!(matches!(
+            get_substitution_matrix(&mut data),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        // Codes for reference base G are not distinct (0x1f = 0b00_01_11_11).
+        let mut data = &[0x1b, 0x1b, 0x1f, 0x1b, 0x1b][..];
+        assert!(matches!(
+            get_substitution_matrix(&mut data),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
 }
@@ -9,22 +9,27 @@ use bytes::Bytes;
 use crate::{
     container::{block::ContentType, Block},
     data_container::{slice, Slice},
+    file_definition::Version,
     reader::container::read_block,
 };
 
-pub fn read_slice(src: &mut Bytes) -> io::Result<Slice> {
-    let header = read_header_from_block(src)?;
+pub fn read_slice(src: &mut Bytes, version: Version, allow_zstd: bool) -> io::Result<Slice> {
+    let header = read_header_from_block(src, version, allow_zstd)?;
 
-    let core_data_block = read_core_data_block(src)?;
+    let core_data_block = read_core_data_block(src, version, allow_zstd)?;
 
     let external_block_count = header.block_count() - 1;
-    let external_blocks = read_external_blocks(src, external_block_count)?;
+    let external_blocks = read_external_blocks(src, version, allow_zstd, external_block_count)?;
 
     Ok(Slice::new(header, core_data_block, external_blocks))
 }
 
-fn read_header_from_block(src: &mut Bytes) -> io::Result<slice::Header> {
-    let block = read_block(src)?;
+fn read_header_from_block(
+    src: &mut Bytes,
+    version: Version,
+    allow_zstd: bool,
+) -> io::Result<slice::Header> {
+    let block = read_block(src, version, allow_zstd)?;
 
     if block.content_type() != ContentType::SliceHeader {
         return Err(io::Error::new(
@@ -41,8 +46,8 @@ fn read_header_from_block(src: &mut Bytes) -> io::Result<slice::Header> {
     get_header(&mut data)
 }
 
-fn read_core_data_block(src: &mut Bytes) -> io::Result<Block> {
-    let block = read_block(src)?;
+fn read_core_data_block(src: &mut Bytes, version: Version, allow_zstd: bool) -> io::Result<Block> {
+    let block = read_block(src, version, allow_zstd)?;
 
     if block.content_type() != ContentType::CoreData {
         return Err(io::Error::new(
@@ -58,11 +63,16 @@ fn read_core_data_block(src: &mut Bytes) -> io::Result<Block> {
     Ok(block)
 }
 
-fn read_external_blocks(src: &mut Bytes, len: usize) -> io::Result<Vec<Block>> {
+fn read_external_blocks(
+    src: &mut Bytes,
+    version: Version,
+    allow_zstd: bool,
+    len: usize,
+) -> io::Result<Vec<Block>> {
     let mut external_blocks = Vec::with_capacity(len);
 
     for _ in 0..len {
-        let block = read_block(src)?;
+        let block = read_block(src, version, allow_zstd)?;
 
         if block.content_type() != ContentType::ExternalData {
             return Err(io::Error::new(
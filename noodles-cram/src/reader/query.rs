@@ -0,0 +1,202 @@
+use std::{
+    io::{self, Read, Seek, SeekFrom},
+    ptr, vec,
+};
+
+use noodles_core::{
+    region::{self, Interval},
+    Position,
+};
+use noodles_fasta as fasta;
+use noodles_sam::{self as sam, AlignmentRecord};
+
+use super::Reader;
+use crate::{crai, Record};
+
+/// An iterator over records of a CRAM reader that intersects a given region.
+///
+/// This is created by calling [`Reader::query`].
+pub struct Query<'a, R>
+where
+    R: Read + Seek,
+{
+    reader: &'a mut Reader<R>,
+
+    reference_sequence_repository: &'a fasta::Repository,
+    header: &'a sam::Header,
+
+    index: vec::IntoIter<crai::Record>,
+
+    reference_sequence_id: usize,
+    interval: Interval,
+
+    records: vec::IntoIter<Record>,
+}
+
+impl<'a, R> Query<'a, R>
+where
+    R: Read + Seek,
+{
+    pub(super) fn new(
+        reader: &'a mut Reader<R>,
+        reference_sequence_repository: &'a fasta::Repository,
+        header: &'a sam::Header,
+        index: Vec<crai::Record>,
+        reference_sequence_id: usize,
+        interval: Interval,
+    ) -> Self {
+        Self {
+            reader,
+
+            reference_sequence_repository,
+            header,
+
+            index: index.into_iter(),
+
+            reference_sequence_id,
+            interval,
+
+            records: Vec::new().into_iter(),
+        }
+    }
+
+    // Reads and resolves the records of the slices described by the given index record.
+    //
+    // An index record usually describes a single slice, but a merged index record (see
+    // [`crai::merge`]) spans consecutive slices of the same container. Every slice that starts in
+    // `[landmark, landmark + slice_length)` is read.
+    fn read_slice_records(&mut self, index_record: &crai::Record) -> io::Result<()> {
+        let container_position = index_record.offset();
+
+        self.reader.seek(SeekFrom::Start(container_position))?;
+
+        let (container_header, data_container) = self
+            .reader
+            .read_data_container_with_container_header()?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing data container"))?;
+
+        let start = usize::try_from(index_record.landmark())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let end = usize::try_from(index_record.slice_length())
+            .ok()
+            .and_then(|len| start.checked_add(len))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid slice length"))?;
+
+        let landmarks = container_header.landmarks();
+
+        if !landmarks.contains(&start) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid slice landmark",
+            ));
+        }
+
+        let compression_header = data_container.compression_header();
+        let max_read_length = self.reader.max_read_length;
+
+        let mut records = Vec::new();
+
+        for (&landmark, slice) in landmarks.iter().zip(data_container.slices()) {
+            if landmark < start || landmark >= end {
+                continue;
+            }
+
+            let mut slice_records = match self.reader.block_cache.as_mut() {
+                Some(block_cache) => slice.records_with_decompressor(
+                    compression_header,
+                    max_read_length,
+                    |block| {
+                        let content_id = if ptr::eq(block, slice.core_data_block()) {
+                            None
+                        } else {
+                            Some(block.content_id())
+                        };
+
+                        block_cache
+                            .get_or_decompress((container_position, landmark, content_id), block)
+                    },
+                )?,
+                None => slice.records_with_decompressor(
+                    compression_header,
+                    max_read_length,
+                    |block| block.decompressed_data(),
+                )?,
+            };
+
+            slice.resolve_records(
+                self.reference_sequence_repository,
+                self.header,
+                compression_header,
+                &mut slice_records,
+            )?;
+
+            records.extend(slice_records);
+        }
+
+        self.records = records.into_iter();
+
+        Ok(())
+    }
+}
+
+impl<'a, R> Iterator for Query<'a, R>
+where
+    R: Read + Seek,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(record) = self.records.next() {
+                if intersects(&record, self.reference_sequence_id, self.interval) {
+                    return Some(Ok(record));
+                }
+
+                continue;
+            }
+
+            let index_record = self.index.next()?;
+
+            if let Err(e) = self.read_slice_records(&index_record) {
+                return Some(Err(e));
+            }
+        }
+    }
+}
+
+pub(crate) fn index_record_intersects(
+    index_record: &crai::Record,
+    reference_sequence_id: usize,
+    interval: Interval,
+) -> bool {
+    if index_record.reference_sequence_id() != Some(reference_sequence_id) {
+        return false;
+    }
+
+    match index_record.alignment_start() {
+        Some(start) => {
+            let end = usize::from(start)
+                .checked_add(index_record.alignment_span())
+                .and_then(|n| n.checked_sub(1))
+                .and_then(Position::new)
+                .unwrap_or(start);
+
+            region::overlaps(start, end, interval)
+        }
+        None => false,
+    }
+}
+
+fn intersects(record: &Record, reference_sequence_id: usize, interval: Interval) -> bool {
+    match (
+        record.reference_sequence_id(),
+        record.alignment_start(),
+        record.alignment_end(),
+    ) {
+        (Some(id), Some(start), Some(end)) => {
+            id == reference_sequence_id && region::overlaps(start, end, interval)
+        }
+        _ => false,
+    }
+}
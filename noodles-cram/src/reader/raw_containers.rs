@@ -0,0 +1,36 @@
+use std::io::{self, Read};
+
+use bytes::Bytes;
+
+use super::Reader;
+use crate::container::Header;
+
+/// An iterator over raw containers of a CRAM reader.
+///
+/// This is created by calling [`Reader::raw_containers`].
+pub struct RawContainers<'a, R>
+where
+    R: Read,
+{
+    reader: &'a mut Reader<R>,
+}
+
+impl<'a, R> RawContainers<'a, R>
+where
+    R: Read,
+{
+    pub(crate) fn new(reader: &'a mut Reader<R>) -> Self {
+        Self { reader }
+    }
+}
+
+impl<'a, R> Iterator for RawContainers<'a, R>
+where
+    R: Read,
+{
+    type Item = io::Result<(Header, Bytes)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader.read_raw_container().transpose()
+    }
+}
@@ -26,7 +26,7 @@ use crate::{
     huffman::CanonicalHuffmanDecoder,
     record::{
         feature::{self, substitution},
-        Feature, Flags, NextMateFlags,
+        Feature, Flags, NextMateFlags, MISSING_QUALITY_SCORE,
     },
     BitReader, Record,
 };
@@ -37,6 +37,7 @@ pub enum ReadRecordError {
     MissingDataSeriesEncoding(DataSeries),
     MissingTagEncoding(tag_ids_dictionary::Key),
     MissingExternalBlock(i32),
+    ReadLengthTooLarge { max: usize, actual: usize },
 }
 
 impl error::Error for ReadRecordError {}
@@ -51,6 +52,11 @@ impl fmt::Display for ReadRecordError {
             Self::MissingExternalBlock(block_content_id) => {
                 write!(f, "missing external block: {}", block_content_id)
             }
+            Self::ReadLengthTooLarge { max, actual } => write!(
+                f,
+                "read length too large: expected <= {}, got {}",
+                max, actual
+            ),
         }
     }
 }
@@ -65,6 +71,7 @@ where
     external_data_readers: ExternalDataReaders<EDR>,
     reference_sequence_id: ReferenceSequenceId,
     prev_alignment_start: Option<Position>,
+    max_read_length: Option<usize>,
 }
 
 impl<'a, CDR, EDR> Reader<'a, CDR, EDR>
@@ -85,9 +92,18 @@ where
             external_data_readers,
             reference_sequence_id,
             prev_alignment_start: initial_alignment_start,
+            max_read_length: None,
         }
     }
 
+    /// Sets the maximum read length of a record.
+    ///
+    /// Records with a larger read length fail to read before any bases or quality scores are
+    /// allocated.
+    pub fn set_max_read_length(&mut self, max_read_length: Option<usize>) {
+        self.max_read_length = max_read_length;
+    }
+
     pub fn read_record(&mut self) -> io::Result<Record> {
         let bam_bit_flags = self.read_bam_bit_flags()?;
         let cram_bit_flags = self.read_cram_bit_flags()?;
@@ -195,12 +211,25 @@ where
             .data_series_encoding_map()
             .read_lengths_encoding();
 
-        decode_itf8(
+        let read_length = decode_itf8(
             encoding,
             &mut self.core_data_reader,
             &mut self.external_data_readers,
         )
-        .and_then(|n| usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)))
+        .and_then(|n| {
+            usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })?;
+
+        match self.max_read_length {
+            Some(max) if read_length > max => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                ReadRecordError::ReadLengthTooLarge {
+                    max,
+                    actual: read_length,
+                },
+            )),
+            _ => Ok(read_length),
+        }
     }
 
     fn read_alignment_start(&mut self) -> io::Result<Option<Position>> {
@@ -525,12 +554,7 @@ where
         record.mapping_quality = self.read_mapping_quality()?;
 
         if flags.are_quality_scores_stored_as_array() {
-            record.quality_scores.as_mut().reserve(read_length);
-
-            for _ in 0..read_length {
-                let score = self.read_quality_score()?;
-                record.quality_scores.push(score);
-            }
+            self.read_quality_scores_stored_as_array(record, read_length)?;
         }
 
         Ok(())
@@ -732,7 +756,51 @@ where
         .and_then(|n| Base::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)))
     }
 
+    // Missing quality scores are stored as a run of `MISSING_QUALITY_SCORE` and are read as an
+    // empty list.
+    fn read_quality_scores_stored_as_array(
+        &mut self,
+        record: &mut Record,
+        read_length: usize,
+    ) -> io::Result<()> {
+        // Scores are decoded directly into the record. A leading run of missing markers is only
+        // counted, as it is either the entire array or invalid.
+        let mut missing_count = 0;
+
+        for i in 0..read_length {
+            let n = self.read_quality_score_byte()?;
+
+            if n == MISSING_QUALITY_SCORE && missing_count == i {
+                missing_count += 1;
+                continue;
+            }
+
+            if missing_count > 0 {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "invalid quality scores: missing and present scores are mixed",
+                ));
+            }
+
+            if i == 0 {
+                record.quality_scores.as_mut().reserve(read_length);
+            }
+
+            let score =
+                Score::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            record.quality_scores.push(score);
+        }
+
+        Ok(())
+    }
+
     fn read_quality_score(&mut self) -> io::Result<Score> {
+        self.read_quality_score_byte().and_then(|n| {
+            Score::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+    }
+
+    fn read_quality_score_byte(&mut self) -> io::Result<u8> {
         let encoding = self
             .compression_header
             .data_series_encoding_map()
@@ -749,7 +817,6 @@ where
             &mut self.core_data_reader,
             &mut self.external_data_readers,
         )
-        .and_then(|n| Score::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)))
     }
 
     fn read_base_substitution_code(&mut self) -> io::Result<substitution::Value> {
@@ -935,20 +1002,17 @@ where
         flags: Flags,
         read_length: usize,
     ) -> io::Result<()> {
-        record.bases.as_mut().reserve(read_length);
+        if !flags.decode_sequence_as_unknown() {
+            record.bases.as_mut().reserve(read_length);
 
-        for _ in 0..read_length {
-            let base = self.read_base()?;
-            record.bases.push(base);
+            for _ in 0..read_length {
+                let base = self.read_base()?;
+                record.bases.push(base);
+            }
         }
 
         if flags.are_quality_scores_stored_as_array() {
-            record.quality_scores.as_mut().reserve(read_length);
-
-            for _ in 0..read_length {
-                let score = self.read_quality_score()?;
-                record.quality_scores.push(score);
-            }
+            self.read_quality_scores_stored_as_array(record, read_length)?;
         }
 
         Ok(())
@@ -989,7 +1053,10 @@ where
                 decoder.decode(core_data_reader).map(|i| i as u8)
             }
         }
-        _ => todo!("decode_byte: {:?}", encoding),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("decode_byte: unhandled encoding: {:?}", encoding),
+        )),
     }
 }
 
@@ -1024,7 +1091,10 @@ where
             }
         }
         Encoding::Beta(offset, len) => core_data_reader.read_u32(*len).map(|i| (i as i32 - offset)),
-        _ => todo!("decode_itf8: {:?}", encoding),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("decode_itf8: unhandled encoding: {:?}", encoding),
+        )),
     }
 }
 
@@ -1100,7 +1170,10 @@ where
 
             Ok(buf)
         }
-        _ => todo!("decode_byte_array: {:?}", encoding),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("decode_byte_array: unhandled encoding: {:?}", encoding),
+        )),
     }
 }
 
@@ -7,11 +7,14 @@ use noodles_fasta as fasta;
 use noodles_sam as sam;
 
 use super::Reader;
-use crate::Record;
+use crate::{DataContainer, Record};
 
 /// An iterator over records of a CRAM reader.
 ///
 /// This is created by calling [`Reader::records`].
+///
+/// Records are decoded one slice at a time, i.e., at most the records of a single slice are
+/// buffered.
 pub struct Records<'a, R>
 where
     R: Read,
@@ -19,6 +22,8 @@ where
     reader: &'a mut Reader<R>,
     reference_sequence_repository: &'a fasta::Repository,
     header: &'a sam::Header,
+    container: Option<DataContainer>,
+    slice_index: usize,
     records: vec::IntoIter<Record>,
 }
 
@@ -35,40 +40,51 @@ where
             reader,
             reference_sequence_repository,
             header,
+            container: None,
+            slice_index: 0,
             records: Vec::new().into_iter(),
         }
     }
 
-    fn read_container_records(&mut self) -> io::Result<bool> {
-        let container = match self.reader.read_data_container()? {
+    fn read_container(&mut self) -> io::Result<bool> {
+        self.container = self.reader.read_data_container()?;
+        self.slice_index = 0;
+        Ok(self.container.is_none())
+    }
+
+    // Decodes the records of the next slice in the current container. This returns `false` if the
+    // current container has no more slices.
+    fn read_slice_records(&mut self) -> io::Result<bool> {
+        let container = match self.container.as_ref() {
             Some(c) => c,
-            None => return Ok(true),
+            None => return Ok(false),
+        };
+
+        let slice = match container.slices().get(self.slice_index) {
+            Some(s) => s,
+            None => return Ok(false),
         };
 
-        self.records = container
-            .slices()
-            .iter()
-            .map(|slice| {
-                let compression_header = container.compression_header();
-
-                slice.records(compression_header).and_then(|mut records| {
-                    slice.resolve_records(
-                        self.reference_sequence_repository,
-                        self.header,
-                        compression_header,
-                        &mut records,
-                    )?;
-
-                    Ok(records)
-                })
-            })
-            .collect::<Result<Vec<_>, _>>()?
-            .into_iter()
-            .flatten()
-            .collect::<Vec<_>>()
-            .into_iter();
-
-        Ok(false)
+        self.slice_index += 1;
+
+        let compression_header = container.compression_header();
+
+        let mut records = slice.records_with_decompressor(
+            compression_header,
+            self.reader.max_read_length,
+            |block| block.decompressed_data(),
+        )?;
+
+        slice.resolve_records(
+            self.reference_sequence_repository,
+            self.header,
+            compression_header,
+            &mut records,
+        )?;
+
+        self.records = records.into_iter();
+
+        Ok(true)
     }
 }
 
@@ -80,14 +96,76 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            match self.records.next() {
-                Some(r) => return Some(Ok(r)),
-                None => match self.read_container_records() {
-                    Ok(true) => return None,
-                    Ok(false) => {}
-                    Err(e) => return Some(Err(e)),
-                },
+            if let Some(r) = self.records.next() {
+                return Some(Ok(r));
+            }
+
+            match self.read_slice_records() {
+                Ok(true) => continue,
+                Ok(false) => {}
+                Err(e) => return Some(Err(e)),
+            }
+
+            match self.read_container() {
+                Ok(true) => return None,
+                Ok(false) => {}
+                Err(e) => return Some(Err(e)),
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Writer;
+
+    #[test]
+    fn test_next_with_multiple_slices_and_containers() -> Result<(), Box<dyn std::error::Error>> {
+        const BASES: &str = "ACGTACGT";
+        // 2 containers, the first with 2 full slices and the second with 1 partial slice
+        const RECORD_COUNT: usize = 2 * 10240 + 8;
+
+        let header = sam::Header::default();
+
+        let mut writer = Writer::builder(Vec::new())
+            .set_slices_per_container(2)
+            .build();
+
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        for i in 0..RECORD_COUNT {
+            let read_length = i % 8 + 1;
+
+            let record = Record::builder()
+                .set_read_name(format!("r{}", i).parse()?)
+                .set_read_length(read_length)
+                .set_bases(BASES[..read_length].parse()?)
+                .build();
+
+            writer.write_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref().clone();
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let repository = fasta::Repository::default();
+        let mut n = 0;
+
+        for (i, result) in reader.records(&repository, &header).enumerate() {
+            let record = result?;
+            assert_eq!(record.read_length(), i % 8 + 1);
+            n += 1;
+        }
+
+        assert_eq!(n, RECORD_COUNT);
+
+        Ok(())
+    }
+}
@@ -3,7 +3,7 @@
 mod builder;
 mod convert;
 pub mod feature;
-mod features;
+pub mod features;
 mod flags;
 mod next_mate_flags;
 pub mod resolve;
@@ -19,6 +19,9 @@ use noodles_core::Position;
 use noodles_sam as sam;
 use once_cell::sync::OnceCell;
 
+// § 10.5 "Quality scores" (2021-10-15): "...missing quality scores are filled with 0xff".
+pub(crate) const MISSING_QUALITY_SCORE: u8 = 0xff;
+
 /// A CRAM record.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Record {
@@ -137,6 +140,42 @@ impl Record {
         &self.features
     }
 
+    /// Returns an iterator over read features with their reference and read positions.
+    ///
+    /// See [`Features::with_positions`]. This returns `None` if the record does not have an
+    /// alignment start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_cram::{self as cram, record::Feature};
+    ///
+    /// let record = cram::Record::builder()
+    ///     .set_alignment_start(Position::try_from(8)?)
+    ///     .set_read_length(4)
+    ///     .add_feature(Feature::Deletion(Position::try_from(3)?, 2))
+    ///     .build();
+    ///
+    /// let mut features = record.features_with_positions().expect("missing alignment start");
+    ///
+    /// assert_eq!(
+    ///     features.next(),
+    ///     Some((
+    ///         (Position::try_from(10)?, Position::try_from(3)?),
+    ///         &Feature::Deletion(Position::try_from(3)?, 2)
+    ///     ))
+    /// );
+    /// assert!(features.next().is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn features_with_positions(
+        &self,
+    ) -> Option<features::WithPositions<'_, std::slice::Iter<'_, Feature>>> {
+        self.alignment_start
+            .map(|alignment_start| self.features.with_positions(alignment_start))
+    }
+
     pub(crate) fn add_feature(&mut self, feature: Feature) {
         self.features.push(feature);
     }
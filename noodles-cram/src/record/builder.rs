@@ -4,7 +4,7 @@ use noodles_sam::{
     record::{quality_scores::Score, sequence::Base},
 };
 
-use super::{Feature, Features, Flags, NextMateFlags, Record};
+use super::{resolve::resolve_quality_scores, Feature, Features, Flags, NextMateFlags, Record};
 
 /// A CRAM record builder.
 pub struct Builder {
@@ -164,6 +164,94 @@ impl Builder {
         self
     }
 
+    /// Chooses how the quality scores are stored.
+    ///
+    /// Quality scores are stored as an array when most read positions need a stored quality score,
+    /// i.e., the score differs from what the other features resolve to (the default score or the
+    /// score of a read base feature). Otherwise, only those positions are stored as quality score
+    /// features. Unmapped records, records with missing quality scores, and records where the
+    /// number of quality scores or a read base feature does not fit the read length always use
+    /// array storage.
+    ///
+    /// This sets or clears the [`Flags::QUALITY_SCORES_STORED_AS_ARRAY`] flag and replaces any
+    /// existing quality score and scores features. It uses the current BAM flags, read length,
+    /// features, and quality scores, so call it after setting those.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_cram::record::{Feature, Record};
+    /// use noodles_sam::record::{quality_scores::Score, Flags};
+    ///
+    /// let record = Record::builder()
+    ///     .set_bam_flags(Flags::empty())
+    ///     .set_read_length(4)
+    ///     .set_alignment_start(Position::MIN)
+    ///     .set_quality_scores("!!5!".parse()?)
+    ///     .select_quality_scores_storage()
+    ///     .build();
+    ///
+    /// assert!(!record.cram_flags().are_quality_scores_stored_as_array());
+    /// assert_eq!(
+    ///     record.features().as_slice(),
+    ///     [Feature::QualityScore(Position::try_from(3)?, Score::try_from('5')?)]
+    /// );
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn select_quality_scores_storage(mut self) -> Self {
+        self.features
+            .retain(|feature| !matches!(feature, Feature::QualityScore(..) | Feature::Scores(..)));
+
+        let read_length = self.read_length;
+
+        let has_read_base_past_end = self.features.iter().any(|feature| {
+            matches!(feature, Feature::ReadBase(..))
+                && usize::from(feature.position()) > read_length
+        });
+
+        if self.bam_flags.is_unmapped()
+            || self.quality_scores.is_empty()
+            || self.quality_scores.len() != read_length
+            || has_read_base_past_end
+        {
+            self.flags.insert(Flags::QUALITY_SCORES_STORED_AS_ARRAY);
+            return self;
+        }
+
+        let resolved_quality_scores = resolve_quality_scores(&self.features, read_length);
+
+        let stored_scores: Vec<_> = self
+            .quality_scores
+            .as_ref()
+            .iter()
+            .zip(resolved_quality_scores.as_ref())
+            .enumerate()
+            .filter(|(_, (actual, resolved))| actual != resolved)
+            .map(|(i, (&score, _))| (i, score))
+            .collect();
+
+        if stored_scores.len() * 2 > self.quality_scores.len() {
+            self.flags.insert(Flags::QUALITY_SCORES_STORED_AS_ARRAY);
+            return self;
+        }
+
+        self.flags.remove(Flags::QUALITY_SCORES_STORED_AS_ARRAY);
+
+        for (i, score) in stored_scores {
+            let position = Position::MIN
+                .checked_add(i)
+                .expect("attempt to add with overflow");
+            let j = self
+                .features
+                .partition_point(|feature| feature.position() <= position);
+            self.features
+                .insert(j, Feature::QualityScore(position, score));
+        }
+
+        self
+    }
+
     /// Builds a CRAM record.
     pub fn build(self) -> Record {
         Record {
@@ -242,4 +330,114 @@ mod tests {
         assert!(builder.mapping_quality.is_none());
         assert!(builder.quality_scores.is_empty());
     }
+
+    #[test]
+    fn test_build_with_flags() {
+        let record = Builder::default()
+            .set_flags(Flags::QUALITY_SCORES_STORED_AS_ARRAY)
+            .build();
+        assert!(record.cram_flags().are_quality_scores_stored_as_array());
+
+        let record = Builder::default().set_flags(Flags::DETACHED).build();
+        assert!(record.cram_flags().is_detached());
+
+        let record = Builder::default()
+            .set_flags(Flags::HAS_MATE_DOWNSTREAM)
+            .build();
+        assert!(record.cram_flags().has_mate_downstream());
+
+        let record = Builder::default()
+            .set_flags(Flags::DECODE_SEQUENCE_AS_UNKNOWN)
+            .build();
+        assert!(record.cram_flags().decode_sequence_as_unknown());
+
+        let record = Builder::default().set_flags(Flags::all()).build();
+        let flags = record.cram_flags();
+        assert!(flags.are_quality_scores_stored_as_array());
+        assert!(flags.is_detached());
+        assert!(flags.has_mate_downstream());
+        assert!(flags.decode_sequence_as_unknown());
+    }
+
+    #[test]
+    fn test_select_quality_scores_storage() -> Result<(), Box<dyn std::error::Error>> {
+        let builder = Builder::default()
+            .set_bam_flags(sam::record::Flags::empty())
+            .set_read_length(4)
+            .add_feature(Feature::ReadBase(
+                Position::try_from(2)?,
+                Base::C,
+                Score::try_from('N')?,
+            ))
+            .add_feature(Feature::QualityScore(
+                Position::try_from(4)?,
+                Score::try_from('D')?,
+            ));
+
+        let record = builder
+            .set_quality_scores("!N5!".parse()?)
+            .select_quality_scores_storage()
+            .build();
+
+        assert!(!record.cram_flags().are_quality_scores_stored_as_array());
+        assert_eq!(
+            record.features().as_slice(),
+            [
+                Feature::ReadBase(Position::try_from(2)?, Base::C, Score::try_from('N')?),
+                Feature::QualityScore(Position::try_from(3)?, Score::try_from('5')?),
+            ]
+        );
+
+        let record = Builder::default()
+            .set_bam_flags(sam::record::Flags::empty())
+            .set_read_length(4)
+            .set_quality_scores("NDLS".parse()?)
+            .select_quality_scores_storage()
+            .build();
+
+        assert!(record.cram_flags().are_quality_scores_stored_as_array());
+        assert!(record.features().is_empty());
+
+        let record = Builder::default()
+            .set_read_length(4)
+            .set_quality_scores("!!!!".parse()?)
+            .select_quality_scores_storage()
+            .build();
+
+        assert!(record.cram_flags().are_quality_scores_stored_as_array());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_quality_scores_storage_with_read_length_mismatch(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        // fewer quality scores than the read length
+        let record = Builder::default()
+            .set_bam_flags(sam::record::Flags::empty())
+            .set_read_length(4)
+            .set_quality_scores("!!".parse()?)
+            .select_quality_scores_storage()
+            .build();
+
+        assert!(record.cram_flags().are_quality_scores_stored_as_array());
+        assert!(record.features().is_empty());
+
+        // a read base feature past the end of the read
+        let record = Builder::default()
+            .set_bam_flags(sam::record::Flags::empty())
+            .set_read_length(2)
+            .add_feature(Feature::ReadBase(
+                Position::try_from(4)?,
+                Base::C,
+                Score::try_from('N')?,
+            ))
+            .set_quality_scores("!!".parse()?)
+            .select_quality_scores_storage()
+            .build();
+
+        assert!(record.cram_flags().are_quality_scores_stored_as_array());
+
+        Ok(())
+    }
 }
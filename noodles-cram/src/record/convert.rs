@@ -66,6 +66,10 @@ impl Record {
             builder = builder.set_tags(data);
         }
 
+        if record.sequence().is_empty() {
+            flags.insert(Flags::DECODE_SEQUENCE_AS_UNKNOWN);
+        }
+
         builder = builder.set_bases(record.sequence().clone());
 
         if !bam_flags.is_unmapped() {
@@ -83,15 +87,12 @@ impl Record {
             builder = builder.set_mapping_quality(mapping_quality);
         }
 
-        if !record.quality_scores().is_empty() {
-            if bam_flags.is_unmapped() {
-                flags.insert(Flags::QUALITY_SCORES_STORED_AS_ARRAY);
-            }
+        builder = builder.set_quality_scores(record.quality_scores().clone());
 
-            builder = builder.set_quality_scores(record.quality_scores().clone());
-        }
-
-        Ok(builder.set_flags(flags).build())
+        Ok(builder
+            .set_flags(flags)
+            .select_quality_scores_storage()
+            .build())
     }
 
     /// Converts this CRAM record to a SAM record.
@@ -187,7 +188,12 @@ fn get_read_group_id(
 
     read_groups
         .get_index_of(read_group_name)
-        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid read group name"))
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid read group name: {}", read_group_name),
+            )
+        })
         .map(Some)
 }
 
@@ -232,3 +238,82 @@ fn build_data(
 
     Ok(data)
 }
+
+#[cfg(test)]
+mod tests {
+    use sam::{
+        header::ReadGroup,
+        record::data::{
+            field::{Tag, Value},
+            Field,
+        },
+    };
+
+    use super::*;
+
+    fn build_sam_record(read_group_name: &str) -> Result<sam::Record, Box<dyn std::error::Error>> {
+        let data = sam::record::Data::try_from(vec![
+            Field::new(Tag::ReadGroup, Value::String(read_group_name.into())),
+            Field::new(Tag::Comment, Value::String(String::from("noodles"))),
+        ])?;
+
+        Ok(sam::Record::builder().set_data(data).build())
+    }
+
+    #[test]
+    fn test_try_from_alignment_record_with_read_group() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_read_group(ReadGroup::new("rg0"))
+            .add_read_group(ReadGroup::new("rg1"))
+            .build();
+
+        let sam_record = build_sam_record("rg1")?;
+        let record = Record::try_from_alignment_record(&header, &sam_record)?;
+
+        assert_eq!(record.read_group_id(), Some(1));
+        assert!(record.tags().get(Tag::ReadGroup).is_none());
+
+        let actual = record.try_into_sam_record(&header)?;
+
+        assert_eq!(
+            actual.data().get(Tag::ReadGroup).map(|field| field.value()),
+            Some(&Value::String(String::from("rg1")))
+        );
+        assert!(actual.data().get(Tag::Comment).is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_alignment_record_with_unknown_sequence(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::default();
+
+        let sam_record = sam::Record::default();
+        let record = Record::try_from_alignment_record(&header, &sam_record)?;
+        assert!(record.cram_flags().decode_sequence_as_unknown());
+
+        let sam_record = sam::Record::builder().set_sequence("ACGT".parse()?).build();
+        let record = Record::try_from_alignment_record(&header, &sam_record)?;
+        assert!(!record.cram_flags().decode_sequence_as_unknown());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_alignment_record_with_unknown_read_group(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_read_group(ReadGroup::new("rg0"))
+            .build();
+
+        let sam_record = build_sam_record("rg1")?;
+
+        assert!(matches!(
+            Record::try_from_alignment_record(&header, &sam_record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
+//! CRAM record features.
+
 mod with_positions;
 
 pub use self::with_positions::WithPositions;
@@ -8,7 +10,7 @@ use std::{
 };
 
 use noodles_core::Position;
-use noodles_sam as sam;
+use noodles_sam::{self as sam, record::sequence::Base};
 
 use super::{Feature, Flags};
 
@@ -27,7 +29,30 @@ impl Features {
         cigar_to_features(flags, cigar, sequence, quality_scores)
     }
 
-    pub(crate) fn with_positions(
+    /// Returns an iterator over features with their reference and read positions.
+    ///
+    /// Each item is the 1-based (reference position, read position) pair at the start of the
+    /// feature and the feature itself. Positions between features advance as matches. Deletions
+    /// and reference skips advance only the reference position, and insertions and soft clips
+    /// advance only the read position. Quality score features are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_cram::record::{Feature, Features};
+    ///
+    /// let features = Features::from(vec![Feature::Deletion(Position::try_from(3)?, 2)]);
+    /// let mut iter = features.with_positions(Position::try_from(8)?);
+    ///
+    /// assert_eq!(
+    ///     iter.next(),
+    ///     Some(((Position::try_from(10)?, Position::try_from(3)?), &features[0]))
+    /// );
+    /// assert!(iter.next().is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn with_positions(
         &self,
         alignment_start: Position,
     ) -> WithPositions<'_, slice::Iter<'_, Feature>> {
@@ -69,16 +94,25 @@ fn cigar_to_features(
     for op in cigar.iter() {
         match op.kind() {
             Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
-                if op.len() == 1 {
+                let end = read_position
+                    .checked_add(op.len())
+                    .expect("attempt to add with overflow");
+
+                let bases = &sequence[read_position..end];
+
+                if bases.contains(&Base::Eq) {
+                    push_bases_with_reference_matches(
+                        &mut features,
+                        flags,
+                        read_position,
+                        bases,
+                        quality_scores,
+                    );
+                } else if op.len() == 1 {
                     let base = sequence[read_position];
                     let score = quality_scores[read_position];
                     features.push(Feature::ReadBase(read_position, base, score));
                 } else {
-                    let end = read_position
-                        .checked_add(op.len())
-                        .expect("attempt to add with overflow");
-
-                    let bases = &sequence[read_position..end];
                     features.push(Feature::Bases(read_position, bases.to_vec()));
 
                     if !flags.are_quality_scores_stored_as_array() {
@@ -151,6 +185,42 @@ fn cigar_to_features(
     features
 }
 
+// Bases that are equal to the reference base (`=`) are not stored as features. They are resolved
+// from the reference sequence when read.
+fn push_bases_with_reference_matches(
+    features: &mut Features,
+    flags: Flags,
+    read_position: Position,
+    bases: &[Base],
+    quality_scores: &sam::record::QualityScores,
+) {
+    if !flags.are_quality_scores_stored_as_array() {
+        let end = read_position
+            .checked_add(bases.len())
+            .expect("attempt to add with overflow");
+
+        let scores = &quality_scores[read_position..end];
+
+        if let [score] = scores {
+            features.push(Feature::QualityScore(read_position, *score));
+        } else {
+            features.push(Feature::Scores(read_position, scores.to_vec()));
+        }
+    }
+
+    let mut position = read_position;
+
+    for run in bases.split(|&base| base == Base::Eq) {
+        if !run.is_empty() {
+            features.push(Feature::Bases(position, run.to_vec()));
+        }
+
+        position = position
+            .checked_add(run.len() + 1)
+            .expect("attempt to add with overflow");
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -392,4 +462,42 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_cigar_to_features_with_reference_matches() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::record::{quality_scores::Score, QualityScores};
+
+        let cigar = "6M".parse()?;
+        let sequence = "GT=C==".parse()?;
+        let quality_scores: QualityScores = "NDLSNN".parse()?;
+
+        let flags = Flags::default();
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let expected = Features::from(vec![
+            Feature::Scores(Position::try_from(1)?, quality_scores.as_ref().to_vec()),
+            Feature::Bases(Position::try_from(1)?, vec![Base::G, Base::T]),
+            Feature::Bases(Position::try_from(4)?, vec![Base::C]),
+        ]);
+        assert_eq!(actual, expected);
+
+        let flags = Flags::QUALITY_SCORES_STORED_AS_ARRAY;
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let expected = Features::from(vec![
+            Feature::Bases(Position::try_from(1)?, vec![Base::G, Base::T]),
+            Feature::Bases(Position::try_from(4)?, vec![Base::C]),
+        ]);
+        assert_eq!(actual, expected);
+
+        let cigar = "1M".parse()?;
+        let sequence = "=".parse()?;
+        let quality_scores = "N".parse()?;
+        let actual = cigar_to_features(Flags::default(), &cigar, &sequence, &quality_scores);
+        let expected = Features::from(vec![Feature::QualityScore(
+            Position::try_from(1)?,
+            Score::try_from('N')?,
+        )]);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
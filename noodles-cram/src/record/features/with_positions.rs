@@ -2,6 +2,9 @@ use noodles_core::Position;
 
 use crate::record::Feature;
 
+/// An iterator over features with their reference and read positions.
+///
+/// This is created by calling [`super::Features::with_positions`].
 pub struct WithPositions<'a, I>
 where
     I: Iterator<Item = &'a Feature>,
@@ -15,7 +18,7 @@ impl<'a, I> WithPositions<'a, I>
 where
     I: Iterator<Item = &'a Feature>,
 {
-    pub fn new(iter: I, alignment_start: Position) -> Self {
+    pub(crate) fn new(iter: I, alignment_start: Position) -> Self {
         Self {
             iter,
             reference_position: alignment_start,
@@ -113,4 +116,37 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_next_with_insertion_and_deletion() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::{feature::Code, Features};
+        use noodles_sam::record::{quality_scores::Score, sequence::Base};
+
+        let features = Features::from(vec![
+            Feature::Insertion(Position::try_from(3)?, vec![Base::A, Base::C]),
+            Feature::QualityScore(Position::try_from(4)?, Score::try_from(8)?),
+            Feature::Deletion(Position::try_from(7)?, 2),
+            Feature::ReferenceSkip(Position::try_from(9)?, 3),
+        ]);
+
+        let actual: Vec<_> = WithPositions::new(features.iter(), Position::try_from(10)?)
+            .map(|((reference_position, read_position), feature)| {
+                (
+                    usize::from(reference_position),
+                    usize::from(read_position),
+                    feature.code(),
+                )
+            })
+            .collect();
+
+        let expected = [
+            (12, 3, Code::Insertion),
+            (14, 7, Code::Deletion),
+            (18, 9, Code::ReferenceSkip),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,434 @@
+//! CRAM reference sequence resolver.
+
+mod file_system_cache;
+
+pub use self::file_system_cache::FileSystemCache;
+
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fs::File,
+    io::{self, BufReader},
+    path::{Path, PathBuf},
+};
+
+use noodles_core::Region;
+use noodles_fasta::{self as fasta, fai};
+use noodles_sam::{self as sam, header::reference_sequence::Md5Checksum};
+
+use crate::data_container::slice::builder::calculate_normalized_sequence_digest;
+
+/// A reference sequence resolver.
+///
+/// A resolver fetches reference sequences by their MD5 checksums, e.g., from a local cache
+/// directory or a reference registry.
+pub trait ReferenceSequenceResolver {
+    /// Returns the reference sequence with the given MD5 checksum.
+    ///
+    /// This returns `None` if the resolver does not have the reference sequence.
+    fn resolve(&mut self, md5_checksum: Md5Checksum)
+        -> Option<io::Result<fasta::record::Sequence>>;
+}
+
+/// A sequence repository adapter that resolves reference sequences by MD5 checksum.
+///
+/// The MD5 checksums (`M5`) and URIs (`UR`) of the reference sequences are taken from the
+/// reference sequence dictionary of the SAM header. When a reference sequence is requested by
+/// name, it is looked up in the following order:
+///
+///   1. the fallback repository, if set (see [`Self::with_repository`]);
+///   2. the resolver, by MD5 checksum; and
+///   3. the URI, if it is a local file (a path or a `file://` URI) of a FASTA file containing a
+///      record with the same name.
+///
+/// A FASTA file is read using its index (`<src>.fai`), if it exists. Otherwise, the file is
+/// indexed on its first lookup. Indices are kept for subsequent lookups.
+///
+/// Sequences resolved by MD5 checksum or URI are checked against the checksum, if present.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::reference_sequence_resolver::{Adapter, FileSystemCache};
+/// use noodles_fasta as fasta;
+/// use noodles_sam as sam;
+///
+/// let header = sam::Header::default();
+/// let adapter = Adapter::new(&header, FileSystemCache::new("cache"));
+/// let repository = fasta::Repository::new(adapter);
+/// ```
+pub struct Adapter<R> {
+    repository: Option<fasta::Repository>,
+    md5_checksums: HashMap<String, Md5Checksum>,
+    uris: HashMap<String, String>,
+    indices: HashMap<PathBuf, fai::Index>,
+    resolver: R,
+}
+
+impl<R> Adapter<R>
+where
+    R: ReferenceSequenceResolver,
+{
+    /// Creates a reference sequence resolver adapter.
+    ///
+    /// Reference sequences without an MD5 checksum (`M5`) or a local URI (`UR`) cannot be
+    /// resolved.
+    pub fn new(header: &sam::Header, resolver: R) -> Self {
+        let reference_sequences = header.reference_sequences();
+
+        let md5_checksums = reference_sequences
+            .iter()
+            .filter_map(|(name, reference_sequence)| {
+                reference_sequence
+                    .md5_checksum()
+                    .map(|md5_checksum| (name.clone(), md5_checksum))
+            })
+            .collect();
+
+        let uris = reference_sequences
+            .iter()
+            .filter_map(|(name, reference_sequence)| {
+                reference_sequence
+                    .uri()
+                    .map(|uri| (name.clone(), uri.into()))
+            })
+            .collect();
+
+        Self {
+            repository: None,
+            md5_checksums,
+            uris,
+            indices: HashMap::new(),
+            resolver,
+        }
+    }
+
+    /// Creates a reference sequence resolver adapter that first looks up reference sequences in
+    /// the given repository.
+    ///
+    /// Reference sequences missing from the repository are resolved by MD5 checksum (`M5`) and
+    /// then by URI (`UR`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::reference_sequence_resolver::{Adapter, FileSystemCache};
+    /// use noodles_fasta as fasta;
+    /// use noodles_sam as sam;
+    ///
+    /// let header = sam::Header::default();
+    /// let adapter = Adapter::with_repository(
+    ///     fasta::Repository::default(),
+    ///     &header,
+    ///     FileSystemCache::new("cache"),
+    /// );
+    /// let repository = fasta::Repository::new(adapter);
+    /// ```
+    pub fn with_repository(
+        repository: fasta::Repository,
+        header: &sam::Header,
+        resolver: R,
+    ) -> Self {
+        let mut adapter = Self::new(header, resolver);
+        adapter.repository = Some(repository);
+        adapter
+    }
+
+    fn resolve_by_md5_checksum(
+        &mut self,
+        name: &str,
+    ) -> Option<io::Result<fasta::record::Sequence>> {
+        let md5_checksum = self.md5_checksums.get(name).copied()?;
+        self.resolver.resolve(md5_checksum)
+    }
+
+    fn resolve_by_uri(&mut self, name: &str) -> Option<io::Result<fasta::record::Sequence>> {
+        let uri = self.uris.get(name)?;
+        let src = uri_to_path(uri)?;
+
+        let index = match self.indices.entry(src.into()) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => match read_index(src) {
+                Ok(Some(index)) => entry.insert(index),
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            },
+        };
+
+        read_sequence(src, index, name).transpose()
+    }
+}
+
+impl<R> fasta::repository::Adapter for Adapter<R>
+where
+    R: ReferenceSequenceResolver,
+{
+    fn get(&mut self, name: &str) -> Option<io::Result<fasta::Record>> {
+        let definition = fasta::record::Definition::new(name, None);
+
+        if let Some(result) = self.repository.as_ref().and_then(|r| r.get(name)) {
+            return Some(result.map(|sequence| fasta::Record::new(definition, sequence)));
+        }
+
+        let sequence = match self
+            .resolve_by_md5_checksum(name)
+            .or_else(|| self.resolve_by_uri(name))?
+        {
+            Ok(sequence) => sequence,
+            Err(e) => return Some(Err(e)),
+        };
+
+        if let Some(&md5_checksum) = self.md5_checksums.get(name) {
+            if let Err(e) = validate_checksum(&sequence, md5_checksum) {
+                return Some(Err(e));
+            }
+        }
+
+        Some(Ok(fasta::Record::new(definition, sequence)))
+    }
+}
+
+fn validate_checksum(
+    sequence: &fasta::record::Sequence,
+    md5_checksum: Md5Checksum,
+) -> io::Result<()> {
+    let actual = calculate_normalized_sequence_digest(sequence.as_ref());
+
+    if actual[..] == md5_checksum[..] {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "reference sequence checksum mismatch: expected {}, got {}",
+                md5_checksum,
+                Md5Checksum::from(actual)
+            ),
+        ))
+    }
+}
+
+// Only local files are supported. Other URI schemes, e.g., `http` or `ftp`, are not resolved.
+fn uri_to_path(uri: &str) -> Option<&Path> {
+    const FILE_SCHEME: &str = "file://";
+
+    if let Some(path) = uri.strip_prefix(FILE_SCHEME) {
+        Some(Path::new(path))
+    } else if uri.contains("://") {
+        None
+    } else {
+        Some(Path::new(uri))
+    }
+}
+
+// Reads the FASTA index (`<src>.fai`) if it exists. Otherwise, the FASTA file is indexed.
+//
+// This returns `None` if the FASTA file does not exist.
+fn read_index(src: &Path) -> io::Result<Option<fai::Index>> {
+    let mut index_src = src.as_os_str().to_owned();
+    index_src.push(".fai");
+
+    match fai::read(&index_src) {
+        Ok(index) => return Ok(Some(index)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e),
+    }
+
+    match fasta::index(src) {
+        Ok(index) => Ok(Some(index)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+fn read_sequence(
+    src: &Path,
+    index: &[fai::Record],
+    name: &str,
+) -> io::Result<Option<fasta::record::Sequence>> {
+    if !index.iter().any(|record| record.name() == name) {
+        return Ok(None);
+    }
+
+    let mut reader = File::open(src)
+        .map(BufReader::new)
+        .map(fasta::Reader::new)?;
+
+    let region = Region::new(name, ..);
+    let record = reader.query(index, &region)?;
+
+    Ok(Some(record.sequence().clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use md5::{Digest, Md5};
+
+    use super::*;
+
+    struct Resolver(Vec<(Md5Checksum, fasta::record::Sequence)>);
+
+    impl ReferenceSequenceResolver for Resolver {
+        fn resolve(
+            &mut self,
+            md5_checksum: Md5Checksum,
+        ) -> Option<io::Result<fasta::record::Sequence>> {
+            self.0
+                .iter()
+                .find(|(c, _)| *c == md5_checksum)
+                .map(|(_, sequence)| Ok(sequence.clone()))
+        }
+    }
+
+    #[test]
+    fn test_get() -> Result<(), Box<dyn std::error::Error>> {
+        use fasta::repository::Adapter as _;
+        use sam::header::ReferenceSequence;
+
+        const SEQUENCE: &[u8] = b"ACGT";
+
+        let md5_checksum = Md5Checksum::from(<[u8; 16]>::from(Md5::digest(SEQUENCE)));
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                ReferenceSequence::builder()
+                    .set_name("sq0".parse()?)
+                    .set_length(4)
+                    .set_md5_checksum(md5_checksum)
+                    .build()?,
+            )
+            .add_reference_sequence(ReferenceSequence::new("sq1".parse()?, 4)?)
+            .add_reference_sequence(
+                ReferenceSequence::builder()
+                    .set_name("sq2".parse()?)
+                    .set_length(4)
+                    .set_md5_checksum(Md5Checksum::from([0; 16]))
+                    .build()?,
+            )
+            .build();
+
+        let resolver = Resolver(vec![
+            (
+                md5_checksum,
+                fasta::record::Sequence::from(SEQUENCE.to_vec()),
+            ),
+            (
+                Md5Checksum::from([0; 16]),
+                fasta::record::Sequence::from(b"TGCA".to_vec()),
+            ),
+        ]);
+
+        let mut adapter = Adapter::new(&header, resolver);
+
+        let record = adapter.get("sq0").transpose()?;
+        assert_eq!(
+            record.map(|r| r.sequence().as_ref().to_vec()),
+            Some(SEQUENCE.to_vec())
+        );
+
+        assert!(adapter.get("sq1").is_none());
+
+        assert!(matches!(
+            adapter.get("sq2"),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_with_repository_and_uri() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{env, fs, process};
+
+        use fasta::repository::Adapter as _;
+        use sam::header::ReferenceSequence;
+
+        let src = env::temp_dir().join(format!(
+            "noodles-cram-reference_sequence_resolver-{}.fa",
+            process::id()
+        ));
+        fs::write(&src, ">sq1\nTTGG\n>sq2\nCCAA\n")?;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 4)?)
+            .add_reference_sequence(
+                ReferenceSequence::builder()
+                    .set_name("sq1".parse()?)
+                    .set_length(4)
+                    .set_md5_checksum(Md5Checksum::from(<[u8; 16]>::from(Md5::digest(b"TTGG"))))
+                    .set_uri(format!("file://{}", src.display()))
+                    .build()?,
+            )
+            .add_reference_sequence(
+                ReferenceSequence::builder()
+                    .set_name("sq2".parse()?)
+                    .set_length(4)
+                    .set_uri("https://example.com/ref.fa")
+                    .build()?,
+            )
+            .build();
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(b"ACGT".to_vec()),
+        )]);
+
+        let mut adapter = Adapter::with_repository(repository, &header, Resolver(Vec::new()));
+
+        let get_sequence = |adapter: &mut Adapter<Resolver>, name| {
+            adapter
+                .get(name)
+                .transpose()
+                .map(|record| record.map(|r| r.sequence().as_ref().to_vec()))
+        };
+
+        assert_eq!(get_sequence(&mut adapter, "sq0")?, Some(b"ACGT".to_vec()));
+        assert_eq!(get_sequence(&mut adapter, "sq1")?, Some(b"TTGG".to_vec()));
+        assert_eq!(get_sequence(&mut adapter, "sq2")?, None);
+
+        fs::remove_file(src)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_with_uri_and_fasta_index() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{env, fs, process};
+
+        use fasta::repository::Adapter as _;
+        use sam::header::ReferenceSequence;
+
+        let src = env::temp_dir().join(format!(
+            "noodles-cram-reference_sequence_resolver-fai-{}.fa",
+            process::id()
+        ));
+        let index_src = src.with_extension("fa.fai");
+
+        // The index maps `sq1` to the record named `sq9`, so the record can only be found using
+        // the index.
+        fs::write(&src, ">sq9\nTTGG\n")?;
+        fs::write(&index_src, "sq1\t4\t5\t4\t5\n")?;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                ReferenceSequence::builder()
+                    .set_name("sq1".parse()?)
+                    .set_length(4)
+                    .set_uri(src.display().to_string())
+                    .build()?,
+            )
+            .build();
+
+        let mut adapter = Adapter::new(&header, Resolver(Vec::new()));
+
+        let result = adapter.get("sq1").transpose();
+
+        fs::remove_file(&src)?;
+        fs::remove_file(&index_src)?;
+
+        let record = result?.expect("missing record");
+        assert_eq!(record.sequence().as_ref(), b"TTGG");
+        assert_eq!(adapter.indices.len(), 1);
+
+        Ok(())
+    }
+}
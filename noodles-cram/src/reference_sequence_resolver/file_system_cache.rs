@@ -0,0 +1,62 @@
+use std::{
+    fs::File,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+use noodles_fasta as fasta;
+use noodles_sam::header::reference_sequence::Md5Checksum;
+
+use super::ReferenceSequenceResolver;
+
+/// A reference sequence resolver backed by a local cache directory.
+///
+/// Each reference sequence is stored in a file named by the lowercase hexadecimal MD5 checksum of
+/// the sequence, e.g., `<src>/d7eba311421bbc9d3ada44709dd61534`. The file contains only the
+/// sequence; characters outside of the printable ASCII range (e.g., line breaks) are stripped.
+#[derive(Clone, Debug)]
+pub struct FileSystemCache {
+    src: PathBuf,
+}
+
+impl FileSystemCache {
+    /// Creates a file system cache reference sequence resolver.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::reference_sequence_resolver::FileSystemCache;
+    /// let resolver = FileSystemCache::new("cache");
+    /// ```
+    pub fn new<P>(src: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self { src: src.into() }
+    }
+}
+
+impl ReferenceSequenceResolver for FileSystemCache {
+    fn resolve(
+        &mut self,
+        md5_checksum: Md5Checksum,
+    ) -> Option<io::Result<fasta::record::Sequence>> {
+        let src = self.src.join(md5_checksum.to_string());
+
+        let mut file = match File::open(src) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return None,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let mut buf = Vec::new();
+
+        if let Err(e) = file.read_to_end(&mut buf) {
+            return Some(Err(e));
+        }
+
+        buf.retain(|b| b.is_ascii_graphic());
+
+        Some(Ok(fasta::record::Sequence::from(buf)))
+    }
+}
@@ -0,0 +1,34 @@
+//! Shared test fixtures.
+
+use md5::{Digest, Md5};
+use noodles_fasta as fasta;
+use noodles_sam::{self as sam, header::ReferenceSequence};
+
+/// Builds a SAM header and a reference sequence repository with the given reference sequence
+/// names.
+///
+/// Each reference sequence has the given sequence, and its MD5 checksum is set in the header.
+pub(crate) fn build_header_and_repository(
+    names: &[&str],
+    sequence: &[u8],
+) -> Result<(sam::Header, fasta::Repository), Box<dyn std::error::Error>> {
+    let mut header_builder = sam::Header::builder();
+    let mut records = Vec::with_capacity(names.len());
+
+    for &name in names {
+        let reference_sequence = ReferenceSequence::builder()
+            .set_name(name.parse()?)
+            .set_length(sequence.len() as i32)
+            .set_md5_checksum(<[u8; 16]>::from(Md5::digest(sequence)).into())
+            .build()?;
+
+        header_builder = header_builder.add_reference_sequence(reference_sequence);
+
+        records.push(fasta::Record::new(
+            fasta::record::Definition::new(name, None),
+            fasta::record::Sequence::from(sequence.to_vec()),
+        ));
+    }
+
+    Ok((header_builder.build(), fasta::Repository::new(records)))
+}
@@ -1,12 +1,16 @@
+//! CRAM writer.
+
 mod builder;
 mod container;
 pub(crate) mod data_container;
 pub(crate) mod num;
 mod options;
+mod read_name_generator;
 pub(crate) mod record;
+pub(crate) mod statistics;
 
-pub use self::builder::Builder;
 pub(crate) use self::options::Options;
+pub use self::{builder::Builder, statistics::Statistics};
 
 use std::{
     io::{self, Write},
@@ -16,12 +20,15 @@ use std::{
 use noodles_fasta as fasta;
 use noodles_sam as sam;
 
-use self::container::write_container;
+use self::{container::write_container, read_name_generator::ReadNameGenerator};
 use super::{
     container::Container, file_definition::Version, DataContainer, FileDefinition, Record,
     MAGIC_NUMBER,
 };
 
+const MIN_VERSION: Version = Version::new(3, 0);
+const MAX_VERSION: Version = Version::new(3, 1);
+
 /// A CRAM writer.
 ///
 /// A call to [`try_finish`] must be made before the writer is dropped.
@@ -55,6 +62,9 @@ where
     options: Options,
     data_container_builder: crate::data_container::Builder,
     record_counter: i64,
+    base_counter: u64,
+    read_name_generator: ReadNameGenerator,
+    statistics: Option<Statistics>,
 }
 
 impl<W> Writer<W>
@@ -99,6 +109,110 @@ where
         &self.inner
     }
 
+    /// Returns the data series statistics collected so far.
+    ///
+    /// This is only set when the writer is built with statistics collection enabled (see
+    /// [`Builder::collect_statistics`]). Statistics are updated as each data container is written,
+    /// so they are complete after a call to [`Self::try_finish`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    ///
+    /// let writer = cram::Writer::new(Vec::new());
+    /// assert!(writer.statistics().is_none());
+    ///
+    /// let writer = cram::Writer::builder(Vec::new())
+    ///     .collect_statistics(true)
+    ///     .build();
+    /// assert!(writer.statistics().is_some());
+    /// ```
+    pub fn statistics(&self) -> Option<&Statistics> {
+        self.statistics.as_ref()
+    }
+
+    /// Estimates the data series statistics of the buffered records.
+    ///
+    /// Buffered records are records that were added with [`Self::write_record`] but not yet
+    /// written in a data container. This encodes them using the compression header the next data
+    /// container would be written with but does not build or write any blocks. Lengths are
+    /// uncompressed sizes.
+    ///
+    /// This can be used to compare the sizes of data series before committing to an encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_cram::{self as cram, data_container::DataSeries};
+    /// use noodles_sam as sam;
+    ///
+    /// let header = sam::Header::default();
+    /// let mut writer = cram::Writer::new(Vec::new());
+    /// writer.write_file_definition()?;
+    /// writer.write_file_header(&header)?;
+    ///
+    /// let record = cram::Record::builder().set_read_name("r0".parse()?).build();
+    /// writer.write_record(&header, record)?;
+    ///
+    /// let statistics = writer.estimate()?;
+    /// assert_eq!(statistics.data_series_len(DataSeries::ReadNames), 3);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn estimate(&self) -> io::Result<Statistics> {
+        self.data_container_builder.estimate(&self.options)
+    }
+
+    /// Returns the number of records written.
+    ///
+    /// This includes records that are buffered in a pending container, which are written when the
+    /// container is full or the stream is finished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_cram as cram;
+    /// use noodles_sam as sam;
+    ///
+    /// let mut writer = cram::Writer::new(Vec::new());
+    /// assert_eq!(writer.records_written(), 0);
+    ///
+    /// let header = sam::Header::default();
+    /// writer.write_record(&header, cram::Record::default())?;
+    /// assert_eq!(writer.records_written(), 1);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn records_written(&self) -> u64 {
+        self.record_counter as u64
+    }
+
+    /// Returns the number of bases written.
+    ///
+    /// This is the sum of the read lengths of all records written, including records that are
+    /// buffered in a pending container.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_cram as cram;
+    /// use noodles_sam as sam;
+    ///
+    /// let mut writer = cram::Writer::new(Vec::new());
+    /// assert_eq!(writer.bases_written(), 0);
+    ///
+    /// let header = sam::Header::default();
+    /// let record = cram::Record::builder().set_read_length(4).build();
+    /// writer.write_record(&header, record)?;
+    /// assert_eq!(writer.bases_written(), 4);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn bases_written(&self) -> u64 {
+        self.base_counter
+    }
+
     /// Attempts to finish the output stream by writing any pending containers and a final EOF
     /// container.
     ///
@@ -119,13 +233,18 @@ where
     /// ```
     pub fn try_finish(&mut self, header: &sam::Header) -> io::Result<()> {
         self.flush(header)?;
+        self.read_name_generator.clear();
+
         let eof_container = Container::eof();
         write_container(&mut self.inner, &eof_container)
     }
 
     /// Writes a CRAM file definition.
     ///
-    /// The file ID is set as a blank value (`[0x00; 20]`).
+    /// The version is set by [`Builder::set_version`], and the file ID is set as a blank value
+    /// (`[0x00; 20]`).
+    ///
+    /// This returns an error if the version is not supported by the writer.
     ///
     /// # Examples
     ///
@@ -148,7 +267,19 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_file_definition(&mut self) -> io::Result<()> {
-        let file_definition = FileDefinition::default();
+        let version = self.options.version;
+
+        if !(MIN_VERSION..=MAX_VERSION).contains(&version) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "unsupported CRAM version: expected {} to {}, got {}",
+                    MIN_VERSION, MAX_VERSION, version
+                ),
+            ));
+        }
+
+        let file_definition = FileDefinition::new(version, [0; 20]);
         write_file_definition(&mut self.inner, &file_definition)
     }
 
@@ -206,10 +337,27 @@ where
     pub fn write_record(&mut self, header: &sam::Header, mut record: Record) -> io::Result<()> {
         use super::data_container::builder::AddRecordError;
 
+        if self.options.preserve_read_names
+            && self.options.synthesize_missing_read_names
+            && record.read_name.is_none()
+        {
+            let read_name = self
+                .read_name_generator
+                .generate(&record, self.record_counter)?;
+
+            record.read_name = Some(read_name);
+        }
+
+        let read_length = record.read_length() as u64;
+
         loop {
-            match self.data_container_builder.add_record(record) {
+            match self
+                .data_container_builder
+                .add_record(&self.options, record)
+            {
                 Ok(_) => {
                     self.record_counter += 1;
+                    self.base_counter += read_length;
                     return Ok(());
                 }
                 Err(e) => match e {
@@ -243,6 +391,10 @@ where
             header,
         )?;
 
+        if let Some(statistics) = self.statistics.as_mut() {
+            statistics.update(&data_container);
+        }
+
         let container = Container::try_from_data_container(&data_container, base_count)?;
         write_container(&mut self.inner, &container)?;
 
@@ -291,3 +443,519 @@ where
     let format = [version.major(), version.minor()];
     writer.write_all(&format)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{data_container::DataSeries, test_util::build_header_and_repository};
+
+    #[test]
+    fn test_records_written_and_bases_written() -> io::Result<()> {
+        const RECORD_COUNT: usize = 5;
+
+        let header = sam::Header::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        for read_length in 1..=RECORD_COUNT {
+            let record = Record::builder().set_read_length(read_length).build();
+            writer.write_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        assert_eq!(writer.records_written(), RECORD_COUNT as u64);
+        assert_eq!(writer.bases_written(), 15);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_estimate() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_sam::record::data::{
+            field::{Tag, Value},
+            Field,
+        };
+
+        use crate::record::Flags;
+
+        let header = sam::Header::default();
+
+        let mut writer = Writer::builder(Vec::new()).collect_statistics(true).build();
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        assert_eq!(writer.estimate()?, Statistics::default());
+
+        for (i, read_name) in ["r0", "r1", "r2"].into_iter().enumerate() {
+            let record = Record::builder()
+                .set_flags(Flags::QUALITY_SCORES_STORED_AS_ARRAY)
+                .set_read_name(read_name.parse()?)
+                .set_read_length(4 + i)
+                .set_bases("ACGTACGT"[..4 + i].parse()?)
+                .set_quality_scores("NDLSNDLS"[..4 + i].parse()?)
+                .set_tags(sam::record::Data::try_from(vec![Field::new(
+                    Tag::Comment,
+                    Value::String(String::from("noodles")),
+                )])?)
+                .build();
+
+            writer.write_record(&header, record)?;
+        }
+
+        let estimate = writer.estimate()?;
+
+        writer.try_finish(&header)?;
+        let actual = writer.statistics().expect("missing statistics");
+
+        assert_eq!(&estimate, actual);
+        assert_eq!(estimate.data_series_len(DataSeries::Bases), 15);
+        assert!(estimate.core_data_len() > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_file_definition_with_version() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::Reader;
+
+        let header = sam::Header::default();
+
+        for version in [Version::new(3, 0), Version::new(3, 1)] {
+            let mut writer = Writer::builder(Vec::new()).set_version(version).build();
+            writer.write_file_definition()?;
+            writer.write_file_header(&header)?;
+            writer.write_record(&header, Record::default())?;
+            writer.try_finish(&header)?;
+
+            let mut reader = Reader::new(&writer.get_ref()[..]);
+            let file_definition = reader.read_file_definition()?;
+            assert_eq!(file_definition.version(), version);
+
+            reader.read_file_header()?;
+            assert!(reader.read_data_container()?.is_some());
+            assert!(reader.read_data_container()?.is_none());
+        }
+
+        let mut writer = Writer::builder(Vec::new())
+            .set_version(Version::new(2, 1))
+            .build();
+
+        assert!(matches!(
+            writer.write_file_definition(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_write_record_with_zstd_compression() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{container::block::CompressionMethod, Reader};
+
+        let header = sam::Header::default();
+
+        let mut writer = Writer::builder(Vec::new())
+            .allow_zstd_compression(true)
+            .build();
+
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+        writer.write_record(&header, Record::default())?;
+        writer.try_finish(&header)?;
+
+        let mut reader = Reader::new(&writer.get_ref()[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        assert!(matches!(
+            reader.read_data_container(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut reader = Reader::new(&writer.get_ref()[..]);
+        reader.set_allow_zstd_compression(true);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let data_container = reader
+            .read_data_container()?
+            .expect("missing data container");
+
+        let slice = &data_container.slices()[0];
+        assert_eq!(
+            slice.core_data_block().compression_method(),
+            CompressionMethod::Zstd
+        );
+
+        let records = slice.records(data_container.compression_header())?;
+        assert_eq!(records.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_concurrent_slice_encoding() -> Result<(), Box<dyn std::error::Error>>
+    {
+        use crate::Reader;
+
+        // 3 full slices and 1 partial slice
+        const RECORD_COUNT: usize = 3 * 10240 + 8;
+        const SLICES_PER_CONTAINER: usize = 4;
+
+        fn write(
+            header: &sam::Header,
+            worker_count: usize,
+        ) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+            let mut writer = Writer::builder(Vec::new())
+                .set_slices_per_container(SLICES_PER_CONTAINER)
+                .set_worker_count(worker_count)
+                .build();
+
+            writer.write_file_definition()?;
+            writer.write_file_header(header)?;
+
+            for i in 0..RECORD_COUNT {
+                let record = Record::builder()
+                    .set_read_name(format!("r{}", i).parse()?)
+                    .set_read_length(i % 8 + 1)
+                    .build();
+
+                writer.write_record(header, record)?;
+            }
+
+            writer.try_finish(header)?;
+
+            Ok(writer.get_ref().clone())
+        }
+
+        let header = sam::Header::default();
+
+        let serial_data = write(&header, 1)?;
+        let parallel_data = write(&header, 4)?;
+        assert_eq!(parallel_data, serial_data);
+
+        let mut reader = Reader::new(&parallel_data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let data_container = reader
+            .read_data_container()?
+            .expect("missing data container");
+
+        assert_eq!(data_container.slices().len(), SLICES_PER_CONTAINER);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_multiple_slices_per_container(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use crate::Reader;
+
+        // 2 containers: 2 full slices, and 1 full slice and 1 partial slice
+        const RECORD_COUNT: usize = 3 * 10240 + 8;
+        const SLICES_PER_CONTAINER: usize = 2;
+
+        let header = sam::Header::default();
+
+        let mut writer = Writer::builder(Vec::new())
+            .set_slices_per_container(SLICES_PER_CONTAINER)
+            .build();
+
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        for _ in 0..RECORD_COUNT {
+            writer.write_record(&header, Record::default())?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let mut reader = Reader::new(&writer.get_ref()[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let mut actual = Vec::new();
+
+        while let Some(data_container) = reader.read_data_container()? {
+            assert_eq!(data_container.slices().len(), SLICES_PER_CONTAINER);
+
+            for slice in data_container.slices() {
+                let slice_header = slice.header();
+                actual.push((slice_header.record_counter(), slice_header.record_count()));
+            }
+        }
+
+        assert_eq!(actual.len(), 4);
+        assert_eq!(actual[0].0, 0);
+
+        for window in actual.windows(2) {
+            let (prev_record_counter, prev_record_count) = window[0];
+            let (record_counter, _) = window[1];
+            assert_eq!(
+                record_counter,
+                prev_record_counter + prev_record_count as i64
+            );
+        }
+
+        let record_count: usize = actual.iter().map(|(_, n)| n).sum();
+        assert_eq!(record_count, RECORD_COUNT);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_unsorted_alignment_starts() -> Result<(), Box<dyn std::error::Error>>
+    {
+        use noodles_core::Position;
+        use noodles_sam::AlignmentRecord;
+
+        use crate::Reader;
+
+        const REFERENCE_SEQUENCE: &[u8] = b"ACGTACGTACGTACGT";
+
+        fn write_and_read(
+            header: &sam::Header,
+            repository: &fasta::Repository,
+            sort_records_by_alignment_start: bool,
+        ) -> Result<Vec<usize>, Box<dyn std::error::Error>> {
+            let mut writer = Writer::builder(Vec::new())
+                .set_reference_sequence_repository(repository.clone())
+                .sort_records_by_alignment_start(sort_records_by_alignment_start)
+                .build();
+
+            writer.write_file_definition()?;
+            writer.write_file_header(header)?;
+
+            for alignment_start in [9, 3, 13, 1] {
+                let record = Record::builder()
+                    .set_bam_flags(sam::record::Flags::empty())
+                    .set_reference_sequence_id(0)
+                    .set_alignment_start(Position::try_from(alignment_start)?)
+                    .set_read_length(4)
+                    .build();
+
+                writer.write_record(header, record)?;
+            }
+
+            writer.try_finish(header)?;
+
+            let data = writer.get_ref();
+            let mut reader = Reader::new(&data[..]);
+            reader.read_file_definition()?;
+            reader.read_file_header()?;
+
+            let alignment_starts = reader
+                .records(repository, header)
+                .map(|result| {
+                    result.map(|record| record.alignment_start().map(usize::from).unwrap_or(0))
+                })
+                .collect::<io::Result<_>>()?;
+
+            Ok(alignment_starts)
+        }
+
+        let (header, repository) = build_header_and_repository(&["sq0"], REFERENCE_SEQUENCE)?;
+
+        assert_eq!(write_and_read(&header, &repository, false)?, [9, 3, 13, 1]);
+        assert_eq!(write_and_read(&header, &repository, true)?, [1, 3, 9, 13]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_built_from_scratch() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+        use noodles_sam::record::{
+            data::{
+                field::{Tag, Value},
+                Field,
+            },
+            MappingQuality,
+        };
+
+        use crate::{record::Flags, Reader};
+
+        const REFERENCE_SEQUENCE: &[u8] = b"ACGTACGTACGTACGT";
+
+        let (header, repository) = build_header_and_repository(&["sq0"], REFERENCE_SEQUENCE)?;
+
+        let record = Record::builder()
+            .set_read_name("r0".parse()?)
+            .set_bam_flags(sam::record::Flags::REVERSE_COMPLEMENTED)
+            .set_flags(Flags::QUALITY_SCORES_STORED_AS_ARRAY)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(5)?)
+            .set_read_length(8)
+            .set_bases("ACGTACGT".parse()?)
+            .set_mapping_quality(MappingQuality::try_from(37)?)
+            .set_quality_scores("NDLSNDLS".parse()?)
+            .add_tag(Field::new(Tag::AlignmentHitCount, Value::UInt8(1)))
+            .build();
+
+        let mut writer = Writer::builder(Vec::new())
+            .set_reference_sequence_repository(repository.clone())
+            .build();
+
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+        writer.write_record(&header, record)?;
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let records: Vec<_> = reader
+            .records(&repository, &header)
+            .map(|result| result.and_then(|record| record.try_into_sam_record(&header)))
+            .collect::<io::Result<_>>()?;
+
+        let expected: sam::Record =
+            "r0\t16\tsq0\t5\t37\t8M\t*\t0\t0\tACGTACGT\tNDLSNDLS\tNH:i:1".parse()?;
+
+        assert_eq!(records, [expected]);
+
+        Ok(())
+    }
+
+    // Converts a SAM record to a CRAM record, writes it, and reads it back as a SAM record. The
+    // reference sequence `sq0` is a repeat of `ACGT` (32 bases).
+    fn write_and_read_sam_record(
+        sam_record: &sam::Record,
+    ) -> Result<sam::Record, Box<dyn std::error::Error>> {
+        use crate::Reader;
+
+        const REFERENCE_SEQUENCE: &[u8] = b"ACGTACGTACGTACGTACGTACGTACGTACGT";
+
+        let (header, repository) = build_header_and_repository(&["sq0"], REFERENCE_SEQUENCE)?;
+
+        let record = Record::try_from_alignment_record(&header, sam_record)?;
+
+        let mut writer = Writer::builder(Vec::new())
+            .set_reference_sequence_repository(repository.clone())
+            .build();
+
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+        writer.write_record(&header, record)?;
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let mut records: Vec<_> = reader
+            .records(&repository, &header)
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(records.len(), 1);
+
+        let record = records.pop().unwrap();
+        let sam_record = record.try_into_sam_record(&header)?;
+
+        Ok(sam_record)
+    }
+
+    #[test]
+    fn test_write_record_with_selected_quality_scores_storage(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_sam::AlignmentRecord;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(sam::header::ReferenceSequence::new("sq0".parse()?, 32)?)
+            .build();
+
+        for (raw_record, are_quality_scores_stored_as_array) in [
+            ("r0\t0\tsq0\t1\t60\t8M\t*\t0\t0\tACGTACGT\tNDLSNDLS", true),
+            ("r0\t0\tsq0\t1\t60\t8M\t*\t0\t0\tACGTACGT\t!!!!!N!!", false),
+        ] {
+            let sam_record: sam::Record = raw_record.parse()?;
+
+            let record = Record::try_from_alignment_record(&header, &sam_record)?;
+            assert_eq!(
+                record.cram_flags().are_quality_scores_stored_as_array(),
+                are_quality_scores_stored_as_array
+            );
+
+            let actual = write_and_read_sam_record(&sam_record)?;
+            assert_eq!(actual.quality_scores(), sam_record.quality_scores());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_reference_match_bases() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_sam::AlignmentRecord;
+
+        let sam_record: sam::Record = "r0\t0\tsq0\t3\t60\t6M\t*\t0\t0\tGT=C==\tNDLSNN".parse()?;
+        let actual = write_and_read_sam_record(&sam_record)?;
+
+        assert_eq!(actual.sequence().to_string(), "GTACGT");
+        assert_eq!(actual.quality_scores(), sam_record.quality_scores());
+        assert_eq!(actual.cigar(), sam_record.cigar());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_soft_clips_and_insertion() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_sam::AlignmentRecord;
+
+        let sam_record: sam::Record = "r0\t0\tsq0\t1\t60\t5S10M5I10M5S\t*\t0\t0\tTTTTTACGTACGTACTTTTTGTACGTACGTTTTTT\tNDLSNNDLSNNDLSNNDLSNNDLSNNDLSNNDLSN".parse()?;
+        let actual = write_and_read_sam_record(&sam_record)?;
+
+        assert_eq!(actual.cigar(), sam_record.cigar());
+        assert_eq!(actual.sequence(), sam_record.sequence());
+        assert_eq!(actual.quality_scores(), sam_record.quality_scores());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_hard_clips() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_sam::AlignmentRecord;
+
+        for cigar in ["3H20M3H", "3H20M", "20M3H", "2H3S15M2S2H"] {
+            let sam_record: sam::Record = format!(
+                "r0\t0\tsq0\t1\t60\t{}\t*\t0\t0\tACGTACGTACGTACGTACGT\tNDLSNNDLSNNDLSNNDLSN",
+                cigar
+            )
+            .parse()?;
+
+            let actual = write_and_read_sam_record(&sam_record)?;
+
+            assert_eq!(actual.cigar(), sam_record.cigar());
+            assert_eq!(actual.sequence(), sam_record.sequence());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_padding() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_sam::AlignmentRecord;
+
+        for cigar in ["10M2P10M", "2P20M", "20M2P", "5M2I1P3I10M", "10M1P2D10M"] {
+            let sam_record: sam::Record = format!(
+                "r0\t0\tsq0\t1\t60\t{}\t*\t0\t0\tACGTACGTACGTACGTACGT\tNDLSNNDLSNNDLSNNDLSN",
+                cigar
+            )
+            .parse()?;
+
+            let actual = write_and_read_sam_record(&sam_record)?;
+
+            assert_eq!(actual.cigar(), sam_record.cigar());
+            assert_eq!(actual.sequence(), sam_record.sequence());
+        }
+
+        Ok(())
+    }
+}
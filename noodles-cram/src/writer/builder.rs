@@ -1,15 +1,16 @@
-use std::io::Write;
+use std::{cmp, io::Write};
 
 use noodles_fasta as fasta;
 
-use super::{Options, Writer};
-use crate::DataContainer;
+use super::{read_name_generator::ReadNameGenerator, Options, Statistics, Writer};
+use crate::{file_definition::Version, DataContainer};
 
 /// A CRAM writer builder.
 pub struct Builder<W> {
     inner: W,
     reference_sequence_repository: fasta::Repository,
     options: Options,
+    collect_statistics: bool,
 }
 
 impl<W> Builder<W>
@@ -21,6 +22,7 @@ where
             inner,
             reference_sequence_repository: fasta::Repository::default(),
             options: Options::default(),
+            collect_statistics: false,
         }
     }
 
@@ -53,6 +55,171 @@ where
         self
     }
 
+    /// Sets whether to synthesize names for records with missing read names.
+    ///
+    /// If `true`, a record without a read name is named using the record counter. Segmented
+    /// records are given the same name as their mate, which is matched by position. Records are
+    /// expected to be coordinate-sorted, i.e., a mate that is not seen by its position, e.g.,
+    /// because it was filtered out, is given its own name.
+    ///
+    /// This has no effect if read names are not preserved (see [`Self::preserve_read_names`]).
+    ///
+    /// The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let builder = cram::Writer::builder(Vec::new()).synthesize_missing_read_names(true);
+    /// ```
+    pub fn synthesize_missing_read_names(mut self, value: bool) -> Self {
+        self.options.synthesize_missing_read_names = value;
+        self
+    }
+
+    /// Sets whether to allow records with different reference sequences in the same slice.
+    ///
+    /// If `false`, a record with a reference sequence that differs from the current slice starts a
+    /// new container. If `true`, the record is added to the current slice, which is then written
+    /// as a multi-reference slice. Alignment starts in multi-reference slices are never encoded as
+    /// deltas.
+    ///
+    /// The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let builder = cram::Writer::builder(Vec::new()).allow_multi_reference_slices(true);
+    /// ```
+    pub fn allow_multi_reference_slices(mut self, value: bool) -> Self {
+        self.options.allow_multi_reference_slices = value;
+        self
+    }
+
+    /// Sets whether to allow compressing slice data blocks with zstd.
+    ///
+    /// If `true`, core and external data blocks are compressed with zstd instead of gzip. zstd is
+    /// not part of the CRAM specification and is written as method ID 9, so the output can only be
+    /// read by readers that explicitly allow it (see [`crate::Reader::set_allow_zstd_compression`]).
+    /// Writing zstd blocks requires the `zstd` feature.
+    ///
+    /// The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let builder = cram::Writer::builder(Vec::new()).allow_zstd_compression(true);
+    /// ```
+    pub fn allow_zstd_compression(mut self, value: bool) -> Self {
+        self.options.allow_zstd_compression = value;
+        self
+    }
+
+    /// Sets whether to sort the records in a slice by alignment start.
+    ///
+    /// When alignment start positions are encoded as deltas (see
+    /// [`Self::encode_alignment_start_positions_as_deltas`]), each delta is relative to the
+    /// previous record in the slice, and records that are not in coordinate order are written with
+    /// negative deltas. If `true`, the records of each single-reference slice are stably sorted by
+    /// alignment start before being written, so all deltas are positive. This changes the order of
+    /// the records in the output.
+    ///
+    /// The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let builder = cram::Writer::builder(Vec::new()).sort_records_by_alignment_start(true);
+    /// ```
+    pub fn sort_records_by_alignment_start(mut self, value: bool) -> Self {
+        self.options.sort_records_by_alignment_start = value;
+        self
+    }
+
+    /// Sets the maximum number of slices in a container.
+    ///
+    /// A value of 0 is treated as 1.
+    ///
+    /// The default is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let builder = cram::Writer::builder(Vec::new()).set_slices_per_container(4);
+    /// ```
+    pub fn set_slices_per_container(mut self, slices_per_container: usize) -> Self {
+        self.options.slices_per_container = cmp::max(slices_per_container, 1);
+        self
+    }
+
+    /// Sets the number of worker threads used to encode the slices of a container.
+    ///
+    /// Once the compression header of a container is built, its slices are independent of each
+    /// other and are encoded concurrently when the worker count is greater than 1. The order of
+    /// the slices in the container is preserved, so the output is the same as when encoding
+    /// serially. This only has an effect when a container has more than one slice (see
+    /// [`Self::set_slices_per_container`]).
+    ///
+    /// A value of 0 is treated as 1.
+    ///
+    /// The default is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    ///
+    /// let builder = cram::Writer::builder(Vec::new())
+    ///     .set_slices_per_container(4)
+    ///     .set_worker_count(4);
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: usize) -> Self {
+        self.options.worker_count = cmp::max(worker_count, 1);
+        self
+    }
+
+    /// Sets the CRAM version of the output.
+    ///
+    /// This is the version written in the file definition (see [`Writer::write_file_definition`]).
+    /// The writer only uses block compression methods and encodings that are valid in CRAM 3.0,
+    /// so CRAM 3.0 and 3.1 are supported. Writing the file definition of any other version returns
+    /// an error.
+    ///
+    /// The default is CRAM 3.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{self as cram, file_definition::Version};
+    /// let builder = cram::Writer::builder(Vec::new()).set_version(Version::new(3, 1));
+    /// ```
+    pub fn set_version(mut self, version: Version) -> Self {
+        self.options.version = version;
+        self
+    }
+
+    /// Sets whether to collect data series statistics.
+    ///
+    /// If `true`, the writer records the number of bytes written per data series and per tag,
+    /// which can be retrieved using [`Writer::statistics`].
+    ///
+    /// The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let builder = cram::Writer::builder(Vec::new()).collect_statistics(true);
+    /// ```
+    pub fn collect_statistics(mut self, value: bool) -> Self {
+        self.collect_statistics = value;
+        self
+    }
+
     /// Builds a CRAM writer.
     ///
     /// # Examples
@@ -68,6 +235,9 @@ where
             options: self.options,
             data_container_builder: DataContainer::builder(0),
             record_counter: 0,
+            base_counter: 0,
+            read_name_generator: ReadNameGenerator::default(),
+            statistics: self.collect_statistics.then(Statistics::default),
         }
     }
 }
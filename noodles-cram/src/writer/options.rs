@@ -1,7 +1,16 @@
+use crate::file_definition::Version;
+
 #[derive(Debug)]
 pub struct Options {
     pub preserve_read_names: bool,
     pub encode_alignment_start_positions_as_deltas: bool,
+    pub synthesize_missing_read_names: bool,
+    pub allow_multi_reference_slices: bool,
+    pub allow_zstd_compression: bool,
+    pub sort_records_by_alignment_start: bool,
+    pub slices_per_container: usize,
+    pub worker_count: usize,
+    pub version: Version,
 }
 
 impl Default for Options {
@@ -9,6 +18,13 @@ impl Default for Options {
         Self {
             preserve_read_names: true,
             encode_alignment_start_positions_as_deltas: true,
+            synthesize_missing_read_names: false,
+            allow_multi_reference_slices: false,
+            allow_zstd_compression: false,
+            sort_records_by_alignment_start: false,
+            slices_per_container: 1,
+            worker_count: 1,
+            version: Version::default(),
         }
     }
 }
@@ -0,0 +1,232 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    io,
+};
+
+use noodles_core::Position;
+use noodles_sam as sam;
+
+use crate::Record;
+
+// A record position, i.e., whether it is unplaced, its reference sequence ID, and its alignment
+// start.
+//
+// Loci are ordered as records are in coordinate-sorted output, i.e., unplaced records are last.
+type Locus = (bool, usize, Option<Position>);
+
+const MIN_LOCUS: Locus = (false, 0, None);
+
+/// A generator of read names for records with missing read names.
+///
+/// Names are taken from the record counter. Segmented records are matched to a previously seen
+/// mate by position, i.e., a record's position is its mate's mate position and vice versa, so
+/// that both mates are given the same name.
+///
+/// Records are expected to be coordinate-sorted. A pending name is dropped once a record is past
+/// its mate's position, since the mate can no longer be seen, e.g., when it was filtered out.
+#[derive(Debug, Default)]
+pub(crate) struct ReadNameGenerator {
+    // Pending read names by mate locus and locus.
+    pending_mate_read_names: BTreeMap<(Locus, Locus), VecDeque<sam::record::ReadName>>,
+}
+
+impl ReadNameGenerator {
+    pub fn generate(
+        &mut self,
+        record: &Record,
+        record_counter: i64,
+    ) -> io::Result<sam::record::ReadName> {
+        let is_segmented = record.bam_flags().is_segmented();
+
+        let locus = build_locus(record.reference_sequence_id(), record.alignment_start);
+        let mate_locus = build_locus(
+            record.next_fragment_reference_sequence_id(),
+            record.next_mate_alignment_start(),
+        );
+
+        self.evict_before(locus);
+
+        if is_segmented {
+            // A pending mate waits for this record's locus, and this record's mate locus is the
+            // pending mate's locus.
+            if let Some(read_name) = self.take_pending_mate_read_name((locus, mate_locus)) {
+                return Ok(read_name);
+            }
+        }
+
+        let read_name: sam::record::ReadName = record_counter
+            .to_string()
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        if is_segmented && mate_locus >= locus {
+            self.pending_mate_read_names
+                .entry((mate_locus, locus))
+                .or_default()
+                .push_back(read_name.clone());
+        }
+
+        Ok(read_name)
+    }
+
+    /// Drops all pending read names.
+    ///
+    /// This is called when no more records are written.
+    pub fn clear(&mut self) {
+        self.pending_mate_read_names.clear();
+    }
+
+    fn take_pending_mate_read_name(
+        &mut self,
+        key: (Locus, Locus),
+    ) -> Option<sam::record::ReadName> {
+        let read_names = self.pending_mate_read_names.get_mut(&key)?;
+        let read_name = read_names.pop_front();
+
+        if read_names.is_empty() {
+            self.pending_mate_read_names.remove(&key);
+        }
+
+        read_name
+    }
+
+    // Drops pending read names with a mate locus before the given locus.
+    fn evict_before(&mut self, locus: Locus) {
+        self.pending_mate_read_names = self.pending_mate_read_names.split_off(&(locus, MIN_LOCUS));
+    }
+}
+
+fn build_locus(reference_sequence_id: Option<usize>, alignment_start: Option<Position>) -> Locus {
+    match reference_sequence_id {
+        Some(id) => (false, id, alignment_start),
+        None => (true, 0, alignment_start),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::record::Flags;
+
+    use super::*;
+
+    #[test]
+    fn test_generate() -> Result<(), Box<dyn std::error::Error>> {
+        fn build_record(
+            flags: Flags,
+            alignment_start: usize,
+            mate_alignment_start: usize,
+        ) -> Result<Record, noodles_core::position::TryFromIntError> {
+            Ok(Record::builder()
+                .set_bam_flags(flags)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(alignment_start)?)
+                .set_next_fragment_reference_sequence_id(0)
+                .set_next_mate_alignment_start(Position::try_from(mate_alignment_start)?)
+                .build())
+        }
+
+        let mut generator = ReadNameGenerator::default();
+
+        let r0 = build_record(Flags::SEGMENTED, 8, 21)?;
+        let r1 = build_record(Flags::SEGMENTED, 13, 34)?;
+        let r2 = build_record(Flags::empty(), 21, 8)?;
+        let r3 = build_record(Flags::SEGMENTED, 21, 8)?;
+        let r4 = build_record(Flags::SEGMENTED, 34, 13)?;
+
+        assert_eq!(generator.generate(&r0, 0)?, "0".parse()?);
+        assert_eq!(generator.generate(&r1, 1)?, "1".parse()?);
+        assert_eq!(generator.generate(&r2, 2)?, "2".parse()?);
+        assert_eq!(generator.generate(&r3, 3)?, "0".parse()?);
+        assert_eq!(generator.generate(&r4, 4)?, "1".parse()?);
+
+        assert!(generator.pending_mate_read_names.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_with_missing_mate() -> Result<(), Box<dyn std::error::Error>> {
+        let mut generator = ReadNameGenerator::default();
+
+        let r0 = Record::builder()
+            .set_bam_flags(Flags::SEGMENTED)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(8)?)
+            .set_next_fragment_reference_sequence_id(0)
+            .set_next_mate_alignment_start(Position::try_from(21)?)
+            .build();
+
+        assert_eq!(generator.generate(&r0, 0)?, "0".parse()?);
+        assert_eq!(generator.pending_mate_read_names.len(), 1);
+
+        // The mate of r0 would be at 21.
+        let r1 = Record::builder()
+            .set_bam_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(34)?)
+            .build();
+
+        assert_eq!(generator.generate(&r1, 1)?, "1".parse()?);
+        assert!(generator.pending_mate_read_names.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_records_with_synthesized_read_names() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_fasta as fasta;
+        use noodles_sam::AlignmentRecord;
+
+        use crate::{Reader, Writer};
+
+        let header = sam::Header::default();
+
+        let mut writer = Writer::builder(Vec::new())
+            .synthesize_missing_read_names(true)
+            .build();
+
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        let mate_flags = Flags::SEGMENTED | Flags::UNMAPPED | Flags::MATE_UNMAPPED;
+
+        // The last record's mate is never written.
+        for flags in [
+            mate_flags | Flags::FIRST_SEGMENT,
+            Flags::UNMAPPED,
+            mate_flags | Flags::LAST_SEGMENT,
+            mate_flags | Flags::FIRST_SEGMENT,
+        ] {
+            let record = Record::builder().set_bam_flags(flags).build();
+            writer.write_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+        assert!(writer
+            .read_name_generator
+            .pending_mate_read_names
+            .is_empty());
+
+        let mut reader = Reader::new(&writer.get_ref()[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let repository = fasta::Repository::default();
+
+        let actual: Vec<_> = reader
+            .records(&repository, &header)
+            .map(|result| result.map(|record| record.read_name().cloned()))
+            .collect::<io::Result<_>>()?;
+
+        let expected = [
+            Some("0".parse()?),
+            Some("1".parse()?),
+            Some("0".parse()?),
+            Some("3".parse()?),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
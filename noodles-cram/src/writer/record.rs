@@ -25,9 +25,10 @@ use crate::{
         },
         CompressionHeader,
     },
+    huffman::CanonicalHuffmanEncoder,
     record::{
         feature::{self, substitution},
-        Feature, Flags, NextMateFlags,
+        Feature, Flags, NextMateFlags, MISSING_QUALITY_SCORE,
     },
     BitWriter, Record,
 };
@@ -38,6 +39,7 @@ pub enum WriteRecordError {
     MissingDataSeriesEncoding(DataSeries),
     MissingTagEncoding(tag_ids_dictionary::Key),
     MissingExternalBlock(i32),
+    UnsupportedEncoding(Encoding),
 }
 
 impl error::Error for WriteRecordError {}
@@ -52,6 +54,9 @@ impl fmt::Display for WriteRecordError {
             Self::MissingExternalBlock(block_content_id) => {
                 write!(f, "missing external block: {}", block_content_id)
             }
+            Self::UnsupportedEncoding(encoding) => {
+                write!(f, "unsupported encoding: {:?}", encoding)
+            }
         }
     }
 }
@@ -62,6 +67,7 @@ pub struct Writer<'a, W, X> {
     external_data_writers: &'a mut HashMap<i32, X>,
     reference_sequence_id: ReferenceSequenceId,
     prev_alignment_start: Option<Position>,
+    huffman_encoders: HuffmanEncoders,
 }
 
 impl<'a, W, X> Writer<'a, W, X>
@@ -82,6 +88,7 @@ where
             external_data_writers,
             reference_sequence_id,
             prev_alignment_start: initial_alignment_start,
+            huffman_encoders: HuffmanEncoders::default(),
         }
     }
 
@@ -123,6 +130,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             bam_bit_flags,
         )
     }
@@ -139,6 +147,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             cram_bit_flags,
         )
     }
@@ -179,6 +188,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             reference_id,
         )
     }
@@ -196,6 +206,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             len,
         )
     }
@@ -215,13 +226,7 @@ where
             match (alignment_start, self.prev_alignment_start) {
                 (None, None) => 0,
                 (Some(alignment_start), Some(prev_alignment_start)) => {
-                    let alignment_start = i32::try_from(usize::from(alignment_start))
-                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
-
-                    let prev_alignment_start = i32::try_from(usize::from(prev_alignment_start))
-                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
-
-                    alignment_start - prev_alignment_start
+                    calculate_alignment_start_delta(alignment_start, prev_alignment_start)?
                 }
                 _ => {
                     return Err(io::Error::new(
@@ -242,6 +247,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             alignment_start_or_delta,
         )
     }
@@ -265,6 +271,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             read_group,
         )
     }
@@ -289,6 +296,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             read_name,
         )
     }
@@ -333,6 +341,7 @@ where
                     encoding,
                     self.core_data_writer,
                     self.external_data_writers,
+                    &mut self.huffman_encoders,
                     next_mate_bit_flags,
                 )
             })
@@ -368,6 +377,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             raw_next_fragment_reference_sequence_id,
         )
     }
@@ -398,6 +408,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             position,
         )
     }
@@ -417,6 +428,7 @@ where
                     encoding,
                     self.core_data_writer,
                     self.external_data_writers,
+                    &mut self.huffman_encoders,
                     template_size,
                 )
             })
@@ -444,6 +456,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             n,
         )
     }
@@ -485,6 +498,7 @@ where
                 encoding,
                 self.core_data_writer,
                 self.external_data_writers,
+                &mut self.huffman_encoders,
                 &buf,
             )?;
         }
@@ -505,11 +519,14 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             n,
         )
     }
 
     fn write_mapped_read(&mut self, record: &Record) -> io::Result<()> {
+        validate_feature_positions(record.features())?;
+
         self.write_number_of_read_features(record.features().len())?;
 
         let mut prev_position = 0;
@@ -523,9 +540,7 @@ where
         self.write_mapping_quality(record.mapping_quality())?;
 
         if record.cram_flags().are_quality_scores_stored_as_array() {
-            for &score in record.quality_scores().as_ref() {
-                self.write_quality_score(score)?;
-            }
+            self.write_quality_scores_stored_as_array(record)?;
         }
 
         Ok(())
@@ -550,6 +565,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             number_of_read_features,
         )
     }
@@ -618,6 +634,7 @@ where
                     encoding,
                     self.core_data_writer,
                     self.external_data_writers,
+                    &mut self.huffman_encoders,
                     feature_code,
                 )
             })
@@ -642,6 +659,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             position,
         )
     }
@@ -664,6 +682,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             &raw_bases,
         )
     }
@@ -688,6 +707,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             &scores,
         )
     }
@@ -710,11 +730,32 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             raw_base,
         )
     }
 
+    // Missing quality scores are written as a run of `MISSING_QUALITY_SCORE` with the length of
+    // the read.
+    fn write_quality_scores_stored_as_array(&mut self, record: &Record) -> io::Result<()> {
+        if record.quality_scores().is_empty() {
+            for _ in 0..record.read_length() {
+                self.write_quality_score_byte(MISSING_QUALITY_SCORE)?;
+            }
+        } else {
+            for &score in record.quality_scores().as_ref() {
+                self.write_quality_score(score)?;
+            }
+        }
+
+        Ok(())
+    }
+
     fn write_quality_score(&mut self, quality_score: Score) -> io::Result<()> {
+        self.write_quality_score_byte(u8::from(quality_score))
+    }
+
+    fn write_quality_score_byte(&mut self, score: u8) -> io::Result<()> {
         let encoding = self
             .compression_header
             .data_series_encoding_map()
@@ -726,12 +767,11 @@ where
                 )
             })?;
 
-        let score = u8::from(quality_score);
-
         encode_byte(
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             score,
         )
     }
@@ -769,6 +809,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             code,
         )
     }
@@ -791,6 +832,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             &raw_bases,
         )
     }
@@ -813,6 +855,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             n,
         )
     }
@@ -835,6 +878,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             n,
         )
     }
@@ -857,6 +901,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             &raw_bases,
         )
     }
@@ -879,6 +924,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             n,
         )
     }
@@ -901,6 +947,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             n,
         )
     }
@@ -930,29 +977,75 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             mapping_quality,
         )
     }
 
     fn write_unmapped_read(&mut self, record: &Record) -> io::Result<()> {
-        for &base in record.bases().as_ref() {
-            self.write_base(base)?;
+        if !record.cram_flags().decode_sequence_as_unknown() {
+            for &base in record.bases().as_ref() {
+                self.write_base(base)?;
+            }
         }
 
         if record.cram_flags().are_quality_scores_stored_as_array() {
-            for &score in record.quality_scores().as_ref() {
-                self.write_quality_score(score)?;
-            }
+            self.write_quality_scores_stored_as_array(record)?;
         }
 
         Ok(())
     }
 }
 
+// Feature positions are written as deltas, so features must be sorted by position.
+fn validate_feature_positions(features: &[Feature]) -> io::Result<()> {
+    for pair in features.windows(2) {
+        let (prev_position, position) = (pair[0].position(), pair[1].position());
+
+        if position < prev_position {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "invalid feature position: expected >= {}, got {}",
+                    prev_position, position
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn calculate_alignment_start_delta(
+    alignment_start: Position,
+    prev_alignment_start: Position,
+) -> io::Result<i32> {
+    let alignment_start = i64::try_from(usize::from(alignment_start))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    let prev_alignment_start = i64::try_from(usize::from(prev_alignment_start))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    let delta = alignment_start
+        .checked_sub(prev_alignment_start)
+        .and_then(|delta| i32::try_from(delta).ok());
+
+    delta.ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "alignment start delta overflow: {} - {} does not fit in an i32",
+                alignment_start, prev_alignment_start
+            ),
+        )
+    })
+}
+
 fn encode_byte<W, X>(
     encoding: &Encoding,
-    _core_data_writer: &mut BitWriter<W>,
+    core_data_writer: &mut BitWriter<W>,
     external_data_writers: &mut HashMap<i32, X>,
+    huffman_encoders: &mut HuffmanEncoders,
     value: u8,
 ) -> io::Result<()>
 where
@@ -972,14 +1065,21 @@ where
 
             writer.write_u8(value)
         }
-        _ => todo!("encode_byte: {:?}", encoding),
+        Encoding::Huffman(..) | Encoding::Beta(..) => encode_bits(
+            encoding,
+            core_data_writer,
+            huffman_encoders,
+            i32::from(value),
+        ),
+        _ => Err(unsupported_encoding(encoding)),
     }
 }
 
 fn encode_itf8<W, X>(
     encoding: &Encoding,
-    _core_data_writer: &mut BitWriter<W>,
+    core_data_writer: &mut BitWriter<W>,
     external_data_writers: &mut HashMap<i32, X>,
+    huffman_encoders: &mut HuffmanEncoders,
     value: i32,
 ) -> io::Result<()>
 where
@@ -999,7 +1099,42 @@ where
 
             write_itf8(writer, value)
         }
-        _ => todo!("encode_itf8: {:?}", encoding),
+        Encoding::Huffman(..) | Encoding::Beta(..) => {
+            encode_bits(encoding, core_data_writer, huffman_encoders, value)
+        }
+        _ => Err(unsupported_encoding(encoding)),
+    }
+}
+
+fn encode_bits<W>(
+    encoding: &Encoding,
+    core_data_writer: &mut BitWriter<W>,
+    huffman_encoders: &mut HuffmanEncoders,
+    value: i32,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    match encoding {
+        Encoding::Huffman(..) => {
+            let encoder = huffman_encoders.get_or_build(encoding)?;
+            encoder.encode(core_data_writer, value)
+        }
+        Encoding::Beta(offset, len) => {
+            let n = value
+                .checked_add(*offset)
+                .and_then(|n| u32::try_from(n).ok())
+                .filter(|&n| *len >= 32 || n >> len == 0)
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("value out of range for beta encoding: {}", value),
+                    )
+                })?;
+
+            core_data_writer.write_u32(n, *len as usize)
+        }
+        _ => Err(unsupported_encoding(encoding)),
     }
 }
 
@@ -1007,6 +1142,7 @@ fn encode_byte_array<W, X>(
     encoding: &Encoding,
     core_data_writer: &mut BitWriter<W>,
     external_data_writers: &mut HashMap<i32, X>,
+    huffman_encoders: &mut HuffmanEncoders,
     data: &[u8],
 ) -> io::Result<()>
 where
@@ -1029,12 +1165,19 @@ where
         Encoding::ByteArrayLen(len_encoding, value_encoding) => {
             let len = i32::try_from(data.len())
                 .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
-            encode_itf8(len_encoding, core_data_writer, external_data_writers, len)?;
+            encode_itf8(
+                len_encoding,
+                core_data_writer,
+                external_data_writers,
+                huffman_encoders,
+                len,
+            )?;
 
             encode_byte_array(
                 value_encoding,
                 core_data_writer,
                 external_data_writers,
+                huffman_encoders,
                 data,
             )
         }
@@ -1053,6 +1196,162 @@ where
 
             Ok(())
         }
-        _ => todo!("encode_byte_array: {:?}", encoding),
+        _ => Err(unsupported_encoding(encoding)),
+    }
+}
+
+fn unsupported_encoding(encoding: &Encoding) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        WriteRecordError::UnsupportedEncoding(encoding.clone()),
+    )
+}
+
+// Canonical Huffman encoders by their encoding.
+//
+// Building an encoder builds its code book, so each is built once and reused for every value
+// written with the same encoding.
+#[derive(Default)]
+struct HuffmanEncoders(HashMap<Encoding, CanonicalHuffmanEncoder>);
+
+impl HuffmanEncoders {
+    fn get_or_build(&mut self, encoding: &Encoding) -> io::Result<&CanonicalHuffmanEncoder> {
+        if !self.0.contains_key(encoding) {
+            let encoder = match encoding {
+                Encoding::Huffman(alphabet, bit_lens) => {
+                    CanonicalHuffmanEncoder::new(alphabet, bit_lens)
+                }
+                _ => return Err(unsupported_encoding(encoding)),
+            };
+
+            self.0.insert(encoding.clone(), encoder);
+        }
+
+        Ok(&self.0[encoding])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_alignment_start_with_delta_overflow() -> Result<(), Box<dyn std::error::Error>> {
+        let compression_header = CompressionHeader::builder().build();
+        assert!(compression_header.preservation_map().ap_data_series_delta());
+
+        let mut core_data_writer = BitWriter::new(Vec::new());
+        let mut external_data_writers: HashMap<i32, Vec<u8>> = HashMap::new();
+
+        let mut writer = Writer::new(
+            &compression_header,
+            &mut core_data_writer,
+            &mut external_data_writers,
+            ReferenceSequenceId::None,
+            Some(Position::try_from(1)?),
+        );
+
+        let alignment_start = Position::try_from(1 << 32)?;
+
+        assert!(matches!(
+            writer.write_alignment_start(Some(alignment_start)),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+                && e.to_string().contains("alignment start delta overflow")
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_mapped_read_with_unsorted_features() -> Result<(), Box<dyn std::error::Error>> {
+        let compression_header = CompressionHeader::builder().build();
+
+        let mut core_data_writer = BitWriter::new(Vec::new());
+        let mut external_data_writers: HashMap<i32, Vec<u8>> = HashMap::new();
+
+        let mut writer = Writer::new(
+            &compression_header,
+            &mut core_data_writer,
+            &mut external_data_writers,
+            ReferenceSequenceId::None,
+            None,
+        );
+
+        let record = Record::builder()
+            .add_feature(Feature::InsertBase(Position::try_from(3)?, Base::A))
+            .add_feature(Feature::InsertBase(Position::try_from(1)?, Base::C))
+            .build();
+
+        assert!(matches!(
+            writer.write_mapped_read(&record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_calculate_alignment_start_delta() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(
+            calculate_alignment_start_delta(Position::try_from(13)?, Position::try_from(8)?)?,
+            5
+        );
+
+        assert_eq!(
+            calculate_alignment_start_delta(Position::try_from(8)?, Position::try_from(13)?)?,
+            -5
+        );
+
+        let alignment_start = Position::try_from(i32::MAX as usize + 2)?;
+        let prev_alignment_start = Position::try_from(1)?;
+
+        assert!(matches!(
+            calculate_alignment_start_delta(alignment_start, prev_alignment_start),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert_eq!(
+            calculate_alignment_start_delta(alignment_start, Position::try_from(2)?)?,
+            i32::MAX
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_itf8() -> io::Result<()> {
+        let mut core_data_writer = BitWriter::new(Vec::new());
+        let mut external_data_writers: HashMap<i32, Vec<u8>> = HashMap::new();
+        let mut huffman_encoders = HuffmanEncoders::default();
+
+        let encoding = Encoding::Huffman(vec![0x4e, 0x44, 0x4c], vec![1, 2, 2]);
+
+        for value in [0x4e, 0x44, 0x4c, 0x4e] {
+            encode_itf8(
+                &encoding,
+                &mut core_data_writer,
+                &mut external_data_writers,
+                &mut huffman_encoders,
+                value,
+            )?;
+        }
+
+        assert_eq!(huffman_encoders.0.len(), 1);
+        assert_eq!(core_data_writer.finish()?, [0b01011000]);
+
+        let mut core_data_writer = BitWriter::new(Vec::new());
+
+        assert!(matches!(
+            encode_itf8(
+                &Encoding::Golomb(0, 1),
+                &mut core_data_writer,
+                &mut external_data_writers,
+                &mut huffman_encoders,
+                8,
+            ),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
     }
 }
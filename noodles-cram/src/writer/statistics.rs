@@ -0,0 +1,259 @@
+//! CRAM writer statistics.
+
+use std::collections::HashMap;
+
+use noodles_sam::record::data::field::Tag;
+
+use crate::{
+    data_container::{compression_header::Encoding, CompressionHeader, DataSeries},
+    DataContainer,
+};
+
+/// CRAM writer statistics.
+///
+/// This holds the number of bytes written per data series and per tag, as measured in the
+/// uncompressed core data and external data blocks of each slice.
+///
+/// If an external block is shared by multiple data series, its bytes are attributed to the first
+/// data series in the order of [`DataSeries`] variants.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Statistics {
+    core_data_len: u64,
+    data_series_lens: HashMap<DataSeries, u64>,
+    tag_lens: HashMap<Tag, u64>,
+}
+
+impl Statistics {
+    /// Returns the number of bytes written to the core data blocks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::writer::Statistics;
+    /// let statistics = Statistics::default();
+    /// assert_eq!(statistics.core_data_len(), 0);
+    /// ```
+    pub fn core_data_len(&self) -> u64 {
+        self.core_data_len
+    }
+
+    /// Returns the number of bytes written to the external blocks of the given data series.
+    ///
+    /// Core data is not attributed to data series. Values of data series that are encoded in the
+    /// core data block, e.g., bit flags (`BF`), read lengths (`RL`), read groups (`RG`), and
+    /// mapping qualities (`MQ`) when Huffman or beta encoded, are only counted in
+    /// [`Self::core_data_len`], and this returns 0 for them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{data_container::DataSeries, writer::Statistics};
+    /// let statistics = Statistics::default();
+    /// assert_eq!(statistics.data_series_len(DataSeries::ReadNames), 0);
+    /// ```
+    pub fn data_series_len(&self, data_series: DataSeries) -> u64 {
+        self.data_series_lens
+            .get(&data_series)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the number of bytes written to the external blocks of the given tag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::writer::Statistics;
+    /// use noodles_sam::record::data::field::Tag;
+    /// let statistics = Statistics::default();
+    /// assert_eq!(statistics.tag_len(Tag::ReadGroup), 0);
+    /// ```
+    pub fn tag_len(&self, tag: Tag) -> u64 {
+        self.tag_lens.get(&tag).copied().unwrap_or_default()
+    }
+
+    /// Returns an iterator over the number of bytes written per data series.
+    pub fn data_series_lens(&self) -> impl Iterator<Item = (DataSeries, u64)> + '_ {
+        self.data_series_lens.iter().map(|(&ds, &len)| (ds, len))
+    }
+
+    /// Returns an iterator over the number of bytes written per tag.
+    pub fn tag_lens(&self) -> impl Iterator<Item = (Tag, u64)> + '_ {
+        self.tag_lens.iter().map(|(&tag, &len)| (tag, len))
+    }
+
+    pub(crate) fn update(&mut self, data_container: &DataContainer) {
+        let compression_header = data_container.compression_header();
+        let data_series_block_content_ids = build_data_series_block_content_ids(compression_header);
+
+        for slice in data_container.slices() {
+            self.add_core_data_len(slice.core_data_block().uncompressed_len() as u64);
+
+            for block in slice.external_blocks() {
+                self.add_external_data_len(
+                    compression_header,
+                    &data_series_block_content_ids,
+                    block.content_id(),
+                    block.uncompressed_len() as u64,
+                );
+            }
+        }
+    }
+
+    pub(crate) fn add_core_data_len(&mut self, len: u64) {
+        self.core_data_len += len;
+    }
+
+    pub(crate) fn add_external_data_len(
+        &mut self,
+        compression_header: &CompressionHeader,
+        data_series_block_content_ids: &HashMap<i32, DataSeries>,
+        block_content_id: i32,
+        len: u64,
+    ) {
+        if compression_header
+            .tag_encoding_map()
+            .contains_key(&block_content_id)
+        {
+            if let Some(tag) = tag_from_block_content_id(block_content_id) {
+                *self.tag_lens.entry(tag).or_default() += len;
+            }
+        } else if let Some(&data_series) = data_series_block_content_ids.get(&block_content_id) {
+            *self.data_series_lens.entry(data_series).or_default() += len;
+        }
+    }
+}
+
+pub(crate) fn build_data_series_block_content_ids(
+    compression_header: &CompressionHeader,
+) -> HashMap<i32, DataSeries> {
+    let mut data_series_block_content_ids = HashMap::new();
+
+    for data_series in DataSeries::ALL {
+        if let Some(encoding) = compression_header
+            .data_series_encoding_map()
+            .get(data_series)
+        {
+            for block_content_id in block_content_ids(encoding) {
+                data_series_block_content_ids
+                    .entry(block_content_id)
+                    .or_insert(data_series);
+            }
+        }
+    }
+
+    data_series_block_content_ids
+}
+
+fn block_content_ids(encoding: &Encoding) -> Vec<i32> {
+    match encoding {
+        Encoding::External(block_content_id) => vec![*block_content_id],
+        Encoding::ByteArrayLen(len_encoding, value_encoding) => {
+            let mut ids = block_content_ids(len_encoding);
+            ids.extend(block_content_ids(value_encoding));
+            ids
+        }
+        Encoding::ByteArrayStop(_, block_content_id) => vec![*block_content_id],
+        _ => Vec::new(),
+    }
+}
+
+// The tag block content ID is the tag ID, i.e., the two tag bytes followed by the value type.
+fn tag_from_block_content_id(block_content_id: i32) -> Option<Tag> {
+    let l = (block_content_id >> 16) as u8;
+    let r = (block_content_id >> 8) as u8;
+    Tag::try_from([l, r]).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::{
+        self as sam,
+        record::data::{field::Value, Field},
+    };
+
+    use super::*;
+    use crate::{record::Flags, Record, Writer};
+
+    #[test]
+    fn test_update() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::default();
+
+        let mut writer = Writer::builder(Vec::new()).collect_statistics(true).build();
+
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        for read_name in ["r0", "r1"] {
+            let record = Record::builder()
+                .set_flags(Flags::QUALITY_SCORES_STORED_AS_ARRAY)
+                .set_read_name(read_name.parse()?)
+                .set_read_length(4)
+                .set_bases("ACGT".parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .set_tags(sam::record::Data::try_from(vec![Field::new(
+                    Tag::Comment,
+                    Value::String(String::from("noodles")),
+                )])?)
+                .build();
+
+            writer.write_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let statistics = writer.statistics().expect("missing statistics");
+
+        assert!(statistics.data_series_len(DataSeries::ReadNames) > 0);
+        assert_eq!(statistics.data_series_len(DataSeries::Bases), 8);
+        assert_eq!(statistics.data_series_len(DataSeries::QualityScores), 8);
+        assert!(statistics.tag_len(Tag::Comment) > 0);
+        assert_eq!(statistics.data_series_len(DataSeries::DeletionLengths), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_with_core_data() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::default();
+
+        let mut writer = Writer::builder(Vec::new()).collect_statistics(true).build();
+
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        for (read_length, bases) in [(4, "ACGT"), (2, "AC")] {
+            let record = Record::builder()
+                .set_read_length(read_length)
+                .set_bases(bases.parse()?)
+                .build();
+
+            writer.write_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let statistics = writer.statistics().expect("missing statistics");
+
+        // The read lengths are Huffman encoded in the core data block.
+        assert!(statistics.core_data_len() > 0);
+        assert_eq!(statistics.data_series_len(DataSeries::ReadLengths), 0);
+        assert_eq!(statistics.data_series_len(DataSeries::Bases), 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_block_content_ids() {
+        assert_eq!(block_content_ids(&Encoding::External(1)), [1]);
+        assert_eq!(
+            block_content_ids(&Encoding::ByteArrayLen(
+                Box::new(Encoding::External(2)),
+                Box::new(Encoding::External(3)),
+            )),
+            [2, 3]
+        );
+        assert_eq!(block_content_ids(&Encoding::ByteArrayStop(0x00, 4)), [4]);
+        assert!(block_content_ids(&Encoding::Beta(0, 8)).is_empty());
+    }
+}
@@ -137,6 +137,73 @@ pub fn optimize_chunks(chunks: &[Chunk], min_offset: bgzf::VirtualPosition) -> V
     merged_chunks
 }
 
+/// Coalesces a list of chunks into a list of non-overlapping chunks that are at least a given
+/// distance apart.
+///
+/// Chunks are merged if they overlap or if the gap between the end of one chunk and the start of
+/// the next is less than or equal to `merge_distance` compressed bytes. Chunks that end and start
+/// in the same BGZF block are always merged. Reading the coalesced chunks may read records outside
+/// of the original chunks, but it reduces the number of seeks.
+///
+/// A `merge_distance` of 0 only merges overlapping chunks and chunks that meet in the same block.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bgzf as bgzf;
+/// use noodles_csi::{
+///     binning_index::coalesce_chunks,
+///     index::reference_sequence::bin::Chunk,
+/// };
+///
+/// let chunks = [
+///     Chunk::new(bgzf::VirtualPosition::from(2 << 16), bgzf::VirtualPosition::from(3 << 16)),
+///     Chunk::new(bgzf::VirtualPosition::from(5 << 16), bgzf::VirtualPosition::from(8 << 16)),
+///     Chunk::new(bgzf::VirtualPosition::from(21 << 16), bgzf::VirtualPosition::from(34 << 16)),
+/// ];
+///
+/// let actual = coalesce_chunks(&chunks, 8);
+///
+/// let expected = [
+///     Chunk::new(bgzf::VirtualPosition::from(2 << 16), bgzf::VirtualPosition::from(8 << 16)),
+///     Chunk::new(bgzf::VirtualPosition::from(21 << 16), bgzf::VirtualPosition::from(34 << 16)),
+/// ];
+///
+/// assert_eq!(actual, expected);
+/// ```
+pub fn coalesce_chunks(chunks: &[Chunk], merge_distance: u64) -> Vec<Chunk> {
+    let mut chunks = chunks.to_vec();
+
+    if chunks.is_empty() {
+        return chunks;
+    }
+
+    chunks.sort_unstable_by_key(|c| c.start());
+
+    let mut coalesced_chunks = Vec::with_capacity(chunks.len());
+
+    // `chunks` is guaranteed to be non-empty.
+    let mut current_chunk = chunks[0];
+
+    for next_chunk in chunks.iter().skip(1) {
+        let gap = next_chunk
+            .start()
+            .compressed()
+            .saturating_sub(current_chunk.end().compressed());
+
+        if next_chunk.start() > current_chunk.end() && gap > merge_distance {
+            coalesced_chunks.push(current_chunk);
+            current_chunk = *next_chunk;
+        } else if current_chunk.end() < next_chunk.end() {
+            current_chunk = Chunk::new(current_chunk.start(), next_chunk.end());
+        }
+    }
+
+    coalesced_chunks.push(current_chunk);
+
+    coalesced_chunks
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -218,4 +285,41 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_coalesce_chunks() {
+        fn chunk((c0, u0): (u64, u16), (c1, u1): (u64, u16)) -> Chunk {
+            Chunk::new(
+                bgzf::VirtualPosition::from(c0 << 16 | u64::from(u0)),
+                bgzf::VirtualPosition::from(c1 << 16 | u64::from(u1)),
+            )
+        }
+
+        let chunks = [
+            chunk((0, 8), (0, 13)),
+            // same block
+            chunk((0, 21), (55, 0)),
+            // 34 bytes apart
+            chunk((89, 5), (144, 0)),
+            chunk((144, 0), (233, 8)),
+            // 4096 bytes apart
+            chunk((4329, 0), (4400, 0)),
+        ];
+
+        assert_eq!(
+            coalesce_chunks(&chunks, 64),
+            [chunk((0, 8), (233, 8)), chunk((4329, 0), (4400, 0))]
+        );
+
+        assert_eq!(
+            coalesce_chunks(&chunks, 0),
+            [
+                chunk((0, 8), (55, 0)),
+                chunk((89, 5), (233, 8)),
+                chunk((4329, 0), (4400, 0)),
+            ]
+        );
+
+        assert!(coalesce_chunks(&[], 64).is_empty());
+    }
 }
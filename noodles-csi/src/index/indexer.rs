@@ -0,0 +1,233 @@
+use std::io;
+
+use noodles_core::Position;
+
+use super::{
+    reference_sequence::{self, bin::Chunk},
+    Index, ReferenceSequence,
+};
+
+const DEFAULT_MIN_SHIFT: u8 = 14;
+const DEFAULT_DEPTH: u8 = 5;
+
+/// A coordinate-sorted index (CSI) indexer.
+///
+/// Unlike a BAI or tabix index, the binning scheme of a CSI is parameterized by the minimum
+/// interval size (`min_shift`) and the number of levels (`depth`). The maximum indexable position
+/// is 2^(`min_shift` + 3 * `depth`) - 1, e.g., a `depth` of 6 with the default `min_shift` (14)
+/// allows positions up to 2^32 - 1.
+#[derive(Debug)]
+pub struct Indexer {
+    min_shift: u8,
+    depth: u8,
+    reference_sequence_builders: Vec<reference_sequence::Builder>,
+    unplaced_unmapped_record_count: u64,
+}
+
+impl Indexer {
+    /// Sets the number of bits for the minimum interval.
+    ///
+    /// This must be set before adding any records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let mut indexer = csi::Index::indexer();
+    /// indexer.set_min_shift(14);
+    /// ```
+    pub fn set_min_shift(&mut self, min_shift: u8) {
+        self.min_shift = min_shift;
+    }
+
+    /// Sets the depth of the binning index.
+    ///
+    /// This must be set before adding any records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let mut indexer = csi::Index::indexer();
+    /// indexer.set_depth(6);
+    /// ```
+    pub fn set_depth(&mut self, depth: u8) {
+        self.depth = depth;
+    }
+
+    /// Adds a record.
+    ///
+    /// Records are expected to be coordinate-sorted. The interval is 1-based, inclusive.
+    ///
+    /// This returns an error if the end position is greater than the maximum position of the
+    /// binning index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_core::Position;
+    /// use noodles_csi::{self as csi, index::reference_sequence::bin::Chunk};
+    ///
+    /// let mut indexer = csi::Index::indexer();
+    ///
+    /// let start = Position::try_from(8)?;
+    /// let end = Position::try_from(13)?;
+    ///
+    /// indexer.add_record(0, start, end, Chunk::new(
+    ///     bgzf::VirtualPosition::from(144),
+    ///     bgzf::VirtualPosition::from(233),
+    /// ))?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn add_record(
+        &mut self,
+        reference_sequence_id: usize,
+        start: Position,
+        end: Position,
+        chunk: Chunk,
+    ) -> io::Result<()> {
+        if reference_sequence_id >= self.reference_sequence_builders.len() {
+            let (min_shift, depth) = (self.min_shift, self.depth);
+
+            self.reference_sequence_builders
+                .resize_with(reference_sequence_id + 1, || {
+                    ReferenceSequence::builder(min_shift, depth)
+                });
+        }
+
+        let reference_sequence_builder =
+            &mut self.reference_sequence_builders[reference_sequence_id];
+        reference_sequence_builder.add_record(start, end, chunk)?;
+
+        Ok(())
+    }
+
+    /// Adds an unplaced, unmapped record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let mut indexer = csi::Index::indexer();
+    /// indexer.add_unplaced_unmapped_record();
+    /// ```
+    pub fn add_unplaced_unmapped_record(&mut self) {
+        self.unplaced_unmapped_record_count += 1;
+    }
+
+    /// Builds a coordinate-sorted index (CSI).
+    ///
+    /// `reference_sequence_count` is the number of reference sequences in the associated file.
+    /// Reference sequences with no records are given empty entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let indexer = csi::Index::indexer();
+    /// let index = indexer.build(0);
+    /// ```
+    pub fn build(mut self, reference_sequence_count: usize) -> Index {
+        if reference_sequence_count > self.reference_sequence_builders.len() {
+            let (min_shift, depth) = (self.min_shift, self.depth);
+
+            self.reference_sequence_builders
+                .resize_with(reference_sequence_count, || {
+                    ReferenceSequence::builder(min_shift, depth)
+                });
+        }
+
+        let reference_sequences = self
+            .reference_sequence_builders
+            .into_iter()
+            .map(|b| b.build())
+            .collect();
+
+        Index::builder()
+            .set_min_shift(self.min_shift)
+            .set_depth(self.depth)
+            .set_reference_sequences(reference_sequences)
+            .set_unplaced_unmapped_record_count(self.unplaced_unmapped_record_count)
+            .build()
+    }
+}
+
+impl Default for Indexer {
+    fn default() -> Self {
+        Self {
+            min_shift: DEFAULT_MIN_SHIFT,
+            depth: DEFAULT_DEPTH,
+            reference_sequence_builders: Vec::new(),
+            unplaced_unmapped_record_count: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_bgzf as bgzf;
+
+    use super::*;
+    use crate::BinningIndex;
+
+    #[test]
+    fn test_build_with_large_reference_sequence() -> Result<(), Box<dyn std::error::Error>> {
+        // BAI and tabix indices are limited to positions < 2^29.
+        const MIN_SHIFT: u8 = 14;
+        const DEPTH: u8 = 6;
+
+        let mut indexer = Indexer::default();
+        indexer.set_min_shift(MIN_SHIFT);
+        indexer.set_depth(DEPTH);
+
+        let chunk_0 = Chunk::new(
+            bgzf::VirtualPosition::from(0),
+            bgzf::VirtualPosition::from(233),
+        );
+        indexer.add_record(0, Position::try_from(8)?, Position::try_from(13)?, chunk_0)?;
+
+        let start = Position::try_from((1 << 32) - 144)?;
+        let end = Position::try_from((1 << 32) - 8)?;
+        let chunk_1 = Chunk::new(
+            bgzf::VirtualPosition::from(233),
+            bgzf::VirtualPosition::from(377),
+        );
+        indexer.add_record(0, start, end, chunk_1)?;
+
+        let index = indexer.build(2);
+
+        assert_eq!(index.min_shift(), MIN_SHIFT);
+        assert_eq!(index.depth(), DEPTH);
+        assert_eq!(index.reference_sequences().len(), 2);
+
+        let query_start = Position::try_from((1 << 32) - 21)?;
+        let chunks = index.query(0, query_start..)?;
+        assert_eq!(chunks, [chunk_1]);
+
+        let chunks = index.query(0, ..=Position::try_from(13)?)?;
+        assert_eq!(chunks, [chunk_0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_record_with_default_binning_and_large_position(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut indexer = Indexer::default();
+
+        let start = Position::try_from(1 << 30)?;
+        let end = start;
+        let chunk = Chunk::new(
+            bgzf::VirtualPosition::from(0),
+            bgzf::VirtualPosition::from(233),
+        );
+
+        assert!(matches!(
+            indexer.add_record(0, start, end, chunk),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -1,10 +1,13 @@
 //! Coordinate-sorted index (CSI) reference sequence and fields.
 
 pub mod bin;
+mod builder;
 mod metadata;
 
 pub use self::{bin::Bin, metadata::Metadata};
 
+pub(crate) use self::builder::Builder;
+
 use std::{io, ops::RangeBounds};
 
 use bit_vec::BitVec;
@@ -22,9 +25,27 @@ pub struct ReferenceSequence {
 }
 
 impl ReferenceSequence {
+    pub(crate) fn builder(min_shift: u8, depth: u8) -> Builder {
+        Builder::new(min_shift, depth)
+    }
+
     pub(super) fn max_position(min_shift: u8, depth: u8) -> io::Result<Position> {
-        assert!(min_shift > 0);
-        let n = (1 << (usize::from(min_shift) + 3 * usize::from(depth))) - 1;
+        if min_shift == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid min shift: expected > 0",
+            ));
+        }
+
+        let shift = u32::from(min_shift) + 3 * u32::from(depth);
+
+        let n = 1usize.checked_shl(shift).map(|n| n - 1).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid min shift and depth: max position overflows",
+            )
+        })?;
+
         Position::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
     }
 
@@ -148,6 +169,30 @@ impl ReferenceSequenceExt for ReferenceSequence {
     }
 }
 
+// `CSIv1.pdf` (2020-07-21)
+#[allow(clippy::many_single_char_names)]
+fn reg2bin(start: Position, end: Position, min_shift: u8, depth: u8) -> u32 {
+    // [beg, end), 0-based
+    let beg = usize::from(start) - 1;
+    let end = usize::from(end) - 1;
+
+    let mut l = depth;
+    let mut s = min_shift;
+    let mut t = ((1 << (usize::from(depth) * 3)) - 1) / 7;
+
+    while l > 0 {
+        if beg >> s == end >> s {
+            return (t + (beg >> s)) as u32;
+        }
+
+        l -= 1;
+        s += 3;
+        t -= 1 << (usize::from(l) * 3);
+    }
+
+    0
+}
+
 // `CSIv1.pdf` (2020-07-21)
 #[allow(clippy::many_single_char_names)]
 fn reg2bins(start: Position, end: Position, min_shift: u8, depth: u8, bins: &mut BitVec) {
@@ -185,6 +230,17 @@ mod tests {
         let actual = ReferenceSequence::max_position(MIN_SHIFT, DEPTH)?;
         let expected = Position::try_from(536870911)?;
         assert_eq!(actual, expected);
+
+        assert!(matches!(
+            ReferenceSequence::max_position(0, DEPTH),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            ReferenceSequence::max_position(MIN_SHIFT, 255),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
         Ok(())
     }
 
@@ -202,6 +258,27 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_reg2bin() -> Result<(), noodles_core::position::TryFromIntError> {
+        let start = Position::try_from(8)?;
+        let end = Position::try_from(13)?;
+        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH), 4681);
+
+        let start = Position::try_from(63245986)?;
+        let end = Position::try_from(63245986)?;
+        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH), 8541);
+
+        let start = Position::try_from(121393)?;
+        let end = Position::try_from(196418)?;
+        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH), 73);
+
+        let start = Position::try_from(1)?;
+        let end = Position::try_from(1 << 29)?;
+        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH), 0);
+
+        Ok(())
+    }
+
     #[test]
     fn test_reg2bins() -> Result<(), noodles_core::position::TryFromIntError> {
         // +------------------------------------------------------------------------------------...
@@ -1,9 +1,12 @@
 //! CSI reference sequence bin and fields.
 
+mod builder;
 mod chunk;
 
 pub use self::chunk::Chunk;
 
+pub(crate) use self::builder::Builder;
+
 use noodles_bgzf as bgzf;
 
 pub(crate) const METADATA_CHUNK_COUNT: u32 = 2;
@@ -17,6 +20,10 @@ pub struct Bin {
 }
 
 impl Bin {
+    pub(crate) fn builder() -> Builder {
+        Builder::default()
+    }
+
     /// Calculates the maximum bin ID.
     ///
     /// # Examples
@@ -0,0 +1,109 @@
+use noodles_bgzf as bgzf;
+
+use super::{Bin, Chunk};
+
+/// A CSI reference sequence bin builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    id: u32,
+    loffset: bgzf::VirtualPosition,
+    chunks: Vec<Chunk>,
+}
+
+impl Builder {
+    /// Sets a bin ID.
+    pub fn set_id(&mut self, id: u32) -> &mut Self {
+        self.id = id;
+        self
+    }
+
+    /// Sets the last offset in the linear index.
+    pub fn set_loffset(&mut self, loffset: bgzf::VirtualPosition) -> &mut Self {
+        self.loffset = loffset;
+        self
+    }
+
+    /// Adds or merges a chunk.
+    pub fn add_chunk(&mut self, chunk: Chunk) -> &mut Self {
+        if let Some(last_chunk) = self.chunks.last_mut() {
+            if chunk.start() <= last_chunk.end() {
+                *last_chunk = Chunk::new(last_chunk.start(), chunk.end());
+                return self;
+            }
+        }
+
+        self.chunks.push(chunk);
+
+        self
+    }
+
+    /// Builds a CSI reference sequence bin.
+    pub fn build(self) -> Bin {
+        Bin::new(self.id, self.loffset, self.chunks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_chunk() {
+        let mut builder = Builder::default();
+
+        builder.add_chunk(Chunk::new(
+            bgzf::VirtualPosition::from(5),
+            bgzf::VirtualPosition::from(13),
+        ));
+
+        builder.add_chunk(Chunk::new(
+            bgzf::VirtualPosition::from(8),
+            bgzf::VirtualPosition::from(21),
+        ));
+
+        builder.add_chunk(Chunk::new(
+            bgzf::VirtualPosition::from(34),
+            bgzf::VirtualPosition::from(55),
+        ));
+
+        assert_eq!(
+            builder.chunks,
+            [
+                Chunk::new(
+                    bgzf::VirtualPosition::from(5),
+                    bgzf::VirtualPosition::from(21)
+                ),
+                Chunk::new(
+                    bgzf::VirtualPosition::from(34),
+                    bgzf::VirtualPosition::from(55)
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build() {
+        let mut builder = Builder::default();
+
+        builder
+            .set_id(4681)
+            .set_loffset(bgzf::VirtualPosition::from(5))
+            .add_chunk(Chunk::new(
+                bgzf::VirtualPosition::from(5),
+                bgzf::VirtualPosition::from(13),
+            ));
+
+        let actual = builder.build();
+
+        let expected = Bin::new(
+            4681,
+            bgzf::VirtualPosition::from(5),
+            vec![Chunk::new(
+                bgzf::VirtualPosition::from(5),
+                bgzf::VirtualPosition::from(13),
+            )],
+        );
+
+        assert_eq!(actual, expected);
+    }
+}
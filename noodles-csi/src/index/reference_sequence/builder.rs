@@ -0,0 +1,249 @@
+use std::{cmp, collections::HashMap, io};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+
+use super::{
+    bin::{self, Chunk},
+    reg2bin, Bin, Metadata, ReferenceSequence,
+};
+
+#[derive(Debug)]
+pub struct Builder {
+    min_shift: u8,
+    depth: u8,
+    bin_builders: HashMap<u32, bin::Builder>,
+    intervals: Vec<Option<bgzf::VirtualPosition>>,
+    start_position: bgzf::VirtualPosition,
+    end_position: bgzf::VirtualPosition,
+    mapped_record_count: u64,
+}
+
+impl Builder {
+    pub fn new(min_shift: u8, depth: u8) -> Self {
+        Self {
+            min_shift,
+            depth,
+            bin_builders: HashMap::new(),
+            intervals: Vec::new(),
+            start_position: bgzf::VirtualPosition::default(),
+            end_position: bgzf::VirtualPosition::default(),
+            mapped_record_count: 0,
+        }
+    }
+
+    pub fn add_record(
+        &mut self,
+        start: Position,
+        end: Position,
+        chunk: Chunk,
+    ) -> io::Result<&mut Self> {
+        let max_position = ReferenceSequence::max_position(self.min_shift, self.depth)?;
+
+        if end > max_position {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "invalid end position: expected <= {}, got {}",
+                    max_position, end
+                ),
+            ));
+        }
+
+        self.update_bins(start, end, chunk);
+        self.update_linear_index(start, end, chunk);
+        self.update_metadata(chunk);
+
+        Ok(self)
+    }
+
+    pub fn build(self) -> ReferenceSequence {
+        if self.bin_builders.is_empty() {
+            return ReferenceSequence::new(Vec::new(), None);
+        }
+
+        let depth = self.depth;
+        let intervals = self.intervals;
+
+        let mut bins: Vec<_> = self
+            .bin_builders
+            .into_iter()
+            .map(|(id, mut builder)| {
+                let loffset = first_window(depth, id)
+                    .and_then(|i| intervals.get(i).copied().flatten())
+                    .unwrap_or_default();
+
+                builder.set_loffset(loffset);
+                builder.build()
+            })
+            .collect();
+
+        bins.sort_unstable_by_key(|bin| bin.id());
+
+        let metadata = Metadata::new(
+            self.start_position,
+            self.end_position,
+            self.mapped_record_count,
+            0,
+        );
+
+        ReferenceSequence::new(bins, Some(metadata))
+    }
+
+    fn update_bins(&mut self, start: Position, end: Position, chunk: Chunk) {
+        let bin_id = reg2bin(start, end, self.min_shift, self.depth);
+
+        let builder = self.bin_builders.entry(bin_id).or_insert_with(|| {
+            let mut builder = Bin::builder();
+            builder.set_id(bin_id);
+            builder
+        });
+
+        builder.add_chunk(chunk);
+    }
+
+    fn update_linear_index(&mut self, start: Position, end: Position, chunk: Chunk) {
+        let linear_index_start_offset = (usize::from(start) - 1) >> self.min_shift;
+        let linear_index_end_offset = (usize::from(end) - 1) >> self.min_shift;
+
+        if linear_index_end_offset >= self.intervals.len() {
+            self.intervals
+                .resize(linear_index_end_offset + 1, Default::default());
+        }
+
+        for interval in &mut self.intervals[linear_index_start_offset..=linear_index_end_offset] {
+            interval.get_or_insert(chunk.start());
+        }
+    }
+
+    fn update_metadata(&mut self, chunk: Chunk) {
+        if self.mapped_record_count == 0 {
+            self.start_position = chunk.start();
+        } else {
+            self.start_position = cmp::min(self.start_position, chunk.start());
+        }
+
+        self.mapped_record_count += 1;
+        self.end_position = cmp::max(self.end_position, chunk.end());
+    }
+}
+
+// Returns the index of the first linear index window covered by the given bin.
+fn first_window(depth: u8, bin_id: u32) -> Option<usize> {
+    let mut level_start = 0;
+
+    for level in 0..=depth {
+        let level_end = level_start + (1 << (3 * u32::from(level)));
+
+        if bin_id < level_end {
+            let offset = (bin_id - level_start) as usize;
+            let shift = 3 * usize::from(depth - level);
+            return Some(offset << shift);
+        }
+
+        level_start = level_end;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MIN_SHIFT: u8 = 14;
+    const DEPTH: u8 = 5;
+
+    #[test]
+    fn test_build() -> Result<(), Box<dyn std::error::Error>> {
+        let mut builder = Builder::new(MIN_SHIFT, DEPTH);
+
+        builder.add_record(
+            Position::try_from(8)?,
+            Position::try_from(13)?,
+            Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(9),
+            ),
+        )?;
+
+        builder.add_record(
+            Position::try_from(121393)?,
+            Position::try_from(196418)?,
+            Chunk::new(
+                bgzf::VirtualPosition::from(9),
+                bgzf::VirtualPosition::from(3473408),
+            ),
+        )?;
+
+        let actual = builder.build();
+
+        let expected = ReferenceSequence::new(
+            vec![
+                Bin::new(
+                    73,
+                    bgzf::VirtualPosition::from(0),
+                    vec![Chunk::new(
+                        bgzf::VirtualPosition::from(9),
+                        bgzf::VirtualPosition::from(3473408),
+                    )],
+                ),
+                Bin::new(
+                    4681,
+                    bgzf::VirtualPosition::from(0),
+                    vec![Chunk::new(
+                        bgzf::VirtualPosition::from(0),
+                        bgzf::VirtualPosition::from(9),
+                    )],
+                ),
+            ],
+            Some(Metadata::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(3473408),
+                2,
+                0,
+            )),
+        );
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_no_bins() {
+        let reference_sequence = Builder::new(MIN_SHIFT, DEPTH).build();
+        assert_eq!(reference_sequence, ReferenceSequence::new(Vec::new(), None));
+    }
+
+    #[test]
+    fn test_add_record_with_end_out_of_range() -> Result<(), Box<dyn std::error::Error>> {
+        let mut builder = Builder::new(MIN_SHIFT, DEPTH);
+
+        let start = Position::try_from(1 << 29)?;
+        let end = start;
+        let chunk = Chunk::new(
+            bgzf::VirtualPosition::from(0),
+            bgzf::VirtualPosition::from(9),
+        );
+
+        assert!(matches!(
+            builder.add_record(start, end, chunk),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_first_window() {
+        assert_eq!(first_window(DEPTH, 0), Some(0));
+        assert_eq!(first_window(DEPTH, 1), Some(0));
+        assert_eq!(first_window(DEPTH, 2), Some(4096));
+        assert_eq!(first_window(DEPTH, 73), Some(0));
+        assert_eq!(first_window(DEPTH, 74), Some(64));
+        assert_eq!(first_window(DEPTH, 4681), Some(0));
+        assert_eq!(first_window(DEPTH, 4682), Some(1));
+        assert_eq!(first_window(DEPTH, 37449), None);
+    }
+}
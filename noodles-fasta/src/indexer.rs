@@ -251,6 +251,17 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_index_record_with_description() -> Result<(), IndexError> {
+        let data = b">sq0 some description here\nACGT\n";
+        let mut indexer = Indexer::new(&data[..]);
+
+        let record = indexer.index_record()?;
+        assert_eq!(record, Some(Record::new(String::from("sq0"), 4, 27, 4, 5)));
+
+        Ok(())
+    }
+
     #[test]
     fn test_index_record_with_invalid_line_bases() {
         let data = b">sq0\nACGT\nACG\nACGT\nAC\n";
@@ -5,10 +5,11 @@ mod records;
 pub use self::records::Records;
 
 use std::{
-    io::{self, BufRead, Read, Seek, SeekFrom},
+    io::{self, BufRead, BufReader, Read, Seek, SeekFrom},
     ops::{Bound, Range, RangeBounds},
 };
 
+use flate2::read::MultiGzDecoder;
 use memchr::memchr;
 use noodles_bgzf as bgzf;
 use noodles_core::{region::Interval, Region};
@@ -136,6 +137,46 @@ where
     }
 }
 
+impl<'a> Reader<Box<dyn BufRead + 'a>> {
+    /// Creates a FASTA reader that detects whether the input is gzip-compressed.
+    ///
+    /// If the stream starts with a gzip header, it is transparently decompressed. BGZF streams
+    /// are decompressed using a BGZF reader. Otherwise, it is read as plain text.
+    ///
+    /// The underlying stream of the returned reader is not seekable, so this is only suitable for
+    /// sequential reading and not for indexing or querying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fasta as fasta;
+    ///
+    /// let data = b">sq0\nACGT\n";
+    /// let mut reader = fasta::Reader::from_reader(&data[..])?;
+    ///
+    /// let mut buf = Vec::new();
+    /// reader.read_definition(&mut String::new())?;
+    /// reader.read_sequence(&mut buf)?;
+    /// assert_eq!(buf, b"ACGT");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn from_reader<R>(reader: R) -> io::Result<Self>
+    where
+        R: Read + 'a,
+    {
+        let (compression, reader) = bgzf::detect_compression(reader)?;
+
+        let inner: Box<dyn BufRead + 'a> = match compression {
+            Some(bgzf::Compression::Bgzf) => Box::new(bgzf::Reader::new(reader)),
+            Some(bgzf::Compression::Gzip) => Box::new(BufReader::new(MultiGzDecoder::new(reader))),
+            None => Box::new(BufReader::new(reader)),
+        };
+
+        Ok(Self::new(inner))
+    }
+}
+
 impl<R> Reader<bgzf::Reader<R>>
 where
     R: Read,
@@ -393,6 +434,42 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_from_reader() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Write;
+
+        use flate2::{write::GzEncoder, Compression};
+
+        fn read_first_sequence(data: &[u8]) -> io::Result<Vec<u8>> {
+            let mut reader = Reader::from_reader(data)?;
+
+            let mut buf = String::new();
+            reader.read_definition(&mut buf)?;
+            assert_eq!(buf, ">sq0");
+
+            let mut sequence = Vec::new();
+            reader.read_sequence(&mut sequence)?;
+
+            Ok(sequence)
+        }
+
+        let data = b">sq0\nACGT\nNN\n>sq1\nNNNN\n";
+
+        let mut writer = GzEncoder::new(Vec::new(), Compression::default());
+        writer.write_all(data)?;
+        let gzip_data = writer.finish()?;
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(data)?;
+        let bgzf_data = writer.finish()?;
+
+        assert_eq!(read_first_sequence(data)?, b"ACGTNN");
+        assert_eq!(read_first_sequence(&gzip_data)?, b"ACGTNN");
+        assert_eq!(read_first_sequence(&bgzf_data)?, b"ACGTNN");
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_sequence() -> io::Result<()> {
         fn t(buf: &mut Vec<u8>, mut reader: &[u8], expected: &[u8]) -> io::Result<()> {
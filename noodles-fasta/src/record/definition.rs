@@ -164,6 +164,14 @@ mod tests {
             Ok(Definition::new("sq0", Some(String::from("LN:13"))))
         );
 
+        assert_eq!(
+            ">sq0 some description here".parse(),
+            Ok(Definition::new(
+                "sq0",
+                Some(String::from("some description here"))
+            ))
+        );
+
         assert_eq!("".parse::<Definition>(), Err(ParseError::Empty));
         assert_eq!("sq0".parse::<Definition>(), Err(ParseError::MissingPrefix));
         assert_eq!(">".parse::<Definition>(), Err(ParseError::MissingName));
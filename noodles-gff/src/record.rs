@@ -1,6 +1,7 @@
 //! GFF record and fields.
 
 pub mod attributes;
+pub mod bed;
 mod builder;
 mod field;
 mod phase;
@@ -375,6 +376,26 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_from_str_with_encoded_attributes() -> Result<(), Box<dyn std::error::Error>> {
+        let s = "sq0\tNOODLES\tgene\t8\t13\t5.5\t-\t.\tID=gene0;Name=gene%3B0;Note=a%2Cb%3Dc;";
+        let record = s.parse::<Record>()?;
+
+        assert_eq!(record.score(), Some(5.5));
+        assert_eq!(record.strand(), Strand::Reverse);
+
+        assert_eq!(
+            record.attributes(),
+            &Attributes::from(vec![
+                attributes::Entry::new("ID", "gene0"),
+                attributes::Entry::new("Name", "gene;0"),
+                attributes::Entry::new("Note", "a,b=c"),
+            ])
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_str_with_cds_feature_and_no_phase() {
         let s = "sq0\tNOODLES\tCDS\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0";
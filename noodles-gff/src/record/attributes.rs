@@ -65,7 +65,10 @@ impl FromStr for Attributes {
             return Ok(Self::default());
         }
 
-        s.split(DELIMITER)
+        // A trailing delimiter is commonly written but does not start a new entry.
+        s.strip_suffix(DELIMITER)
+            .unwrap_or(s)
+            .split(DELIMITER)
             .map(|t| t.parse())
             .collect::<Result<Vec<_>, _>>()
             .map(Self::from)
@@ -112,10 +115,23 @@ mod tests {
         )]);
         assert_eq!(actual, expected);
 
+        let s = "gene_id=ndls0;gene_name=gene0;";
+        let actual = s.parse::<Attributes>()?;
+        let expected = Attributes::from(vec![
+            Entry::new("gene_id", "ndls0"),
+            Entry::new("gene_name", "gene0"),
+        ]);
+        assert_eq!(actual, expected);
+
         let actual = "".parse::<Attributes>()?;
         let expected = Attributes::default();
         assert_eq!(actual, expected);
 
+        assert!(matches!(
+            "gene_id=ndls0;;".parse::<Attributes>(),
+            Err(ParseError::InvalidEntry(entry::ParseError::Empty))
+        ));
+
         Ok(())
     }
 }
@@ -0,0 +1,384 @@
+//! GFF record conversion from BED records.
+
+use std::{error, fmt, num};
+
+use noodles_bed as bed;
+use noodles_core::Position;
+
+use super::{attributes::Entry, Attributes, Record, Strand};
+
+const REGION_TYPE: &str = "region";
+const EXON_TYPE: &str = "exon";
+const ID_KEY: &str = "ID";
+const PARENT_KEY: &str = "Parent";
+const BLOCK_FIELDS_COUNT: usize = 6;
+const BLOCK_DELIMITER: char = ',';
+
+mod sealed {
+    use noodles_bed as bed;
+
+    pub trait Sealed {}
+
+    impl Sealed for bed::Record<3> {}
+    impl Sealed for bed::Record<4> {}
+    impl Sealed for bed::Record<5> {}
+    impl Sealed for bed::Record<6> {}
+}
+
+/// The optional standard fields of a BED record.
+///
+/// This is implemented for BED3 to BED6 records. Fields that are not in the record are `None`.
+pub trait BedRecord: sealed::Sealed {
+    /// Returns the feature name (`name`).
+    fn name(&self) -> Option<&bed::record::Name>;
+
+    /// Returns the score (`score`).
+    fn score(&self) -> Option<bed::record::Score>;
+
+    /// Returns the feature strand (`strand`).
+    fn strand(&self) -> Option<bed::record::Strand>;
+}
+
+impl BedRecord for bed::Record<3> {
+    fn name(&self) -> Option<&bed::record::Name> {
+        None
+    }
+
+    fn score(&self) -> Option<bed::record::Score> {
+        None
+    }
+
+    fn strand(&self) -> Option<bed::record::Strand> {
+        None
+    }
+}
+
+impl BedRecord for bed::Record<4> {
+    fn name(&self) -> Option<&bed::record::Name> {
+        bed::Record::<4>::name(self)
+    }
+
+    fn score(&self) -> Option<bed::record::Score> {
+        None
+    }
+
+    fn strand(&self) -> Option<bed::record::Strand> {
+        None
+    }
+}
+
+impl BedRecord for bed::Record<5> {
+    fn name(&self) -> Option<&bed::record::Name> {
+        bed::Record::<5>::name(self)
+    }
+
+    fn score(&self) -> Option<bed::record::Score> {
+        bed::Record::<5>::score(self)
+    }
+
+    fn strand(&self) -> Option<bed::record::Strand> {
+        None
+    }
+}
+
+impl BedRecord for bed::Record<6> {
+    fn name(&self) -> Option<&bed::record::Name> {
+        bed::Record::<6>::name(self)
+    }
+
+    fn score(&self) -> Option<bed::record::Score> {
+        bed::Record::<6>::score(self)
+    }
+
+    fn strand(&self) -> Option<bed::record::Strand> {
+        bed::Record::<6>::strand(self)
+    }
+}
+
+/// An error returned when a BED record fails to convert to GFF records.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FromBedError {
+    /// The block count (`blockCount`) is invalid.
+    InvalidBlockCount(num::ParseIntError),
+    /// A block size (`blockSizes`) is invalid.
+    InvalidBlockSize(num::ParseIntError),
+    /// A block start (`blockStarts`) is invalid.
+    InvalidBlockStart(num::ParseIntError),
+    /// The number of block sizes or block starts does not match the block count.
+    BlockCountMismatch,
+    /// A block is empty or its position is invalid.
+    InvalidBlock,
+}
+
+impl error::Error for FromBedError {}
+
+impl fmt::Display for FromBedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidBlockCount(e) => write!(f, "invalid block count: {}", e),
+            Self::InvalidBlockSize(e) => write!(f, "invalid block size: {}", e),
+            Self::InvalidBlockStart(e) => write!(f, "invalid block start: {}", e),
+            Self::BlockCountMismatch => f.write_str("block count mismatch"),
+            Self::InvalidBlock => f.write_str("invalid block"),
+        }
+    }
+}
+
+impl Record {
+    /// Converts a BED record to GFF records.
+    ///
+    /// The first record is the feature itself. BED start positions are converted to 1-based
+    /// positions when parsed, so the start and end are used as is. Its feature type is `region`,
+    /// and the name is set as the `ID` attribute.
+    ///
+    /// If the record is a BED6 record with BED12 block fields (`blockCount`, `blockSizes`, and
+    /// `blockStarts`), which are read from the optional fields of the record, each block follows
+    /// as an `exon` record. The `Parent` attribute of each exon links it to the region. If the
+    /// record does not have a name, the region ID is generated from its position, e.g.,
+    /// `sq0:8-20`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed as bed;
+    /// use noodles_gff as gff;
+    ///
+    /// let bed_record: bed::Record<3> = "sq0\t7\t13".parse()?;
+    /// let records = gff::Record::from_bed(&bed_record)?;
+    /// assert_eq!(records[0].to_string(), "sq0\t.\tregion\t8\t13\t.\t.\t.\t.");
+    ///
+    /// let bed_record: bed::Record<6> = "sq0\t7\t13\tndls0\t21\t+".parse()?;
+    /// let records = gff::Record::from_bed(&bed_record)?;
+    ///
+    /// assert_eq!(records.len(), 1);
+    /// assert_eq!(records[0].to_string(), "sq0\t.\tregion\t8\t13\t21\t+\t.\tID=ndls0");
+    ///
+    /// let bed_record: bed::Record<6> =
+    ///     "sq0\t7\t20\tndls0\t0\t-\t7\t20\t0\t2\t3,4,\t0,9,".parse()?;
+    /// let records = gff::Record::from_bed(&bed_record)?;
+    ///
+    /// assert_eq!(records.len(), 3);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_bed<const N: u8>(record: &bed::Record<N>) -> Result<Vec<Self>, FromBedError>
+    where
+        bed::Record<N>: bed::record::BedN<3> + BedRecord,
+    {
+        let strand = convert_strand(BedRecord::strand(record));
+        let blocks = parse_blocks(record)?;
+
+        let mut builder = Self::builder()
+            .set_reference_sequence_name(record.reference_sequence_name().into())
+            .set_type(REGION_TYPE.into())
+            .set_start(record.start_position())
+            .set_end(record.end_position())
+            .set_strand(strand);
+
+        if let Some(score) = BedRecord::score(record) {
+            builder = builder.set_score(f32::from(u16::from(score)));
+        }
+
+        let id = match BedRecord::name(record) {
+            Some(name) => Some(name.to_string()),
+            None if !blocks.is_empty() => Some(format!(
+                "{}:{}-{}",
+                record.reference_sequence_name(),
+                record.start_position(),
+                record.end_position()
+            )),
+            None => None,
+        };
+
+        if let Some(id) = id.as_ref() {
+            builder = builder.set_attributes(Attributes::from(vec![Entry::new(ID_KEY, id)]));
+        }
+
+        let mut records = Vec::with_capacity(blocks.len() + 1);
+        records.push(builder.build());
+
+        if let Some(id) = id {
+            let attributes = Attributes::from(vec![Entry::new(PARENT_KEY, id)]);
+
+            for (start, end) in blocks {
+                let exon = Self::builder()
+                    .set_reference_sequence_name(record.reference_sequence_name().into())
+                    .set_type(EXON_TYPE.into())
+                    .set_start(start)
+                    .set_end(end)
+                    .set_strand(strand)
+                    .set_attributes(attributes.clone())
+                    .build();
+
+                records.push(exon);
+            }
+        }
+
+        Ok(records)
+    }
+}
+
+// Only BED6 records can have the block fields in their optional fields. For BED3 to BED5
+// records, the optional fields start with the missing standard fields.
+fn parse_blocks<const N: u8>(
+    record: &bed::Record<N>,
+) -> Result<Vec<(Position, Position)>, FromBedError>
+where
+    bed::Record<N>: bed::record::BedN<3>,
+{
+    let optional_fields = record.optional_fields();
+
+    if N < 6 || optional_fields.len() < BLOCK_FIELDS_COUNT {
+        return Ok(Vec::new());
+    }
+
+    let block_count: usize = optional_fields[3]
+        .parse()
+        .map_err(FromBedError::InvalidBlockCount)?;
+    let block_sizes = parse_block_values(&optional_fields[4], FromBedError::InvalidBlockSize)?;
+    let block_starts = parse_block_values(&optional_fields[5], FromBedError::InvalidBlockStart)?;
+
+    if block_sizes.len() != block_count || block_starts.len() != block_count {
+        return Err(FromBedError::BlockCountMismatch);
+    }
+
+    block_starts
+        .into_iter()
+        .zip(block_sizes)
+        .map(|(block_start, block_size)| {
+            resolve_block(record.start_position(), block_start, block_size)
+        })
+        .collect()
+}
+
+fn convert_strand(strand: Option<bed::record::Strand>) -> Strand {
+    match strand {
+        Some(bed::record::Strand::Forward) => Strand::Forward,
+        Some(bed::record::Strand::Reverse) => Strand::Reverse,
+        None => Strand::None,
+    }
+}
+
+fn parse_block_values(
+    s: &str,
+    f: fn(num::ParseIntError) -> FromBedError,
+) -> Result<Vec<usize>, FromBedError> {
+    s.split(BLOCK_DELIMITER)
+        .filter(|t| !t.is_empty())
+        .map(|t| t.parse().map_err(f))
+        .collect()
+}
+
+// Block starts are relative to the 0-based chromStart, i.e., the 1-based start position.
+fn resolve_block(
+    start_position: Position,
+    block_start: usize,
+    block_size: usize,
+) -> Result<(Position, Position), FromBedError> {
+    let start = usize::from(start_position)
+        .checked_add(block_start)
+        .and_then(Position::new)
+        .ok_or(FromBedError::InvalidBlock)?;
+
+    let end = block_size
+        .checked_sub(1)
+        .and_then(|len| usize::from(start).checked_add(len))
+        .and_then(Position::new)
+        .ok_or(FromBedError::InvalidBlock)?;
+
+    Ok((start, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_bed() -> Result<(), Box<dyn std::error::Error>> {
+        let bed_record: bed::Record<3> = "sq0\t7\t13".parse()?;
+        let actual = Record::from_bed(&bed_record)?;
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0].to_string(), "sq0\t.\tregion\t8\t13\t.\t.\t.\t.");
+
+        let bed_record: bed::Record<5> = "sq0\t7\t13\tndls0\t21".parse()?;
+        let actual = Record::from_bed(&bed_record)?;
+        assert_eq!(actual.len(), 1);
+        assert_eq!(
+            actual[0].to_string(),
+            "sq0\t.\tregion\t8\t13\t21\t.\t.\tID=ndls0"
+        );
+
+        let bed_record: bed::Record<6> = "sq0\t7\t13\t.\t0\t.".parse()?;
+        let actual = Record::from_bed(&bed_record)?;
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0].to_string(), "sq0\t.\tregion\t8\t13\t.\t.\t.\t.");
+
+        let bed_record: bed::Record<6> = "sq0\t7\t13\tndls0\t21\t-".parse()?;
+        let actual = Record::from_bed(&bed_record)?;
+
+        let expected = [Record::builder()
+            .set_reference_sequence_name(String::from("sq0"))
+            .set_type(String::from("region"))
+            .set_start(Position::try_from(8)?)
+            .set_end(Position::try_from(13)?)
+            .set_score(21.0)
+            .set_strand(Strand::Reverse)
+            .set_attributes(Attributes::from(vec![Entry::new("ID", "ndls0")]))
+            .build()];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bed_with_blocks() -> Result<(), Box<dyn std::error::Error>> {
+        let bed_record: bed::Record<6> =
+            "sq0\t7\t20\tndls0\t0\t-\t7\t20\t0\t2\t3,4,\t0,9,".parse()?;
+
+        let actual: Vec<_> = Record::from_bed(&bed_record)?
+            .iter()
+            .map(|record| record.to_string())
+            .collect();
+
+        let expected = [
+            "sq0\t.\tregion\t8\t20\t.\t-\t.\tID=ndls0",
+            "sq0\t.\texon\t8\t10\t.\t-\t.\tParent=ndls0",
+            "sq0\t.\texon\t17\t20\t.\t-\t.\tParent=ndls0",
+        ];
+
+        assert_eq!(actual, expected);
+
+        let bed_record: bed::Record<4> =
+            "sq0\t7\t20\tndls0\t0\t-\t7\t20\t0\t2\t3,4,\t0,9,".parse()?;
+        assert_eq!(Record::from_bed(&bed_record)?.len(), 1);
+
+        let bed_record: bed::Record<6> =
+            "sq0\t7\t20\tndls0\t0\t-\t7\t20\t0\t2\t3,\t0,9,".parse()?;
+        assert_eq!(
+            Record::from_bed(&bed_record),
+            Err(FromBedError::BlockCountMismatch)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bed_with_blocks_and_no_name() -> Result<(), Box<dyn std::error::Error>> {
+        let bed_record: bed::Record<6> = "sq0\t7\t20\t.\t0\t+\t7\t20\t0\t2\t3,4,\t0,9,".parse()?;
+
+        let actual: Vec<_> = Record::from_bed(&bed_record)?
+            .iter()
+            .map(|record| record.to_string())
+            .collect();
+
+        let expected = [
+            "sq0\t.\tregion\t8\t20\t.\t+\t.\tID=sq0:8-20",
+            "sq0\t.\texon\t8\t10\t.\t+\t.\tParent=sq0:8-20",
+            "sq0\t.\texon\t17\t20\t.\t+\t.\tParent=sq0:8-20",
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
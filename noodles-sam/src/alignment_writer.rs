@@ -7,6 +7,8 @@ use super::{AlignmentRecord, Header};
 /// A call to [`finish`] must be made before the writer is dropped.
 pub trait AlignmentWriter {
     /// Writes a SAM header.
+    ///
+    /// This must be called before writing any alignment records.
     fn write_alignment_header(&mut self, header: &Header) -> io::Result<()>;
 
     /// Writes an alignment record.
@@ -61,6 +61,7 @@
 //! ```
 
 mod builder;
+pub mod compatibility;
 #[allow(clippy::module_inception)]
 pub mod header;
 mod parser;
@@ -68,6 +69,7 @@ pub mod program;
 pub mod read_group;
 pub mod record;
 pub mod reference_sequence;
+pub mod validation;
 
 use std::{fmt, str::FromStr};
 
@@ -100,6 +102,7 @@ pub struct Header {
     read_groups: ReadGroups,
     programs: Programs,
     comments: Vec<String>,
+    unknown_records: Vec<String>,
 }
 
 impl Header {
@@ -135,6 +138,25 @@ impl Header {
         self.header.as_ref()
     }
 
+    /// Returns the format version (`@HD VN`) if the header header is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, header::{self, header::Version}};
+    ///
+    /// let header = sam::Header::default();
+    /// assert!(header.version().is_none());
+    ///
+    /// let header = sam::Header::builder()
+    ///     .set_header(header::header::Header::new(Version::new(1, 6)))
+    ///     .build();
+    /// assert_eq!(header.version(), Some(Version::new(1, 6)));
+    /// ```
+    pub fn version(&self) -> Option<header::Version> {
+        self.header().map(|header| header.version())
+    }
+
     /// Returns a mutable reference to the SAM header header if it is set.
     ///
     /// # Examples
@@ -348,6 +370,40 @@ impl Header {
         self.comments.push(comment.into());
     }
 
+    /// Returns the raw lines of header records with an unknown type.
+    ///
+    /// These are only retained when parsing leniently (see [`Self::from_str_with_strictness`]).
+    /// Each line includes its record type prefix, e.g., `@XY`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    ///
+    /// let header = sam::Header::from_str_with_strictness("@XY\tKEY:val\n", false)?;
+    /// assert_eq!(header.unknown_records(), ["@XY\tKEY:val"]);
+    /// # Ok::<_, sam::header::ParseError>(())
+    /// ```
+    pub fn unknown_records(&self) -> &[String] {
+        &self.unknown_records
+    }
+
+    /// Returns a mutable reference to the raw lines of header records with an unknown type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    ///
+    /// let mut header = sam::Header::default();
+    /// header.unknown_records_mut().push(String::from("@XY\tKEY:val"));
+    ///
+    /// assert_eq!(header.to_string(), "@XY\tKEY:val\n");
+    /// ```
+    pub fn unknown_records_mut(&mut self) -> &mut Vec<String> {
+        &mut self.unknown_records
+    }
+
     /// Returns whether there are no records in this SAM header.
     ///
     /// # Examples
@@ -367,6 +423,68 @@ impl Header {
             && self.read_groups.is_empty()
             && self.programs.is_empty()
             && self.comments.is_empty()
+            && self.unknown_records.is_empty()
+    }
+
+    /// Parses a raw SAM header with the given strictness.
+    ///
+    /// If `strict` is `true`, this is the same as parsing with [`str::parse`], and a record with
+    /// an unknown type (e.g., `@XY`) is an error. Otherwise, the raw lines of records with an
+    /// unknown type are retained as is (see [`Self::unknown_records`]) and are written after the
+    /// program records when the header is formatted.
+    ///
+    /// In lenient mode, a reference sequence MD5 checksum (`M5`) that is not 32 hex digits is also
+    /// kept as a raw field (see [`ReferenceSequence::fields`]) rather than being an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    ///
+    /// let s = "@HD\tVN:1.6\n@XY\tKEY:val\n";
+    ///
+    /// assert!(sam::Header::from_str_with_strictness(s, true).is_err());
+    ///
+    /// let header = sam::Header::from_str_with_strictness(s, false)?;
+    /// assert_eq!(header.to_string(), s);
+    /// # Ok::<_, sam::header::ParseError>(())
+    /// ```
+    pub fn from_str_with_strictness(s: &str, strict: bool) -> Result<Self, ParseError> {
+        parser::parse_with_strictness(s, strict)
+    }
+
+    /// Validates a SAM record against the header.
+    ///
+    /// This checks that the reference sequence names (`RNAME` and `RNEXT`) are in the reference
+    /// sequence dictionary, the position (`POS`) is within the reference sequence, and the read
+    /// group (`RG:Z`) and program (`PG:Z`) are declared in the header. It also checks that the
+    /// CIGAR operations are consistent with the sequence (see [`validation::validate_cigar`]).
+    /// The first failing check is returned as an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, header::{validation::ValidationError, ReferenceSequence}};
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+    ///     .build();
+    ///
+    /// let record: sam::Record = "r0\t0\tsq0\t1\t60\t1M\t*\t0\t0\tA\t*".parse()?;
+    /// assert!(header.validate_record(&record).is_ok());
+    ///
+    /// let record: sam::Record = "r0\t0\tsq1\t1\t60\t1M\t*\t0\t0\tA\t*".parse()?;
+    /// assert_eq!(
+    ///     header.validate_record(&record),
+    ///     Err(ValidationError::MissingReferenceSequence("sq1".parse()?))
+    /// );
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn validate_record(
+        &self,
+        record: &crate::Record,
+    ) -> Result<(), validation::ValidationError> {
+        validation::validate_record(self, record)
     }
 
     /// Removes all records from the header.
@@ -388,6 +506,7 @@ impl Header {
         self.read_groups.clear();
         self.programs.clear();
         self.comments.clear();
+        self.unknown_records.clear();
     }
 }
 
@@ -431,6 +550,10 @@ impl fmt::Display for Header {
             writeln!(f, "{}", program)?;
         }
 
+        for line in &self.unknown_records {
+            writeln!(f, "{}", line)?;
+        }
+
         for comment in &self.comments {
             writeln!(f, "{}\t{}", record::Kind::Comment, comment)?;
         }
@@ -10,6 +10,7 @@ pub struct Builder {
     read_groups: ReadGroups,
     programs: Programs,
     comments: Vec<String>,
+    unknown_records: Vec<String>,
 }
 
 impl Builder {
@@ -156,6 +157,25 @@ impl Builder {
         self
     }
 
+    /// Adds the raw line of a header record with an unknown type.
+    ///
+    /// The line is written as is when the header is formatted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// let header = sam::Header::builder().add_unknown_record("@XY\tKEY:val").build();
+    /// assert_eq!(header.unknown_records(), ["@XY\tKEY:val"]);
+    /// ```
+    pub fn add_unknown_record<S>(mut self, line: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.unknown_records.push(line.into());
+        self
+    }
+
     /// Builds a SAM header.
     ///
     /// # Example
@@ -172,6 +192,7 @@ impl Builder {
             read_groups: self.read_groups,
             programs: self.programs,
             comments: self.comments,
+            unknown_records: self.unknown_records,
         }
     }
 }
@@ -189,6 +210,7 @@ mod tests {
         assert!(header.read_groups.is_empty());
         assert!(header.programs.is_empty());
         assert!(header.comments.is_empty());
+        assert!(header.unknown_records.is_empty());
     }
 
     #[test]
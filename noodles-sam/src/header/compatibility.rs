@@ -0,0 +1,176 @@
+//! SAM header format version compatibility.
+
+use std::{error, fmt};
+
+use super::{header::Version, Header};
+
+/// A SAM header feature introduced in a later format version.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Feature {
+    /// Header subsort order (`@HD SS`).
+    SubsortOrder,
+    /// Reference sequence description (`@SQ DS`).
+    ReferenceSequenceDescription,
+    /// Reference sequence molecule topology (`@SQ TP`).
+    MoleculeTopology,
+}
+
+impl Feature {
+    /// Returns the format version in which the feature was introduced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::{compatibility::Feature, header::Version};
+    /// assert_eq!(Feature::SubsortOrder.min_version(), Version::new(1, 6));
+    /// ```
+    pub fn min_version(self) -> Version {
+        match self {
+            Self::SubsortOrder | Self::ReferenceSequenceDescription | Self::MoleculeTopology => {
+                Version::new(1, 6)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SubsortOrder => f.write_str("@HD SS"),
+            Self::ReferenceSequenceDescription => f.write_str("@SQ DS"),
+            Self::MoleculeTopology => f.write_str("@SQ TP"),
+        }
+    }
+}
+
+/// A feature that is not supported by the header format version.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Incompatibility {
+    version: Version,
+    feature: Feature,
+}
+
+impl Incompatibility {
+    /// Returns the header format version.
+    pub fn version(&self) -> Version {
+        self.version
+    }
+
+    /// Returns the unsupported feature.
+    pub fn feature(&self) -> Feature {
+        self.feature
+    }
+}
+
+impl error::Error for Incompatibility {}
+
+impl fmt::Display for Incompatibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} requires VN >= {}, got VN:{}",
+            self.feature,
+            self.feature.min_version(),
+            self.version
+        )
+    }
+}
+
+/// Checks that the features used in a SAM header are supported by its format version (`@HD VN`).
+///
+/// Headers without a header record (`@HD`) have no format version and are not checked.
+///
+/// If `strict` is `true`, the first incompatibility is returned as an error. Otherwise, all
+/// incompatibilities are returned as warnings.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::{self as sam, header::compatibility};
+///
+/// let header: sam::Header = "@HD\tVN:1.3\n@SQ\tSN:sq0\tLN:8\tTP:linear\n".parse()?;
+///
+/// let warnings = compatibility::check(&header, false)?;
+/// assert_eq!(warnings.len(), 1);
+///
+/// assert!(compatibility::check(&header, true).is_err());
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn check(header: &Header, strict: bool) -> Result<Vec<Incompatibility>, Incompatibility> {
+    let version = match header.version() {
+        Some(version) => version,
+        None => return Ok(Vec::new()),
+    };
+
+    let mut incompatibilities = Vec::new();
+
+    for feature in features(header) {
+        if version < feature.min_version() {
+            let incompatibility = Incompatibility { version, feature };
+
+            if strict {
+                return Err(incompatibility);
+            }
+
+            incompatibilities.push(incompatibility);
+        }
+    }
+
+    Ok(incompatibilities)
+}
+
+fn features(header: &Header) -> Vec<Feature> {
+    let mut features = Vec::new();
+
+    if let Some(hd) = header.header() {
+        if hd.subsort_order().is_some() {
+            features.push(Feature::SubsortOrder);
+        }
+    }
+
+    for reference_sequence in header.reference_sequences().values() {
+        if reference_sequence.description().is_some() {
+            features.push(Feature::ReferenceSequenceDescription);
+        }
+
+        if reference_sequence.molecule_topology().is_some() {
+            features.push(Feature::MoleculeTopology);
+        }
+    }
+
+    features
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() -> Result<(), Box<dyn std::error::Error>> {
+        let header: Header = "@HD\tVN:1.3\n@SQ\tSN:sq0\tLN:8\tTP:linear\n".parse()?;
+
+        assert_eq!(
+            check(&header, false),
+            Ok(vec![Incompatibility {
+                version: Version::new(1, 3),
+                feature: Feature::MoleculeTopology,
+            }])
+        );
+
+        assert_eq!(
+            check(&header, true),
+            Err(Incompatibility {
+                version: Version::new(1, 3),
+                feature: Feature::MoleculeTopology,
+            })
+        );
+
+        let header: Header = "@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\tTP:linear\n".parse()?;
+        assert_eq!(check(&header, true), Ok(Vec::new()));
+
+        let header: Header = "@SQ\tSN:sq0\tLN:8\tTP:linear\n".parse()?;
+        assert_eq!(check(&header, true), Ok(Vec::new()));
+
+        Ok(())
+    }
+}
@@ -4,11 +4,11 @@ use super::{
     header,
     program::{self, Program},
     read_group::{self, ReadGroup},
-    record,
-    reference_sequence::{self, ReferenceSequence},
-    Header, Record,
+    record, reference_sequence, Header, Record,
 };
 
+const PREFIX: char = '@';
+
 /// An error returned when a raw SAM header fails to parse.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ParseError {
@@ -78,6 +78,13 @@ impl fmt::Display for ParseError {
 /// # Ok::<(), sam::header::ParseError>(())
 /// ```
 pub(super) fn parse(s: &str) -> Result<Header, ParseError> {
+    parse_with_strictness(s, true)
+}
+
+// Records with an unknown type and reference sequence records with an invalid MD5 checksum are
+// errors when `strict` is `true`. Otherwise, the raw lines of unknown records and the raw MD5
+// checksums are retained.
+pub(super) fn parse_with_strictness(s: &str, strict: bool) -> Result<Header, ParseError> {
     let mut builder = Header::builder();
 
     let mut read_group_ids: HashSet<String> = HashSet::new();
@@ -85,7 +92,16 @@ pub(super) fn parse(s: &str) -> Result<Header, ParseError> {
     let mut program_ids: HashSet<String> = HashSet::new();
 
     for (i, line) in s.lines().enumerate() {
-        let record: Record = line.parse().map_err(ParseError::InvalidRecord)?;
+        let record: Record = match line.parse() {
+            Ok(record) => record,
+            Err(record::ParseError::InvalidKind(record::kind::ParseError::Invalid))
+                if !strict && line.starts_with(PREFIX) =>
+            {
+                builder = builder.add_unknown_record(line);
+                continue;
+            }
+            Err(e) => return Err(ParseError::InvalidRecord(e)),
+        };
 
         builder = match record.kind() {
             record::Kind::Header => {
@@ -98,8 +114,9 @@ pub(super) fn parse(s: &str) -> Result<Header, ParseError> {
                 }
             }
             record::Kind::ReferenceSequence => {
-                let reference_sequence = ReferenceSequence::try_from(record)
-                    .map_err(ParseError::InvalidReferenceSequence)?;
+                let reference_sequence =
+                    reference_sequence::try_from_record_with_strictness(record, strict)
+                        .map_err(ParseError::InvalidReferenceSequence)?;
 
                 if !reference_sequence_names.insert(reference_sequence.name().clone()) {
                     return Err(ParseError::DuplicateReferenceSequenceName(
@@ -188,6 +205,25 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_with_strictness_with_unknown_record() -> Result<(), ParseError> {
+        let s = "@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\n@XY\tKEY:val\n";
+
+        assert!(matches!(
+            parse_with_strictness(s, true),
+            Err(ParseError::InvalidRecord(record::ParseError::InvalidKind(
+                _
+            )))
+        ));
+
+        let header = parse_with_strictness(s, false)?;
+        assert_eq!(header.reference_sequences().len(), 1);
+        assert_eq!(header.unknown_records(), ["@XY\tKEY:val"]);
+        assert_eq!(header.to_string(), s);
+
+        Ok(())
+    }
+
     #[test]
     fn test_parse_with_multiple_hd() {
         let s = "\
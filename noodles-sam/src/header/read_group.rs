@@ -17,7 +17,7 @@ use super::{
 ///
 /// A read group typically defines the set of reads that came from the same run on a sequencing
 /// instrument. The read group ID is guaranteed to be set.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq)]
 pub struct ReadGroup {
     id: String,
     barcode: Option<String>,
@@ -34,6 +34,8 @@ pub struct ReadGroup {
     platform_unit: Option<String>,
     sample: Option<String>,
     fields: HashMap<Tag, String>,
+    // The tag order of a parsed read group, which is used when it is formatted.
+    order: Vec<Tag>,
 }
 
 impl ReadGroup {
@@ -78,6 +80,7 @@ impl ReadGroup {
             platform_unit: None,
             sample: None,
             fields: HashMap::new(),
+            order: Vec::new(),
         }
     }
 
@@ -309,72 +312,102 @@ impl ReadGroup {
     pub fn fields(&self) -> &HashMap<Tag, String> {
         &self.fields
     }
+
+    fn fmt_field(&self, f: &mut fmt::Formatter<'_>, tag: Tag) -> fmt::Result {
+        match tag {
+            Tag::Id => write!(f, "\t{}:{}", tag, self.id),
+            Tag::PredictedMedianInsertSize => match self.predicted_median_insert_size() {
+                Some(predicted_median_insert_size) => {
+                    write!(f, "\t{}:{}", tag, predicted_median_insert_size)
+                }
+                None => Ok(()),
+            },
+            Tag::Platform => match self.platform() {
+                Some(platform) => write!(f, "\t{}:{}", tag, platform),
+                None => Ok(()),
+            },
+            _ => {
+                let value = match tag {
+                    Tag::Barcode => self.barcode(),
+                    Tag::SequencingCenter => self.sequencing_center(),
+                    Tag::Description => self.description(),
+                    Tag::ProducedAt => self.produced_at(),
+                    Tag::FlowOrder => self.flow_order(),
+                    Tag::KeySequence => self.key_sequence(),
+                    Tag::Library => self.library(),
+                    Tag::Program => self.program(),
+                    Tag::PlatformModel => self.platform_model(),
+                    Tag::PlatformUnit => self.platform_unit(),
+                    Tag::Sample => self.sample(),
+                    _ => self.fields.get(&tag).map(|value| value.as_str()),
+                };
+
+                match value {
+                    Some(value) => write!(f, "\t{}:{}", tag, value),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+// The order of fields that are not in the parsed tag order.
+static STANDARD_TAGS: [Tag; 14] = [
+    Tag::Id,
+    Tag::Barcode,
+    Tag::SequencingCenter,
+    Tag::Description,
+    Tag::ProducedAt,
+    Tag::FlowOrder,
+    Tag::KeySequence,
+    Tag::Library,
+    Tag::Program,
+    Tag::PredictedMedianInsertSize,
+    Tag::Platform,
+    Tag::PlatformModel,
+    Tag::PlatformUnit,
+    Tag::Sample,
+];
+
+// The parsed tag order is not part of the read group value.
+impl PartialEq for ReadGroup {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.barcode == other.barcode
+            && self.sequencing_center == other.sequencing_center
+            && self.description == other.description
+            && self.produced_at == other.produced_at
+            && self.flow_order == other.flow_order
+            && self.key_sequence == other.key_sequence
+            && self.library == other.library
+            && self.program == other.program
+            && self.predicted_median_insert_size == other.predicted_median_insert_size
+            && self.platform == other.platform
+            && self.platform_model == other.platform_model
+            && self.platform_unit == other.platform_unit
+            && self.sample == other.sample
+            && self.fields == other.fields
+    }
 }
 
 impl fmt::Display for ReadGroup {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", record::Kind::ReadGroup)?;
-        write!(f, "\t{}:{}", Tag::Id, self.id)?;
-
-        if let Some(barcode) = self.barcode() {
-            write!(f, "\t{}:{}", Tag::Barcode, barcode)?;
-        }
 
-        if let Some(sequencing_center) = self.sequencing_center() {
-            write!(f, "\t{}:{}", Tag::SequencingCenter, sequencing_center)?;
+        for &tag in &self.order {
+            self.fmt_field(f, tag)?;
         }
 
-        if let Some(description) = self.description() {
-            write!(f, "\t{}:{}", Tag::Description, description)?;
-        }
-
-        if let Some(produced_at) = self.produced_at() {
-            write!(f, "\t{}:{}", Tag::ProducedAt, produced_at)?;
-        }
-
-        if let Some(flow_order) = self.flow_order() {
-            write!(f, "\t{}:{}", Tag::FlowOrder, flow_order)?;
-        }
-
-        if let Some(key_sequence) = self.key_sequence() {
-            write!(f, "\t{}:{}", Tag::KeySequence, key_sequence)?;
-        }
-
-        if let Some(library) = self.library() {
-            write!(f, "\t{}:{}", Tag::Library, library)?;
-        }
-
-        if let Some(program) = self.program() {
-            write!(f, "\t{}:{}", Tag::Program, program)?;
-        }
-
-        if let Some(predicted_median_insert_size) = self.predicted_median_insert_size() {
-            write!(
-                f,
-                "\t{}:{}",
-                Tag::PredictedMedianInsertSize,
-                predicted_median_insert_size
-            )?;
-        }
-
-        if let Some(platform) = self.platform() {
-            write!(f, "\t{}:{}", Tag::Platform, platform)?;
-        }
-
-        if let Some(platform_model) = self.platform_model() {
-            write!(f, "\t{}:{}", Tag::PlatformModel, platform_model)?;
-        }
-
-        if let Some(platform_unit) = self.platform_unit() {
-            write!(f, "\t{}:{}", Tag::PlatformUnit, platform_unit)?;
-        }
-
-        if let Some(sample) = self.sample() {
-            write!(f, "\t{}:{}", Tag::Sample, sample)?;
+        for &tag in &STANDARD_TAGS {
+            if !self.order.contains(&tag) {
+                self.fmt_field(f, tag)?;
+            }
         }
 
         for (tag, value) in &self.fields {
-            write!(f, "\t{}:{}", tag, value)?;
+            if !self.order.contains(tag) {
+                write!(f, "\t{}:{}", tag, value)?;
+            }
         }
 
         Ok(())
@@ -427,10 +460,15 @@ fn parse_map(raw_fields: Fields) -> Result<ReadGroup, TryFromRecordError> {
     use builder::BuildError;
 
     let mut builder = ReadGroup::builder();
+    let mut order = Vec::new();
 
     for (raw_tag, value) in raw_fields {
         let tag = raw_tag.parse().map_err(TryFromRecordError::InvalidTag)?;
 
+        if !order.contains(&tag) {
+            order.push(tag);
+        }
+
         builder = match tag {
             Tag::Id => builder.set_id(value),
             Tag::Barcode => builder.set_barcode(value),
@@ -460,7 +498,10 @@ fn parse_map(raw_fields: Fields) -> Result<ReadGroup, TryFromRecordError> {
     }
 
     match builder.build() {
-        Ok(rg) => Ok(rg),
+        Ok(mut rg) => {
+            rg.order = order;
+            Ok(rg)
+        }
         Err(BuildError::MissingId) => Err(TryFromRecordError::MissingRequiredTag(Tag::Id)),
     }
 }
@@ -481,6 +522,44 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_fmt_with_parsed_record() -> Result<(), Box<dyn std::error::Error>> {
+        let s = "@RG\tID:rg0\tBC:ACGT\tCN:ndls\tDS:noodles sample\tDT:2022-04-01\tFO:TACG\tKS:TCAG\tLB:lib0\tPG:noodles\tPI:300\tPL:ILLUMINA\tPM:model0\tPU:run0.lane1\tSM:sample0\tzn:noodles\tza:sam";
+
+        let record: Record = s.parse()?;
+        let read_group = ReadGroup::try_from(record)?;
+
+        assert_eq!(read_group.description(), Some("noodles sample"));
+        assert_eq!(read_group.produced_at(), Some("2022-04-01"));
+        assert_eq!(read_group.library(), Some("lib0"));
+        assert_eq!(read_group.platform_unit(), Some("run0.lane1"));
+        assert_eq!(read_group.sample(), Some("sample0"));
+
+        assert_eq!(read_group.to_string(), s);
+
+        let s = "@RG\tID:rg0\tSM:s0\tLB:l0";
+        let record: Record = s.parse()?;
+        let read_group = ReadGroup::try_from(record)?;
+        assert_eq!(read_group.to_string(), s);
+
+        let s = "@RG\tzn:noodles\tPL:ILLUMINA\tID:rg0\tza:sam\tPI:300";
+        let record: Record = s.parse()?;
+        let read_group = ReadGroup::try_from(record)?;
+        assert_eq!(read_group.to_string(), s);
+
+        let expected = ReadGroup::builder()
+            .set_id("rg0")
+            .set_platform(Platform::Illumina)
+            .set_predicted_median_insert_size(300)
+            .insert(Tag::Other([b'z', b'n']), "noodles")
+            .insert(Tag::Other([b'z', b'a']), "sam")
+            .build()?;
+
+        assert_eq!(read_group, expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_try_from_record_for_read_group_with_invalid_record() {
         let record = Record::new(
@@ -425,6 +425,7 @@ impl Builder {
             platform_unit: self.platform_unit,
             sample: self.sample,
             fields: self.fields,
+            order: Vec::new(),
         })
     }
 }
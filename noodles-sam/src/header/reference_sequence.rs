@@ -428,14 +428,23 @@ impl TryFrom<Record> for ReferenceSequence {
     type Error = TryFromRecordError;
 
     fn try_from(record: Record) -> Result<Self, Self::Error> {
-        match record.into() {
-            (record::Kind::ReferenceSequence, record::Value::Map(fields)) => parse_map(fields),
-            _ => Err(TryFromRecordError::InvalidRecord),
-        }
+        try_from_record_with_strictness(record, true)
     }
 }
 
-fn parse_map(raw_fields: Fields) -> Result<ReferenceSequence, TryFromRecordError> {
+// If `strict` is `false`, an invalid MD5 checksum (`M5`) is kept as a raw field rather than
+// returned as an error.
+pub(super) fn try_from_record_with_strictness(
+    record: Record,
+    strict: bool,
+) -> Result<ReferenceSequence, TryFromRecordError> {
+    match record.into() {
+        (record::Kind::ReferenceSequence, record::Value::Map(fields)) => parse_map(fields, strict),
+        _ => Err(TryFromRecordError::InvalidRecord),
+    }
+}
+
+fn parse_map(raw_fields: Fields, strict: bool) -> Result<ReferenceSequence, TryFromRecordError> {
     use builder::BuildError;
 
     let mut builder = ReferenceSequence::builder();
@@ -469,12 +478,11 @@ fn parse_map(raw_fields: Fields) -> Result<ReferenceSequence, TryFromRecordError
             }
             Tag::AssemblyId => builder.set_assembly_id(value),
             Tag::Description => builder.set_description(value),
-            Tag::Md5Checksum => {
-                let md5_checksum = value
-                    .parse()
-                    .map_err(TryFromRecordError::InvalidMd5Checksum)?;
-                builder.set_md5_checksum(md5_checksum)
-            }
+            Tag::Md5Checksum => match value.parse() {
+                Ok(md5_checksum) => builder.set_md5_checksum(md5_checksum),
+                Err(_) if !strict => builder.insert(tag, value),
+                Err(e) => return Err(TryFromRecordError::InvalidMd5Checksum(e)),
+            },
             Tag::Species => builder.set_species(value),
             Tag::MoleculeTopology => {
                 let molecule_topology = value
@@ -636,4 +644,64 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_try_from_record_for_reference_sequence_with_md5_checksum_and_alternative_names(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let record = Record::new(
+            record::Kind::ReferenceSequence,
+            record::Value::try_from_iter([
+                ("SN", "sq0"),
+                ("LN", "13"),
+                ("M5", "d7eba311421bbc9d3ada44709dd61534"),
+                ("AN", "sq0a,sq0b"),
+                ("UR", "file:///tmp/ref.fa"),
+            ])?,
+        );
+
+        let reference_sequence = ReferenceSequence::try_from(record)?;
+
+        assert_eq!(
+            reference_sequence.md5_checksum(),
+            Some("d7eba311421bbc9d3ada44709dd61534".parse()?)
+        );
+        assert_eq!(
+            reference_sequence
+                .alternative_names()
+                .map(|names| names.to_vec()),
+            Some(vec![String::from("sq0a"), String::from("sq0b")])
+        );
+        assert_eq!(reference_sequence.uri(), Some("file:///tmp/ref.fa"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_record_with_strictness_with_invalid_md5_checksum(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let build_record = || {
+            record::Value::try_from_iter([("SN", "sq0"), ("LN", "13"), ("M5", "d7eba311")])
+                .map(|value| Record::new(record::Kind::ReferenceSequence, value))
+        };
+
+        assert_eq!(
+            try_from_record_with_strictness(build_record()?, true),
+            Err(TryFromRecordError::InvalidMd5Checksum(
+                md5_checksum::ParseError::InvalidLength(8)
+            ))
+        );
+
+        let reference_sequence = try_from_record_with_strictness(build_record()?, false)?;
+        assert!(reference_sequence.md5_checksum().is_none());
+        assert_eq!(
+            reference_sequence.fields().get(&Tag::Md5Checksum),
+            Some(&String::from("d7eba311"))
+        );
+        assert_eq!(
+            reference_sequence.to_string(),
+            "@SQ\tSN:sq0\tLN:13\tM5:d7eba311"
+        );
+
+        Ok(())
+    }
 }
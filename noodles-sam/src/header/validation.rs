@@ -0,0 +1,279 @@
+//! SAM record validation.
+
+use std::{error, fmt};
+
+use noodles_core::Position;
+
+use super::Header;
+use crate::{
+    record::{data::field::Tag, ReferenceSequenceName},
+    AlignmentRecord,
+};
+
+/// An error returned when a SAM record is invalid with respect to a SAM header.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidationError {
+    /// The reference sequence name (`RNAME`) is not in the reference sequence dictionary.
+    MissingReferenceSequence(ReferenceSequenceName),
+    /// The position (`POS`) is beyond the end of the reference sequence.
+    PositionOutOfBounds {
+        /// The position.
+        position: Position,
+        /// The reference sequence length.
+        reference_sequence_len: usize,
+    },
+    /// The mate reference sequence name (`RNEXT`) is not in the reference sequence dictionary.
+    MissingMateReferenceSequence(ReferenceSequenceName),
+    /// The read group (`RG:Z`) is not in the read group dictionary.
+    MissingReadGroup(String),
+    /// The program (`PG:Z`) is not in the program dictionary.
+    MissingProgram(String),
+    /// The read length of the CIGAR operations does not match the sequence length.
+    CigarReadLengthMismatch {
+        /// The number of read bases consumed by the CIGAR operations.
+        cigar_read_len: usize,
+        /// The sequence length.
+        sequence_len: usize,
+    },
+}
+
+impl error::Error for ValidationError {}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingReferenceSequence(name) => {
+                write!(f, "missing reference sequence: {}", name)
+            }
+            Self::PositionOutOfBounds {
+                position,
+                reference_sequence_len,
+            } => write!(
+                f,
+                "position out of reference sequence bounds: expected <= {}, got {}",
+                reference_sequence_len, position
+            ),
+            Self::MissingMateReferenceSequence(name) => {
+                write!(f, "missing mate reference sequence: {}", name)
+            }
+            Self::MissingReadGroup(id) => write!(f, "missing read group: {}", id),
+            Self::MissingProgram(id) => write!(f, "missing program: {}", id),
+            Self::CigarReadLengthMismatch {
+                cigar_read_len,
+                sequence_len,
+            } => write!(
+                f,
+                "CIGAR read length mismatch: expected {}, got {}",
+                sequence_len, cigar_read_len
+            ),
+        }
+    }
+}
+
+pub(super) fn validate_record(
+    header: &Header,
+    record: &crate::Record,
+) -> Result<(), ValidationError> {
+    if let Some(reference_sequence_name) = record.reference_sequence_name() {
+        let reference_sequence = header
+            .reference_sequences()
+            .get(reference_sequence_name.as_str())
+            .ok_or_else(|| {
+                ValidationError::MissingReferenceSequence(reference_sequence_name.clone())
+            })?;
+
+        if let Some(position) = record.position() {
+            let reference_sequence_len = reference_sequence.len() as usize;
+
+            if usize::from(position) > reference_sequence_len {
+                return Err(ValidationError::PositionOutOfBounds {
+                    position,
+                    reference_sequence_len,
+                });
+            }
+        }
+    }
+
+    if let Some(mate_reference_sequence_name) = record.mate_reference_sequence_name() {
+        if !header
+            .reference_sequences()
+            .contains_key(mate_reference_sequence_name.as_str())
+        {
+            return Err(ValidationError::MissingMateReferenceSequence(
+                mate_reference_sequence_name.clone(),
+            ));
+        }
+    }
+
+    if let Some(id) = get_string_value(record, Tag::ReadGroup) {
+        if !header.read_groups().contains_key(id) {
+            return Err(ValidationError::MissingReadGroup(id.into()));
+        }
+    }
+
+    if let Some(id) = get_string_value(record, Tag::Program) {
+        if !header.programs().contains_key(id) {
+            return Err(ValidationError::MissingProgram(id.into()));
+        }
+    }
+
+    validate_cigar(record)
+}
+
+/// Validates that the read length of the CIGAR operations matches the sequence length.
+///
+/// Records with no CIGAR operations or no sequence are not checked.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::{self as sam, header::validation::{self, ValidationError}};
+///
+/// let record: sam::Record = "r0\t4\t*\t0\t255\t4M\t*\t0\t0\tACGT\t*".parse()?;
+/// assert!(validation::validate_cigar(&record).is_ok());
+///
+/// let record: sam::Record = "r0\t4\t*\t0\t255\t4M\t*\t0\t0\tACG\t*".parse()?;
+/// assert_eq!(
+///     validation::validate_cigar(&record),
+///     Err(ValidationError::CigarReadLengthMismatch {
+///         cigar_read_len: 4,
+///         sequence_len: 3,
+///     })
+/// );
+/// # Ok::<_, sam::record::ParseError>(())
+/// ```
+pub fn validate_cigar(record: &crate::Record) -> Result<(), ValidationError> {
+    let cigar = record.cigar();
+    let sequence = record.sequence();
+
+    if cigar.is_empty() || sequence.is_empty() {
+        return Ok(());
+    }
+
+    let cigar_read_len = cigar.read_len();
+    let sequence_len = sequence.len();
+
+    if cigar_read_len == sequence_len {
+        Ok(())
+    } else {
+        Err(ValidationError::CigarReadLengthMismatch {
+            cigar_read_len,
+            sequence_len,
+        })
+    }
+}
+
+fn get_string_value(record: &crate::Record, tag: Tag) -> Option<&str> {
+    record
+        .data()
+        .get(tag)
+        .and_then(|field| field.value().as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::header::{Program, ReadGroup, ReferenceSequence};
+
+    fn build_header() -> Result<Header, Box<dyn std::error::Error>> {
+        Ok(Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+            .add_read_group(ReadGroup::new("rg0"))
+            .add_program(Program::new("pg0"))
+            .build())
+    }
+
+    #[test]
+    fn test_validate_record() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let record = "r0\t0\tsq0\t8\t60\t1M\t=\t1\t0\tA\t*\tRG:Z:rg0\tPG:Z:pg0".parse()?;
+        assert_eq!(validate_record(&header, &record), Ok(()));
+
+        let record = crate::Record::default();
+        assert_eq!(validate_record(&header, &record), Ok(()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_record_with_invalid_record() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let record = "r0\t0\tsq1\t1\t60\t1M\t*\t0\t0\tA\t*".parse()?;
+        assert_eq!(
+            validate_record(&header, &record),
+            Err(ValidationError::MissingReferenceSequence("sq1".parse()?))
+        );
+
+        let record = "r0\t0\tsq0\t9\t60\t1M\t*\t0\t0\tA\t*".parse()?;
+        assert_eq!(
+            validate_record(&header, &record),
+            Err(ValidationError::PositionOutOfBounds {
+                position: Position::try_from(9)?,
+                reference_sequence_len: 8,
+            })
+        );
+
+        let record = "r0\t0\tsq0\t1\t60\t1M\tsq1\t1\t0\tA\t*".parse()?;
+        assert_eq!(
+            validate_record(&header, &record),
+            Err(ValidationError::MissingMateReferenceSequence(
+                "sq1".parse()?
+            ))
+        );
+
+        let record = "r0\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\tRG:Z:rg1".parse()?;
+        assert_eq!(
+            validate_record(&header, &record),
+            Err(ValidationError::MissingReadGroup(String::from("rg1")))
+        );
+
+        let record = "r0\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\tPG:Z:pg1".parse()?;
+        assert_eq!(
+            validate_record(&header, &record),
+            Err(ValidationError::MissingProgram(String::from("pg1")))
+        );
+
+        let record = crate::Record::builder()
+            .set_flags(crate::record::Flags::empty())
+            .set_reference_sequence_name("sq0".parse()?)
+            .set_position(Position::try_from(1)?)
+            .set_cigar("10M".parse()?)
+            .set_sequence("ACGTACGT".parse()?)
+            .build();
+
+        assert_eq!(
+            validate_record(&header, &record),
+            Err(ValidationError::CigarReadLengthMismatch {
+                cigar_read_len: 10,
+                sequence_len: 8,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_cigar() -> Result<(), Box<dyn std::error::Error>> {
+        let record = "r0\t4\t*\t0\t255\t2S3M1I2D2M\t*\t0\t0\tACGTACGT\t*".parse()?;
+        assert_eq!(validate_cigar(&record), Ok(()));
+
+        let record = "r0\t4\t*\t0\t255\t*\t*\t0\t0\tACGTACGT\t*".parse()?;
+        assert_eq!(validate_cigar(&record), Ok(()));
+
+        let record = "r0\t4\t*\t0\t255\t10M\t*\t0\t0\t*\t*".parse()?;
+        assert_eq!(validate_cigar(&record), Ok(()));
+
+        let record = "r0\t4\t*\t0\t255\t10M\t*\t0\t0\tACGTACGT\t*".parse()?;
+        assert_eq!(
+            validate_cigar(&record),
+            Err(ValidationError::CigarReadLengthMismatch {
+                cigar_read_len: 10,
+                sequence_len: 8,
+            })
+        );
+
+        Ok(())
+    }
+}
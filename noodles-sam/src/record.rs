@@ -6,16 +6,20 @@ pub mod data;
 mod field;
 mod flags;
 pub mod mapping_quality;
+pub mod md;
+pub mod nm;
 mod parser;
 pub mod quality_scores;
 pub mod read_name;
+mod record_ref;
 pub mod reference_sequence_name;
 pub mod sequence;
 
 pub use self::{
     builder::Builder, cigar::Cigar, data::Data, field::Field, flags::Flags,
     mapping_quality::MappingQuality, parser::ParseError, quality_scores::QualityScores,
-    read_name::ReadName, reference_sequence_name::ReferenceSequenceName, sequence::Sequence,
+    read_name::ReadName, record_ref::RecordRef, reference_sequence_name::ReferenceSequenceName,
+    sequence::Sequence,
 };
 
 use std::{fmt, io, str::FromStr};
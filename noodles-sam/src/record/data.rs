@@ -251,13 +251,16 @@ impl Data {
         i.map(|j| {
             let removed_field = self.fields.swap_remove(j);
 
-            let swapped_field = &self.fields[j];
-            set_index(
-                &mut self.standard_field_indices,
-                &mut self.other_field_indices,
-                swapped_field.tag(),
-                j,
-            );
+            // The last field is moved to the removed position unless the removed field was the
+            // last field.
+            if let Some(swapped_field) = self.fields.get(j) {
+                set_index(
+                    &mut self.standard_field_indices,
+                    &mut self.other_field_indices,
+                    swapped_field.tag(),
+                    j,
+                );
+            }
 
             removed_field
         })
@@ -465,6 +468,31 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_remove() -> Result<(), ParseError> {
+        let mut data = Data::try_from(vec![
+            Field::new(Tag::ReadGroup, Value::String(String::from("rg0"))),
+            Field::new(Tag::AlignmentHitCount, Value::Int32(1)),
+            Field::new(Tag::Comment, Value::String(String::from("noodles"))),
+        ])?;
+
+        let rg = Field::new(Tag::ReadGroup, Value::String(String::from("rg0")));
+        assert_eq!(data.remove(Tag::ReadGroup), Some(rg));
+        assert_eq!(data.to_string(), "CO:Z:noodles\tNH:i:1");
+
+        let nh = Field::new(Tag::AlignmentHitCount, Value::Int32(1));
+        assert_eq!(data.remove(Tag::AlignmentHitCount), Some(nh));
+        assert_eq!(data.to_string(), "CO:Z:noodles");
+
+        let co = Field::new(Tag::Comment, Value::String(String::from("noodles")));
+        assert_eq!(data.remove(Tag::Comment), Some(co));
+        assert!(data.is_empty());
+
+        assert!(data.remove(Tag::Comment).is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_str() -> Result<(), ParseError> {
         assert_eq!("".parse(), Ok(Data::default()));
@@ -484,4 +512,21 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_get_with_integer_values() -> Result<(), Box<dyn std::error::Error>> {
+        let data: Data = "NM:i:3\tZB:B:i,1,-2".parse()?;
+
+        let edit_distance = data
+            .get(Tag::EditDistance)
+            .and_then(|field| field.value().as_int());
+        assert_eq!(edit_distance, Some(3));
+
+        let values = data
+            .get("ZB".parse()?)
+            .and_then(|field| field.value().as_int_array());
+        assert_eq!(values, Some(vec![1, -2]));
+
+        Ok(())
+    }
 }
@@ -497,6 +497,58 @@ impl Value {
         matches!(self, Self::UInt32Array(_))
     }
 
+    /// Returns the value as an array of 64-bit integers.
+    ///
+    /// This is a convenience method that converts any integer array to a `Vec<i64>`, which
+    /// captures the entire range of all record data field integer array values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::data::field::Value;
+    /// assert_eq!(Value::UInt8Array(vec![0]).as_int_array(), Some(vec![0]));
+    /// assert_eq!(Value::FloatArray(vec![0.0]).as_int_array(), None);
+    /// ```
+    pub fn as_int_array(&self) -> Option<Vec<i64>> {
+        fn convert<N>(values: &[N]) -> Vec<i64>
+        where
+            N: Copy + Into<i64>,
+        {
+            values.iter().copied().map(Into::into).collect()
+        }
+
+        match *self {
+            Self::Int8Array(ref a) => Some(convert(a)),
+            Self::UInt8Array(ref a) => Some(convert(a)),
+            Self::Int16Array(ref a) => Some(convert(a)),
+            Self::UInt16Array(ref a) => Some(convert(a)),
+            Self::Int32Array(ref a) => Some(convert(a)),
+            Self::UInt32Array(ref a) => Some(convert(a)),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the value is an integer array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::data::field::Value;
+    /// assert!(Value::Int32Array(vec![0]).is_int_array());
+    /// assert!(!Value::FloatArray(vec![0.0]).is_int_array());
+    /// ```
+    pub fn is_int_array(&self) -> bool {
+        matches!(
+            self,
+            Self::Int8Array(_)
+                | Self::UInt8Array(_)
+                | Self::Int16Array(_)
+                | Self::UInt16Array(_)
+                | Self::Int32Array(_)
+                | Self::UInt32Array(_)
+        )
+    }
+
     /// Returns the value as an array of single-precision floating-points if it is an array of
     /// single-precision floating-points.
     ///
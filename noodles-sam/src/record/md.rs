@@ -0,0 +1,313 @@
+//! SAM record mismatched positions (`MD`).
+
+use std::{fmt::Write, io};
+
+use noodles_core::Position;
+use noodles_fasta as fasta;
+
+use super::{cigar::op::Kind, sequence::Base, Cigar, Sequence};
+use crate::{AlignmentRecord, Header};
+
+/// Calculates the string for mismatched positions (`MD:Z`) of an alignment record.
+///
+/// The reference sequence of the record is fetched from the given repository. Matches are
+/// counted, mismatches are written as the reference base, and deletions are written as `^`
+/// followed by the deleted reference bases. Insertions, soft clips, and reference skips are not
+/// included.
+///
+/// This returns an error if the record is unmapped, has no sequence, or does not fit in its
+/// reference sequence.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_fasta as fasta;
+/// use noodles_sam::{self as sam, header::ReferenceSequence, record::md};
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+///     .build();
+///
+/// let repository = fasta::Repository::new(vec![fasta::Record::new(
+///     fasta::record::Definition::new("sq0", None),
+///     fasta::record::Sequence::from(b"ACGTACGT".to_vec()),
+/// )]);
+///
+/// let record = sam::Record::builder()
+///     .set_flags(sam::record::Flags::empty())
+///     .set_reference_sequence_name("sq0".parse()?)
+///     .set_position(Position::try_from(1)?)
+///     .set_cigar("4M".parse()?)
+///     .set_sequence("AGGT".parse()?)
+///     .build();
+///
+/// assert_eq!(md::calculate(&header, &repository, &record)?, "1C2");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn calculate<R>(
+    header: &Header,
+    reference_sequence_repository: &fasta::Repository,
+    record: &R,
+) -> io::Result<String>
+where
+    R: AlignmentRecord + ?Sized,
+{
+    let (reference_sequence, alignment_start) =
+        get_reference_sequence(header, reference_sequence_repository, record)?;
+
+    calculate_with_reference_sequence(
+        record.cigar(),
+        record.sequence(),
+        &reference_sequence,
+        alignment_start,
+    )
+}
+
+// Returns the reference sequence and alignment start of a mapped record with a sequence.
+pub(crate) fn get_reference_sequence<R>(
+    header: &Header,
+    reference_sequence_repository: &fasta::Repository,
+    record: &R,
+) -> io::Result<(fasta::record::Sequence, Position)>
+where
+    R: AlignmentRecord + ?Sized,
+{
+    if record.flags().is_unmapped() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "record is unmapped",
+        ));
+    }
+
+    if record.sequence().is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "missing sequence",
+        ));
+    }
+
+    let reference_sequence_name = record
+        .reference_sequence(header.reference_sequences())
+        .transpose()?
+        .map(|reference_sequence| reference_sequence.name().to_string())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "missing reference sequence name",
+            )
+        })?;
+
+    let alignment_start = record
+        .alignment_start()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing alignment start"))?;
+
+    let reference_sequence = reference_sequence_repository
+        .get(&reference_sequence_name)
+        .transpose()?
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("missing reference sequence: {}", reference_sequence_name),
+            )
+        })?;
+
+    Ok((reference_sequence, alignment_start))
+}
+
+// Returns the read bases and reference bases of an alignment match operation.
+pub(crate) fn get_bases<'a>(
+    sequence: &'a Sequence,
+    reference_sequence: &'a fasta::record::Sequence,
+    read_position: usize,
+    reference_position: Position,
+    len: usize,
+) -> io::Result<(&'a [Base], &'a [u8])> {
+    let read_bases = sequence
+        .as_ref()
+        .get(read_position..read_position + len)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "sequence length does not match CIGAR",
+            )
+        })?;
+
+    let reference_bases = get_reference_bases(reference_sequence, reference_position, len)?;
+
+    Ok((read_bases, reference_bases))
+}
+
+pub(crate) fn get_reference_bases(
+    reference_sequence: &fasta::record::Sequence,
+    reference_position: Position,
+    len: usize,
+) -> io::Result<&[u8]> {
+    let start = usize::from(reference_position) - 1;
+
+    reference_sequence
+        .as_ref()
+        .get(start..start + len)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "alignment is out of reference sequence bounds",
+            )
+        })
+}
+
+pub(crate) fn is_mismatch(read_base: Base, reference_base: u8) -> bool {
+    read_base != Base::Eq && !u8::from(read_base).eq_ignore_ascii_case(&reference_base)
+}
+
+fn calculate_with_reference_sequence(
+    cigar: &Cigar,
+    sequence: &Sequence,
+    reference_sequence: &fasta::record::Sequence,
+    alignment_start: Position,
+) -> io::Result<String> {
+    let mut md = String::new();
+    let mut match_count = 0;
+
+    let mut read_position = 0;
+    let mut reference_position = alignment_start;
+
+    for op in cigar.iter() {
+        let len = op.len();
+
+        match op.kind() {
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                let (read_bases, reference_bases) = get_bases(
+                    sequence,
+                    reference_sequence,
+                    read_position,
+                    reference_position,
+                    len,
+                )?;
+
+                for (&read_base, &reference_base) in read_bases.iter().zip(reference_bases) {
+                    if is_mismatch(read_base, reference_base) {
+                        write!(md, "{}", match_count).ok();
+                        md.push(char::from(reference_base.to_ascii_uppercase()));
+                        match_count = 0;
+                    } else {
+                        match_count += 1;
+                    }
+                }
+
+                read_position += len;
+                reference_position = advance(reference_position, len)?;
+            }
+            Kind::Deletion => {
+                let reference_bases =
+                    get_reference_bases(reference_sequence, reference_position, len)?;
+
+                write!(md, "{}^", match_count).ok();
+                md.extend(
+                    reference_bases
+                        .iter()
+                        .map(|&b| char::from(b.to_ascii_uppercase())),
+                );
+                match_count = 0;
+
+                reference_position = advance(reference_position, len)?;
+            }
+            Kind::Insertion | Kind::SoftClip => read_position += len,
+            Kind::Skip => reference_position = advance(reference_position, len)?,
+            Kind::HardClip | Kind::Pad => {}
+        }
+    }
+
+    write!(md, "{}", match_count).ok();
+
+    Ok(md)
+}
+
+pub(crate) fn advance(position: Position, len: usize) -> io::Result<Position> {
+    usize::from(position)
+        .checked_add(len)
+        .and_then(Position::new)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "position overflow"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::header::ReferenceSequence;
+
+    #[test]
+    fn test_calculate() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 10)?)
+            .build();
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(b"ACGTACGTAC".to_vec()),
+        )]);
+
+        let record = crate::Record::builder()
+            .set_flags(crate::record::Flags::empty())
+            .set_reference_sequence_name("sq0".parse()?)
+            .set_position(Position::try_from(1)?)
+            .set_cigar("4M1D3M".parse()?)
+            .set_sequence("ATGTCGT".parse()?)
+            .build();
+
+        assert_eq!(calculate(&header, &repository, &record)?, "1C2^A3");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_calculate_with_reference_sequence() -> Result<(), Box<dyn std::error::Error>> {
+        fn t(
+            cigar: &str,
+            sequence: &str,
+            alignment_start: usize,
+            expected: &str,
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            let reference_sequence = fasta::record::Sequence::from(b"ACGTACGTAC".to_vec());
+
+            let actual = calculate_with_reference_sequence(
+                &cigar.parse()?,
+                &sequence.parse()?,
+                &reference_sequence,
+                Position::try_from(alignment_start)?,
+            )?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        t("4M", "ACGT", 1, "4")?;
+        t("4M", "TCGA", 1, "0A2T0")?;
+        t("2S2M", "NNGT", 3, "2")?;
+        t("2M2I2M", "ACTTGT", 1, "4")?;
+        t("2M1D1M", "ACT", 1, "2^G1")?;
+        t("1M1D1M", "AT", 1, "1^C0G0")?;
+        t("2M2N2M", "ACAC", 1, "4")?;
+        t("4=", "A=GT", 1, "4")?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_calculate_with_reference_sequence_out_of_bounds(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequence = fasta::record::Sequence::from(b"ACGT".to_vec());
+
+        assert!(matches!(
+            calculate_with_reference_sequence(
+                &"4M".parse()?,
+                &"ACGT".parse()?,
+                &reference_sequence,
+                Position::try_from(2)?,
+            ),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
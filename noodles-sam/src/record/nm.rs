@@ -0,0 +1,153 @@
+//! SAM record edit distance (`NM`).
+
+use std::io;
+
+use noodles_core::Position;
+use noodles_fasta as fasta;
+
+use super::{
+    cigar::op::Kind,
+    md::{advance, get_bases, get_reference_sequence, is_mismatch},
+    Cigar, Sequence,
+};
+use crate::{AlignmentRecord, Header};
+
+/// Calculates the edit distance (`NM:i`) of an alignment record to its reference sequence.
+///
+/// The reference sequence of the record is fetched from the given repository. The edit distance
+/// is the number of mismatched bases plus the number of inserted and deleted bases. Soft clips and
+/// reference skips are not counted.
+///
+/// This returns an error if the record is unmapped, has no sequence, or does not fit in its
+/// reference sequence.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_fasta as fasta;
+/// use noodles_sam::{self as sam, header::ReferenceSequence, record::nm};
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+///     .build();
+///
+/// let repository = fasta::Repository::new(vec![fasta::Record::new(
+///     fasta::record::Definition::new("sq0", None),
+///     fasta::record::Sequence::from(b"ACGTACGT".to_vec()),
+/// )]);
+///
+/// let record = sam::Record::builder()
+///     .set_flags(sam::record::Flags::empty())
+///     .set_reference_sequence_name("sq0".parse()?)
+///     .set_position(Position::try_from(1)?)
+///     .set_cigar("2M1I2M".parse()?)
+///     .set_sequence("AGTGT".parse()?)
+///     .build();
+///
+/// assert_eq!(nm::calculate(&header, &repository, &record)?, 2);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn calculate<R>(
+    header: &Header,
+    reference_sequence_repository: &fasta::Repository,
+    record: &R,
+) -> io::Result<usize>
+where
+    R: AlignmentRecord + ?Sized,
+{
+    let (reference_sequence, alignment_start) =
+        get_reference_sequence(header, reference_sequence_repository, record)?;
+
+    calculate_with_reference_sequence(
+        record.cigar(),
+        record.sequence(),
+        &reference_sequence,
+        alignment_start,
+    )
+}
+
+fn calculate_with_reference_sequence(
+    cigar: &Cigar,
+    sequence: &Sequence,
+    reference_sequence: &fasta::record::Sequence,
+    alignment_start: Position,
+) -> io::Result<usize> {
+    let mut edit_distance = 0;
+
+    let mut read_position = 0;
+    let mut reference_position = alignment_start;
+
+    for op in cigar.iter() {
+        let len = op.len();
+
+        match op.kind() {
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                let (read_bases, reference_bases) = get_bases(
+                    sequence,
+                    reference_sequence,
+                    read_position,
+                    reference_position,
+                    len,
+                )?;
+
+                edit_distance += read_bases
+                    .iter()
+                    .zip(reference_bases)
+                    .filter(|(&read_base, &reference_base)| is_mismatch(read_base, reference_base))
+                    .count();
+
+                read_position += len;
+                reference_position = advance(reference_position, len)?;
+            }
+            Kind::Insertion => {
+                edit_distance += len;
+                read_position += len;
+            }
+            Kind::Deletion => {
+                edit_distance += len;
+                reference_position = advance(reference_position, len)?;
+            }
+            Kind::SoftClip => read_position += len,
+            Kind::Skip => reference_position = advance(reference_position, len)?,
+            Kind::HardClip | Kind::Pad => {}
+        }
+    }
+
+    Ok(edit_distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_with_reference_sequence() -> Result<(), Box<dyn std::error::Error>> {
+        fn t(
+            cigar: &str,
+            sequence: &str,
+            alignment_start: usize,
+            expected: usize,
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            let reference_sequence = fasta::record::Sequence::from(b"ACGTACGTAC".to_vec());
+
+            let actual = calculate_with_reference_sequence(
+                &cigar.parse()?,
+                &sequence.parse()?,
+                &reference_sequence,
+                Position::try_from(alignment_start)?,
+            )?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        t("4M", "ACGT", 1, 0)?;
+        t("3M2I2M1D2M", "ATGTTTAGT", 1, 4)?;
+        t("2S2M2N2M", "TTGTGT", 3, 0)?;
+        t("1H4M1H", "ACTT", 1, 1)?;
+
+        Ok(())
+    }
+}
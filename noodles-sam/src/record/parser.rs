@@ -13,8 +13,8 @@ use super::{
 };
 use crate::AlignmentRecord;
 
-const FIELD_DELIMITER: char = '\t';
-const MAX_FIELDS: usize = 12;
+pub(super) const FIELD_DELIMITER: char = '\t';
+pub(super) const MAX_FIELDS: usize = 12;
 
 /// An error returned when a raw SAM record fails to parse.
 #[derive(Clone, Debug, PartialEq)]
@@ -172,14 +172,14 @@ pub(super) fn parse(s: &str) -> Result<Record, ParseError> {
     Ok(record)
 }
 
-fn parse_string<'a, I>(fields: &mut I, field: Field) -> Result<&'a str, ParseError>
+pub(super) fn parse_string<'a, I>(fields: &mut I, field: Field) -> Result<&'a str, ParseError>
 where
     I: Iterator<Item = &'a str>,
 {
     fields.next().ok_or(ParseError::MissingField(field))
 }
 
-fn parse_flag<'a, I>(fields: &mut I) -> Result<Flags, ParseError>
+pub(super) fn parse_flag<'a, I>(fields: &mut I) -> Result<Flags, ParseError>
 where
     I: Iterator<Item = &'a str>,
 {
@@ -211,7 +211,7 @@ where
     })
 }
 
-fn parse_pos<'a, I>(fields: &mut I) -> Result<Option<Position>, ParseError>
+pub(super) fn parse_pos<'a, I>(fields: &mut I) -> Result<Option<Position>, ParseError>
 where
     I: Iterator<Item = &'a str>,
 {
@@ -220,7 +220,7 @@ where
         .map(Position::new)
 }
 
-fn parse_mapq<'a, I>(fields: &mut I) -> Result<Option<MappingQuality>, ParseError>
+pub(super) fn parse_mapq<'a, I>(fields: &mut I) -> Result<Option<MappingQuality>, ParseError>
 where
     I: Iterator<Item = &'a str>,
 {
@@ -258,7 +258,7 @@ where
     })
 }
 
-fn parse_pnext<'a, I>(fields: &mut I) -> Result<Option<Position>, ParseError>
+pub(super) fn parse_pnext<'a, I>(fields: &mut I) -> Result<Option<Position>, ParseError>
 where
     I: Iterator<Item = &'a str>,
 {
@@ -267,7 +267,7 @@ where
         .map(Position::new)
 }
 
-fn parse_tlen<'a, I>(fields: &mut I) -> Result<i32, ParseError>
+pub(super) fn parse_tlen<'a, I>(fields: &mut I) -> Result<i32, ParseError>
 where
     I: Iterator<Item = &'a str>,
 {
@@ -129,6 +129,25 @@ impl QualityScores {
     pub fn push(&mut self, score: Score) {
         self.0.push(score);
     }
+
+    /// Reverses the order of the scores in place.
+    ///
+    /// This is typically paired with [`super::Sequence::reverse_complement`] to reorient a read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::QualityScores;
+    ///
+    /// let mut quality_scores: QualityScores = "NDLS".parse()?;
+    /// quality_scores.reverse();
+    ///
+    /// assert_eq!(quality_scores.to_string(), "SLDN");
+    /// # Ok::<_, noodles_sam::record::quality_scores::ParseError>(())
+    /// ```
+    pub fn reverse(&mut self) {
+        self.0.reverse();
+    }
 }
 
 impl AsRef<[Score]> for QualityScores {
@@ -261,6 +280,17 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_reverse() -> Result<(), ParseError> {
+        let mut quality_scores = QualityScores::try_from(vec![45, 35, 43, 50, 0])?;
+        quality_scores.reverse();
+        assert_eq!(
+            quality_scores,
+            QualityScores::try_from(vec![0, 50, 43, 35, 45])?
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() -> Result<(), ParseError> {
         let quality_scores = QualityScores::default();
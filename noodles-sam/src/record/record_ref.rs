@@ -0,0 +1,250 @@
+use noodles_core::Position;
+
+use super::{
+    parser::{
+        parse_flag, parse_mapq, parse_pnext, parse_pos, parse_string, parse_tlen, FIELD_DELIMITER,
+        MAX_FIELDS,
+    },
+    Field, Flags, MappingQuality, ParseError, Record, EQ_FIELD, NULL_FIELD,
+};
+
+/// A borrowed view of a raw SAM record.
+///
+/// Fields are read directly from the backing buffer without copying. Fixed-size fields (flags,
+/// positions, mapping quality, and template length) are parsed when the view is created;
+/// variable-length fields are returned as raw strings. Use [`Self::to_owned`] to parse the full
+/// record into a [`Record`].
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::{self as sam, record::RecordRef};
+///
+/// let record = RecordRef::try_from("r0\t0\tsq0\t8\t13\t4M\t=\t21\t17\tACGT\tNDLS")?;
+///
+/// assert_eq!(record.read_name(), Some("r0"));
+/// assert_eq!(record.flags(), sam::record::Flags::empty());
+/// assert_eq!(record.position().map(usize::from), Some(8));
+/// assert_eq!(record.mate_reference_sequence_name(), Some("sq0"));
+/// # Ok::<_, sam::record::ParseError>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordRef<'a> {
+    src: &'a str,
+    read_name: Option<&'a str>,
+    flags: Flags,
+    reference_sequence_name: Option<&'a str>,
+    position: Option<Position>,
+    mapping_quality: Option<MappingQuality>,
+    cigar: &'a str,
+    mate_reference_sequence_name: Option<&'a str>,
+    mate_position: Option<Position>,
+    template_length: i32,
+    sequence: &'a str,
+    quality_scores: &'a str,
+    data: &'a str,
+}
+
+impl<'a> RecordRef<'a> {
+    /// Returns the read name.
+    pub fn read_name(&self) -> Option<&'a str> {
+        self.read_name
+    }
+
+    /// Returns the flags.
+    pub fn flags(&self) -> Flags {
+        self.flags
+    }
+
+    /// Returns the reference sequence name.
+    pub fn reference_sequence_name(&self) -> Option<&'a str> {
+        self.reference_sequence_name
+    }
+
+    /// Returns the start position.
+    pub fn position(&self) -> Option<Position> {
+        self.position
+    }
+
+    /// Returns the mapping quality.
+    pub fn mapping_quality(&self) -> Option<MappingQuality> {
+        self.mapping_quality
+    }
+
+    /// Returns the raw CIGAR string.
+    ///
+    /// This is empty if the CIGAR is missing (`*`).
+    pub fn cigar(&self) -> &'a str {
+        self.cigar
+    }
+
+    /// Returns the mate reference sequence name.
+    ///
+    /// A mate reference sequence name of `=` is resolved to the reference sequence name.
+    pub fn mate_reference_sequence_name(&self) -> Option<&'a str> {
+        self.mate_reference_sequence_name
+    }
+
+    /// Returns the mate start position.
+    pub fn mate_position(&self) -> Option<Position> {
+        self.mate_position
+    }
+
+    /// Returns the template length.
+    pub fn template_length(&self) -> i32 {
+        self.template_length
+    }
+
+    /// Returns the raw sequence.
+    ///
+    /// This is empty if the sequence is missing (`*`).
+    pub fn sequence(&self) -> &'a str {
+        self.sequence
+    }
+
+    /// Returns the raw quality scores.
+    ///
+    /// This is empty if the quality scores are missing (`*`).
+    pub fn quality_scores(&self) -> &'a str {
+        self.quality_scores
+    }
+
+    /// Returns the raw data fields.
+    ///
+    /// This is empty if the record has no data fields.
+    pub fn data(&self) -> &'a str {
+        self.data
+    }
+
+    /// Parses the full record into an owned SAM record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, record::RecordRef, AlignmentRecord};
+    ///
+    /// let record_ref = RecordRef::try_from("r0\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*")?;
+    /// let record = record_ref.to_owned()?;
+    ///
+    /// assert_eq!(record.flags(), sam::record::Flags::UNMAPPED);
+    /// # Ok::<_, sam::record::ParseError>(())
+    /// ```
+    pub fn to_owned(&self) -> Result<Record, ParseError> {
+        self.src.parse()
+    }
+}
+
+impl<'a> TryFrom<&'a str> for RecordRef<'a> {
+    type Error = ParseError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        let mut fields = s.splitn(MAX_FIELDS, FIELD_DELIMITER);
+
+        let read_name = parse_string(&mut fields, Field::Name).map(parse_nullable)?;
+        let flags = parse_flag(&mut fields)?;
+        let reference_sequence_name =
+            parse_string(&mut fields, Field::ReferenceSequenceName).map(parse_nullable)?;
+        let position = parse_pos(&mut fields)?;
+        let mapping_quality = parse_mapq(&mut fields)?;
+        let cigar = parse_string(&mut fields, Field::Cigar).map(parse_raw)?;
+
+        let mate_reference_sequence_name =
+            parse_string(&mut fields, Field::MateReferenceSequenceName).map(|t| match t {
+                EQ_FIELD => reference_sequence_name,
+                _ => parse_nullable(t),
+            })?;
+
+        let mate_position = parse_pnext(&mut fields)?;
+        let template_length = parse_tlen(&mut fields)?;
+        let sequence = parse_string(&mut fields, Field::Sequence).map(parse_raw)?;
+        let quality_scores = parse_string(&mut fields, Field::QualityScores).map(parse_raw)?;
+        let data = fields.next().unwrap_or_default();
+
+        Ok(Self {
+            src: s,
+            read_name,
+            flags,
+            reference_sequence_name,
+            position,
+            mapping_quality,
+            cigar,
+            mate_reference_sequence_name,
+            mate_position,
+            template_length,
+            sequence,
+            quality_scores,
+            data,
+        })
+    }
+}
+
+fn parse_nullable(s: &str) -> Option<&str> {
+    match s {
+        NULL_FIELD => None,
+        _ => Some(s),
+    }
+}
+
+fn parse_raw(s: &str) -> &str {
+    parse_nullable(s).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AlignmentRecord;
+
+    #[test]
+    fn test_try_from_str_for_record_ref() -> Result<(), Box<dyn std::error::Error>> {
+        let s = "r0\t99\tsq0\t8\t13\t4M\t=\t21\t17\tACGT\tNDLS\tNH:i:1\tRG:Z:rg0";
+        let record = RecordRef::try_from(s)?;
+
+        assert_eq!(record.read_name(), Some("r0"));
+        assert_eq!(record.flags(), Flags::from(99));
+        assert_eq!(record.reference_sequence_name(), Some("sq0"));
+        assert_eq!(record.position(), Position::new(8));
+        assert_eq!(record.mapping_quality(), MappingQuality::new(13));
+        assert_eq!(record.cigar(), "4M");
+        assert_eq!(record.mate_reference_sequence_name(), Some("sq0"));
+        assert_eq!(record.mate_position(), Position::new(21));
+        assert_eq!(record.template_length(), 17);
+        assert_eq!(record.sequence(), "ACGT");
+        assert_eq!(record.quality_scores(), "NDLS");
+        assert_eq!(record.data(), "NH:i:1\tRG:Z:rg0");
+
+        // The view borrows from the source.
+        assert!(s
+            .as_bytes()
+            .as_ptr_range()
+            .contains(&record.sequence().as_ptr()));
+
+        let record = RecordRef::try_from("*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*")?;
+        assert!(record.read_name().is_none());
+        assert!(record.reference_sequence_name().is_none());
+        assert!(record.position().is_none());
+        assert!(record.mapping_quality().is_none());
+        assert!(record.cigar().is_empty());
+        assert!(record.mate_reference_sequence_name().is_none());
+        assert!(record.sequence().is_empty());
+        assert!(record.quality_scores().is_empty());
+        assert!(record.data().is_empty());
+
+        assert_eq!(
+            RecordRef::try_from("r0\t4"),
+            Err(ParseError::MissingField(Field::ReferenceSequenceName))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_owned() -> Result<(), Box<dyn std::error::Error>> {
+        let s = "r0\t99\tsq0\t8\t13\t4M\t=\t21\t17\tACGT\tNDLS\tNH:i:1";
+        let record = RecordRef::try_from(s)?.to_owned()?;
+
+        assert_eq!(record, s.parse()?);
+        assert_eq!(record.flags(), Flags::from(99));
+
+        Ok(())
+    }
+}
@@ -128,6 +128,47 @@ impl Sequence {
     pub fn push(&mut self, base: Base) {
         self.0.push(base);
     }
+
+    /// Complements each base in the sequence in place.
+    ///
+    /// See [`Base::complement`] for the complement rules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Sequence;
+    ///
+    /// let mut sequence: Sequence = "ACGTN".parse()?;
+    /// sequence.complement();
+    ///
+    /// assert_eq!(sequence.to_string(), "TGCAN");
+    /// # Ok::<_, noodles_sam::record::sequence::ParseError>(())
+    /// ```
+    pub fn complement(&mut self) {
+        for base in &mut self.0 {
+            *base = base.complement();
+        }
+    }
+
+    /// Reverse complements the sequence in place.
+    ///
+    /// See [`Base::complement`] for the complement rules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Sequence;
+    ///
+    /// let mut sequence: Sequence = "ACGTN".parse()?;
+    /// sequence.reverse_complement();
+    ///
+    /// assert_eq!(sequence.to_string(), "NACGT");
+    /// # Ok::<_, noodles_sam::record::sequence::ParseError>(())
+    /// ```
+    pub fn reverse_complement(&mut self) {
+        self.0.reverse();
+        self.complement();
+    }
 }
 
 impl AsRef<[Base]> for Sequence {
@@ -238,6 +279,22 @@ mod tests {
         assert_eq!(sequence.to_string(), "ATCG");
     }
 
+    #[test]
+    fn test_reverse_complement() -> Result<(), ParseError> {
+        let mut sequence: Sequence = "ACGTN".parse()?;
+        sequence.reverse_complement();
+        assert_eq!(sequence, "NACGT".parse()?);
+
+        let mut sequence: Sequence = "AACRG=".parse()?;
+        sequence.reverse_complement();
+        assert_eq!(sequence, "=CYGTT".parse()?);
+
+        sequence.reverse_complement();
+        assert_eq!(sequence, "AACRG=".parse()?);
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_str() {
         let expected = Sequence(vec![Base::A, Base::T, Base::C, Base::G]);
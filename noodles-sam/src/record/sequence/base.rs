@@ -64,6 +64,40 @@ pub enum Base {
     Eq,
 }
 
+impl Base {
+    /// Returns the complement of the base.
+    ///
+    /// This uses the IUPAC complement rules, including ambiguity codes, e.g., `R` (A or G) is the
+    /// complement of `Y` (C or T). Uracil (`U`) complements to adenine (`A`). Bases without a
+    /// complement, e.g., `N` and `=`, are their own complement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::sequence::Base;
+    /// assert_eq!(Base::A.complement(), Base::T);
+    /// assert_eq!(Base::R.complement(), Base::Y);
+    /// assert_eq!(Base::N.complement(), Base::N);
+    /// ```
+    pub fn complement(self) -> Self {
+        match self {
+            Self::A => Self::T,
+            Self::C => Self::G,
+            Self::G => Self::C,
+            Self::T | Self::U => Self::A,
+            Self::R => Self::Y,
+            Self::Y => Self::R,
+            Self::K => Self::M,
+            Self::M => Self::K,
+            Self::B => Self::V,
+            Self::V => Self::B,
+            Self::D => Self::H,
+            Self::H => Self::D,
+            _ => self,
+        }
+    }
+}
+
 impl fmt::Display for Base {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_char(char::from(*self))
@@ -208,6 +242,29 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_complement() {
+        let bases = [
+            (Base::A, Base::T),
+            (Base::C, Base::G),
+            (Base::R, Base::Y),
+            (Base::K, Base::M),
+            (Base::B, Base::V),
+            (Base::D, Base::H),
+        ];
+
+        for (base, expected) in bases {
+            assert_eq!(base.complement(), expected);
+            assert_eq!(expected.complement(), base);
+        }
+
+        assert_eq!(Base::U.complement(), Base::A);
+
+        for base in [Base::S, Base::W, Base::N, Base::X, Base::Eq] {
+            assert_eq!(base.complement(), base);
+        }
+    }
+
     #[test]
     fn test_try_from_char_for_base() {
         for (c, &expected) in ('A'..='Z').zip(ALPHA_BASES) {
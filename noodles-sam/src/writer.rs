@@ -119,6 +119,10 @@ where
 
     /// Writes a SAM record.
     ///
+    /// The writer does not track whether a header was written, so records can be appended to a
+    /// stream that already has a header, e.g., a file opened in append mode. In that case, the
+    /// caller is responsible for the existing header being compatible with the records.
+    ///
     /// # Examples
     ///
     /// ```
@@ -278,4 +282,28 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_record_appended_to_header() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::Reader;
+
+        let mut buf = Vec::new();
+
+        let header: Header = "@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\n".parse()?;
+        Writer::new(&mut buf).write_header(&header)?;
+
+        let mut writer = Writer::new(&mut buf);
+        let record: Record = "r0\t0\tsq0\t1\t60\t4M\t*\t0\t0\tACGT\t*".parse()?;
+        writer.write_record(&record)?;
+        writer.write_record(&record)?;
+
+        let mut reader = Reader::new(&buf[..]);
+        let actual_header: Header = reader.read_header()?.parse()?;
+        assert_eq!(actual_header, header);
+
+        let records = reader.records().collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(records, [record.clone(), record]);
+
+        Ok(())
+    }
 }
@@ -217,3 +217,56 @@ where
         Ok((start, end))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use noodles_bgzf as bgzf;
+
+    use super::*;
+    use crate::index::reference_sequence::Bin;
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        fn chunk(start: u64, end: u64) -> Chunk {
+            Chunk::new(
+                bgzf::VirtualPosition::from(start),
+                bgzf::VirtualPosition::from(end),
+            )
+        }
+
+        let reference_sequence = ReferenceSequence::new(
+            vec![
+                Bin::new(0, vec![chunk(5, 8)]),
+                Bin::new(4681, vec![chunk(1, 3), chunk(8, 13), chunk(13, 21)]),
+                Bin::new(4682, vec![chunk(34, 55)]),
+            ],
+            vec![
+                bgzf::VirtualPosition::from(5),
+                bgzf::VirtualPosition::from(34),
+            ],
+            None,
+        );
+
+        let index = Index::builder()
+            .set_reference_sequence_names([String::from("sq0")].into_iter().collect())
+            .set_reference_sequences(vec![reference_sequence])
+            .build();
+
+        let start = Position::try_from(8)?;
+        let end = Position::try_from(13)?;
+
+        // The chunk in bin 4681 that ends before the linear index offset of the first window (5)
+        // is skipped, and the remaining overlapping chunks are merged.
+        assert_eq!(index.query(0, start..=end)?, [chunk(5, 21)]);
+
+        let start = Position::try_from(16385)?;
+        assert_eq!(index.query(0, start..)?, [chunk(34, 55)]);
+
+        assert!(matches!(
+            index.query(1, start..),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
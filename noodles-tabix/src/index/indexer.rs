@@ -1,7 +1,14 @@
+use std::io;
+
 use noodles_core::Position;
 use noodles_csi::index::reference_sequence::bin::Chunk;
 
-use super::{reference_sequence, Header, Index, ReferenceSequence, ReferenceSequenceNames};
+use super::{
+    header::{format::CoordinateSystem, Format},
+    reference_sequence, Header, Index, ReferenceSequence, ReferenceSequenceNames,
+};
+
+const FIELD_DELIMITER: char = '\t';
 
 /// A tabix indexer.
 #[derive(Debug, Default)]
@@ -71,6 +78,39 @@ impl Indexer {
         reference_sequence_builder.add_record(start, end, chunk);
     }
 
+    /// Adds a record from a tab-delimited line.
+    ///
+    /// The reference sequence name, start position, and end position are read from the columns
+    /// defined in the index header, and positions are converted from the coordinate system of the
+    /// header format. When the header has no end position column, the end position is the start
+    /// position, or, for VCF and SAM, is calculated from the length of the reference bases or
+    /// CIGAR, respectively.
+    ///
+    /// The line must not include the line terminator. Comments, i.e., lines that start with the
+    /// line comment prefix, and empty lines are expected to be filtered by the caller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi::index::reference_sequence::bin::Chunk;
+    /// use noodles_tabix as tabix;
+    ///
+    /// let mut indexer = tabix::Index::indexer();
+    /// indexer.set_header(tabix::index::header::Builder::bed().build());
+    ///
+    /// indexer.add_line("sq0\t7\t13", Chunk::new(
+    ///     bgzf::VirtualPosition::from(144),
+    ///     bgzf::VirtualPosition::from(233),
+    /// ))?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn add_line(&mut self, line: &str, chunk: Chunk) -> io::Result<()> {
+        let (reference_sequence_name, start, end) = parse_line(&self.header, line)?;
+        self.add_record(reference_sequence_name, start, end, chunk);
+        Ok(())
+    }
+
     /// Builds a tabix index.
     ///
     /// # Examples
@@ -94,3 +134,202 @@ impl Indexer {
             .build()
     }
 }
+
+fn parse_line<'a>(header: &Header, line: &'a str) -> io::Result<(&'a str, Position, Position)> {
+    let fields: Vec<_> = line.split(FIELD_DELIMITER).collect();
+
+    let get_field = |i: usize| {
+        i.checked_sub(1)
+            .and_then(|j| fields.get(j))
+            .copied()
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("missing field at column {}", i),
+                )
+            })
+    };
+
+    let parse_position = |s: &str| {
+        s.parse::<usize>()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    };
+
+    let invalid_position = || io::Error::new(io::ErrorKind::InvalidData, "invalid position");
+
+    let reference_sequence_name = get_field(header.reference_sequence_name_index())?;
+
+    let raw_start = get_field(header.start_position_index()).and_then(parse_position)?;
+
+    let start = match header.format().coordinate_system() {
+        CoordinateSystem::Gff => Some(raw_start),
+        CoordinateSystem::Bed => raw_start.checked_add(1),
+    };
+
+    let start = start.and_then(Position::new).ok_or_else(invalid_position)?;
+
+    let end = match header.end_position_index() {
+        Some(i) => get_field(i).and_then(parse_position)?,
+        None => match header.format() {
+            Format::Vcf => {
+                let reference_bases = get_field(header.start_position_index() + 2)?;
+                usize::from(start)
+                    .checked_add(reference_bases.len().max(1) - 1)
+                    .ok_or_else(invalid_position)?
+            }
+            Format::Sam => {
+                let cigar = get_field(header.start_position_index() + 2)?;
+                let len = reference_len(cigar)?;
+
+                usize::from(start)
+                    .checked_add(len.max(1) - 1)
+                    .ok_or_else(invalid_position)?
+            }
+            Format::Generic(_) => usize::from(start),
+        },
+    };
+
+    let end = Position::new(end)
+        .filter(|&end| end >= start)
+        .ok_or_else(invalid_position)?;
+
+    Ok((reference_sequence_name, start, end))
+}
+
+fn reference_len(cigar: &str) -> io::Result<usize> {
+    if cigar == "*" {
+        return Ok(0);
+    }
+
+    let mut len: usize = 0;
+    let mut n: usize = 0;
+
+    for c in cigar.chars() {
+        match c {
+            '0'..='9' => {
+                let d = c.to_digit(10).map(|d| d as usize).unwrap_or_default();
+
+                n = n
+                    .checked_mul(10)
+                    .and_then(|m| m.checked_add(d))
+                    .ok_or_else(invalid_cigar_op_length)?;
+            }
+            'M' | 'D' | 'N' | '=' | 'X' => {
+                len = len.checked_add(n).ok_or_else(invalid_cigar_op_length)?;
+                n = 0;
+            }
+            'I' | 'S' | 'H' | 'P' => n = 0,
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid CIGAR op: {}", c),
+                ))
+            }
+        }
+    }
+
+    Ok(len)
+}
+
+fn invalid_cigar_op_length() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "invalid CIGAR op length")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::index::header;
+
+    #[test]
+    fn test_parse_line() -> Result<(), Box<dyn std::error::Error>> {
+        let header = header::Builder::bed().build();
+        assert_eq!(
+            parse_line(&header, "sq0\t7\t13\tndls")?,
+            ("sq0", Position::try_from(8)?, Position::try_from(13)?)
+        );
+
+        let header = header::Builder::gff().build();
+        assert_eq!(
+            parse_line(&header, "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0")?,
+            ("sq0", Position::try_from(8)?, Position::try_from(13)?)
+        );
+
+        let header = header::Builder::vcf().build();
+        assert_eq!(
+            parse_line(&header, "sq0\t8\t.\tACGT\tA\t.\tPASS\t.")?,
+            ("sq0", Position::try_from(8)?, Position::try_from(11)?)
+        );
+
+        let header = header::Builder::sam().build();
+        assert_eq!(
+            parse_line(
+                &header,
+                "r0\t0\tsq0\t8\t60\t2S4M1I2D\t*\t0\t0\tACGTACG\tNDLSNDL"
+            )?,
+            ("sq0", Position::try_from(8)?, Position::try_from(13)?)
+        );
+
+        let header = header::Builder::bed().build();
+        assert!(matches!(
+            parse_line(&header, "sq0\t7"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert!(matches!(
+            parse_line(&header, "sq0\tndls\t13"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert!(matches!(
+            parse_line(&header, "sq0\t13\t8"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let header = header::Builder::bed().build();
+        let line = format!("sq0\t{}\t{}", usize::MAX, usize::MAX);
+        assert!(matches!(
+            parse_line(&header, &line),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let header = header::Builder::vcf().build();
+        let line = format!("sq0\t{}\t.\tACGT\tA\t.\tPASS\t.", usize::MAX - 1);
+        assert!(matches!(
+            parse_line(&header, &line),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let header = header::Builder::sam().build();
+        assert!(matches!(
+            parse_line(
+                &header,
+                "r0\t0\tsq0\t8\t60\t99999999999999999999999M\t*\t0\t0\t*\t*"
+            ),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reference_len() -> io::Result<()> {
+        assert_eq!(reference_len("*")?, 0);
+        assert_eq!(reference_len("2S4M1I2D3N")?, 9);
+
+        let max_len = usize::MAX.to_string();
+        assert_eq!(reference_len(&format!("{}M", max_len))?, usize::MAX);
+
+        assert!(matches!(
+            reference_len(&format!("{}0M", max_len)),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert!(matches!(
+            reference_len(&format!("{}M1D", max_len)),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert!(matches!(
+            reference_len("4Z"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -37,7 +37,14 @@ pub use self::{index::Index, reader::Reader, writer::Writer};
 #[cfg(feature = "async")]
 pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
 
-use std::{fs::File, io, path::Path};
+use std::{
+    fs::File,
+    io::{self, BufRead, Read},
+    path::Path,
+};
+
+use noodles_bgzf as bgzf;
+use noodles_csi::index::reference_sequence::bin::Chunk;
 
 static MAGIC_NUMBER: &[u8] = b"TBI\x01";
 
@@ -84,3 +91,161 @@ where
     writer.write_index(index)?;
     Ok(())
 }
+
+/// Indexes a bgzipped tab-delimited file.
+///
+/// This is a convenience function and is equivalent to opening the file at the given path as a
+/// BGZF reader and calling [`index_reader`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// use noodles_tabix as tabix;
+/// let header = tabix::index::header::Builder::bed().build();
+/// let index = tabix::index("sample.bed.gz", header)?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn index<P>(src: P, header: index::Header) -> io::Result<Index>
+where
+    P: AsRef<Path>,
+{
+    let mut reader = File::open(src).map(bgzf::Reader::new)?;
+    index_reader(&mut reader, header)
+}
+
+/// Indexes lines from a BGZF reader.
+///
+/// The index header describes the layout of the tab-delimited lines: the columns of the reference
+/// sequence name and the start and end positions, the coordinate system, the line comment prefix,
+/// and the number of leading lines to skip. Comments and empty lines are not indexed.
+///
+/// Lines are expected to be grouped by reference sequence name and sorted by start position.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Write};
+/// use noodles_bgzf as bgzf;
+/// use noodles_tabix as tabix;
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"#chrom\tstart\tend\nsq0\t7\t13\n")?;
+/// let data = writer.finish()?;
+///
+/// let mut reader = bgzf::Reader::new(&data[..]);
+/// let header = tabix::index::header::Builder::bed().build();
+/// let index = tabix::index_reader(&mut reader, header)?;
+///
+/// assert_eq!(index.reference_sequence_names().len(), 1);
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn index_reader<R>(reader: &mut bgzf::Reader<R>, header: index::Header) -> io::Result<Index>
+where
+    R: Read,
+{
+    let line_comment_prefix = char::from(header.line_comment_prefix());
+    let mut line_skip_count = header.line_skip_count();
+
+    let mut indexer = Index::indexer();
+    indexer.set_header(header);
+
+    let mut buf = String::new();
+    let mut start_position = reader.virtual_position();
+
+    loop {
+        buf.clear();
+
+        if reader.read_line(&mut buf)? == 0 {
+            break;
+        }
+
+        let end_position = reader.virtual_position();
+        let line = buf.trim_end_matches(&['\n', '\r'][..]);
+
+        if line_skip_count > 0 {
+            line_skip_count -= 1;
+        } else if !line.is_empty() && !line.starts_with(line_comment_prefix) {
+            let chunk = Chunk::new(start_position, end_position);
+            indexer.add_line(line, chunk)?;
+        }
+
+        start_position = end_position;
+    }
+
+    Ok(indexer.build())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use noodles_core::Position;
+    use noodles_csi::{binning_index::ReferenceSequenceExt, BinningIndex};
+
+    use super::*;
+
+    #[test]
+    fn test_index_reader() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"#chrom\tstart\tend\nsq0\t7\t13\nsq0\t121392\t196418\nsq1\t20\t34\n")?;
+        let data = writer.finish()?;
+
+        let mut reader = bgzf::Reader::new(&data[..]);
+        let header = index::header::Builder::bed().build();
+        let index = index_reader(&mut reader, header.clone())?;
+
+        assert_eq!(index.header(), &header);
+
+        let reference_sequence_names: Vec<_> = index.reference_sequence_names().iter().collect();
+        assert_eq!(reference_sequence_names, ["sq0", "sq1"]);
+
+        let reference_sequences = index.reference_sequences();
+        assert_eq!(reference_sequences.len(), 2);
+
+        let mut bin_ids: Vec<_> = reference_sequences[0]
+            .bins()
+            .iter()
+            .map(|bin| bin.id())
+            .collect();
+        bin_ids.sort_unstable();
+        assert_eq!(bin_ids, [73, 4681]);
+        assert_eq!(reference_sequences[0].intervals().len(), 12);
+        assert_eq!(
+            reference_sequences[0]
+                .metadata()
+                .map(|metadata| metadata.mapped_record_count()),
+            Some(2)
+        );
+
+        let bin_ids: Vec<_> = reference_sequences[1]
+            .bins()
+            .iter()
+            .map(|bin| bin.id())
+            .collect();
+        assert_eq!(bin_ids, [4681]);
+
+        let start = Position::try_from(8)?;
+        let end = Position::try_from(13)?;
+        let chunks = index.query(0, start..=end)?;
+        assert_eq!(
+            chunks,
+            [Chunk::new(
+                bgzf::VirtualPosition::from(17),
+                bgzf::VirtualPosition::from(44)
+            )]
+        );
+
+        let start = Position::try_from(150000)?;
+        let chunks = index.query(0, start..)?;
+        assert_eq!(
+            chunks,
+            [Chunk::new(
+                bgzf::VirtualPosition::from(26),
+                bgzf::VirtualPosition::from(44)
+            )]
+        );
+
+        Ok(())
+    }
+}
@@ -1,7 +1,23 @@
 //! I/O for alignment formats.
 
+mod downsample;
+mod flagstat;
 mod format;
+mod idxstats;
+mod pairs;
+pub mod pileup;
+mod read_header;
 mod reader;
 mod writer;
 
-pub use self::{format::Format, reader::Reader, writer::Writer};
+pub use self::{
+    downsample::Downsample,
+    flagstat::{flagstat, FlagStats},
+    format::Format,
+    idxstats::idxstats,
+    pairs::{Pair, Pairs},
+    pileup::Pileup,
+    read_header::read_header,
+    reader::Reader,
+    writer::Writer,
+};
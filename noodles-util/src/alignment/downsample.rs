@@ -0,0 +1,189 @@
+use std::io;
+
+use noodles_sam::AlignmentRecord;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x00000100000001b3;
+
+/// An iterator that deterministically keeps a fraction of alignment records.
+///
+/// A record is kept if the hash of its read name and the seed falls below the fraction. The
+/// decision only depends on the read name, so all records of a template, e.g., both mates of a
+/// pair, are either kept or discarded together. The same fraction and seed select the same records
+/// across runs.
+///
+/// Records without a read name are hashed as if the read name were empty.
+pub struct Downsample<I> {
+    records: I,
+    fraction: f64,
+    seed: u64,
+}
+
+impl<I, R> Downsample<I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: AlignmentRecord,
+{
+    /// Creates an iterator that keeps the given fraction of alignment records.
+    ///
+    /// A fraction less than or equal to 0 discards all records, and a fraction greater than or
+    /// equal to 1 keeps all records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_util::alignment::{self, Downsample};
+    ///
+    /// let data = Cursor::new(b"@HD\tVN:1.6
+    /// r0\t77\t*\t0\t255\t*\t*\t0\t0\t*\t*
+    /// r0\t141\t*\t0\t255\t*\t*\t0\t0\t*\t*
+    /// ");
+    ///
+    /// let mut reader = alignment::Reader::builder(data).build()?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let records: Vec<_> = Downsample::new(reader.records(&header), 1.0, 0)
+    ///     .collect::<io::Result<_>>()?;
+    ///
+    /// assert_eq!(records.len(), 2);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn new(records: I, fraction: f64, seed: u64) -> Self {
+        Self {
+            records,
+            fraction,
+            seed,
+        }
+    }
+}
+
+impl<I, R> Iterator for Downsample<I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: AlignmentRecord,
+{
+    type Item = io::Result<R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.records.next()? {
+                Ok(record) => {
+                    let read_name: &[u8] = record
+                        .read_name()
+                        .map(|name| name.as_ref())
+                        .unwrap_or_default();
+
+                    if is_selected(read_name, self.fraction, self.seed) {
+                        return Some(Ok(record));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+fn is_selected(read_name: &[u8], fraction: f64, seed: u64) -> bool {
+    if fraction >= 1.0 {
+        return true;
+    }
+
+    // Uses the upper 53 bits of the hash to build a uniform value in [0, 1).
+    let n = (hash(read_name, seed) >> 11) as f64 / (1u64 << 53) as f64;
+    n < fraction
+}
+
+// FNV-1a of the seed and read name with a final avalanche step (splitmix64). This is stable across
+// platforms and runs, unlike the default hasher of the standard library.
+fn hash(read_name: &[u8], seed: u64) -> u64 {
+    let mut h = FNV_OFFSET_BASIS;
+
+    for &b in seed.to_le_bytes().iter().chain(read_name) {
+        h ^= u64::from(b);
+        h = h.wrapping_mul(FNV_PRIME);
+    }
+
+    h ^= h >> 30;
+    h = h.wrapping_mul(0xbf58476d1ce4e5b9);
+    h ^= h >> 27;
+    h = h.wrapping_mul(0x94d049bb133111eb);
+    h ^ (h >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam as sam;
+
+    use super::*;
+
+    fn build_records() -> Vec<io::Result<sam::Record>> {
+        let mut data = String::new();
+
+        for i in 0..16 {
+            data.push_str(&format!("r{}\t77\t*\t0\t255\t*\t*\t0\t0\t*\t*\n", i));
+            data.push_str(&format!("r{}\t141\t*\t0\t255\t*\t*\t0\t0\t*\t*\n", i));
+        }
+
+        data.lines()
+            .map(|line| {
+                line.parse::<sam::Record>()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            })
+            .collect()
+    }
+
+    fn read_names(records: Vec<sam::Record>) -> Vec<String> {
+        records
+            .iter()
+            .filter_map(|record| record.read_name().map(|name| name.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        let records: Vec<_> =
+            Downsample::new(build_records().into_iter(), 0.5, 0).collect::<io::Result<_>>()?;
+        let actual = read_names(records);
+
+        let expected = [
+            "r1", "r1", "r2", "r2", "r3", "r3", "r12", "r12", "r14", "r14", "r15", "r15",
+        ];
+        assert_eq!(actual, expected);
+
+        let records: Vec<_> =
+            Downsample::new(build_records().into_iter(), 0.5, 0).collect::<io::Result<_>>()?;
+        assert_eq!(read_names(records), actual);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_keeps_mates_together() -> io::Result<()> {
+        for seed in 0..8 {
+            let records: Vec<_> = Downsample::new(build_records().into_iter(), 0.25, seed)
+                .collect::<io::Result<_>>()?;
+            let names = read_names(records);
+
+            for pair in names.chunks(2) {
+                assert_eq!(pair.len(), 2);
+                assert_eq!(pair[0], pair[1]);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_fraction_bounds() -> io::Result<()> {
+        let records: Vec<_> =
+            Downsample::new(build_records().into_iter(), 0.0, 0).collect::<io::Result<_>>()?;
+        assert!(records.is_empty());
+
+        let records: Vec<_> =
+            Downsample::new(build_records().into_iter(), 1.0, 0).collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 32);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,255 @@
+use std::io;
+
+use noodles_sam::{record::Flags, AlignmentRecord};
+
+/// Alignment record counts by flags.
+///
+/// Counts with a `primary_` prefix only include primary records, i.e., records that are neither
+/// secondary nor supplementary. Pairing counts only include primary records.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FlagStats {
+    total: u64,
+    qc_failed: u64,
+    primary: u64,
+    secondary: u64,
+    supplementary: u64,
+    duplicate: u64,
+    primary_duplicate: u64,
+    mapped: u64,
+    primary_mapped: u64,
+    paired: u64,
+    read_1: u64,
+    read_2: u64,
+    proper_pair: u64,
+    mate_mapped: u64,
+    singleton: u64,
+}
+
+impl FlagStats {
+    /// Returns the number of records.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns the number of records that failed quality checks.
+    pub fn qc_failed(&self) -> u64 {
+        self.qc_failed
+    }
+
+    /// Returns the number of primary records.
+    pub fn primary(&self) -> u64 {
+        self.primary
+    }
+
+    /// Returns the number of secondary records.
+    pub fn secondary(&self) -> u64 {
+        self.secondary
+    }
+
+    /// Returns the number of supplementary records.
+    pub fn supplementary(&self) -> u64 {
+        self.supplementary
+    }
+
+    /// Returns the number of duplicate records.
+    pub fn duplicate(&self) -> u64 {
+        self.duplicate
+    }
+
+    /// Returns the number of primary duplicate records.
+    pub fn primary_duplicate(&self) -> u64 {
+        self.primary_duplicate
+    }
+
+    /// Returns the number of mapped records.
+    pub fn mapped(&self) -> u64 {
+        self.mapped
+    }
+
+    /// Returns the number of primary mapped records.
+    pub fn primary_mapped(&self) -> u64 {
+        self.primary_mapped
+    }
+
+    /// Returns the number of primary records that are paired in sequencing.
+    pub fn paired(&self) -> u64 {
+        self.paired
+    }
+
+    /// Returns the number of primary paired records that are the first segment.
+    pub fn read_1(&self) -> u64 {
+        self.read_1
+    }
+
+    /// Returns the number of primary paired records that are the last segment.
+    pub fn read_2(&self) -> u64 {
+        self.read_2
+    }
+
+    /// Returns the number of primary mapped paired records that are properly aligned.
+    pub fn proper_pair(&self) -> u64 {
+        self.proper_pair
+    }
+
+    /// Returns the number of primary mapped paired records with a mapped mate.
+    pub fn mate_mapped(&self) -> u64 {
+        self.mate_mapped
+    }
+
+    /// Returns the number of primary mapped paired records with an unmapped mate.
+    pub fn singleton(&self) -> u64 {
+        self.singleton
+    }
+
+    fn add(&mut self, flags: Flags) {
+        self.total += 1;
+
+        if flags.is_qc_fail() {
+            self.qc_failed += 1;
+        }
+
+        if flags.is_duplicate() {
+            self.duplicate += 1;
+        }
+
+        if !flags.is_unmapped() {
+            self.mapped += 1;
+        }
+
+        if flags.is_secondary() {
+            self.secondary += 1;
+            return;
+        } else if flags.is_supplementary() {
+            self.supplementary += 1;
+            return;
+        }
+
+        self.primary += 1;
+
+        if flags.is_duplicate() {
+            self.primary_duplicate += 1;
+        }
+
+        if !flags.is_unmapped() {
+            self.primary_mapped += 1;
+        }
+
+        if !flags.is_segmented() {
+            return;
+        }
+
+        self.paired += 1;
+
+        if flags.is_first_segment() {
+            self.read_1 += 1;
+        }
+
+        if flags.is_last_segment() {
+            self.read_2 += 1;
+        }
+
+        if flags.is_unmapped() {
+            return;
+        }
+
+        if flags.is_properly_aligned() {
+            self.proper_pair += 1;
+        }
+
+        if flags.is_mate_unmapped() {
+            self.singleton += 1;
+        } else {
+            self.mate_mapped += 1;
+        }
+    }
+}
+
+/// Counts alignment records by their flags.
+///
+/// This is similar to the output of `samtools flagstat`, but QC-passed and QC-failed records are
+/// not counted separately.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Cursor};
+/// use noodles_util::alignment;
+///
+/// let data = Cursor::new(b"@HD\tVN:1.6
+/// r0\t77\t*\t0\t255\t*\t*\t0\t0\t*\t*
+/// r0\t141\t*\t0\t255\t*\t*\t0\t0\t*\t*
+/// ");
+///
+/// let mut reader = alignment::Reader::builder(data).build()?;
+/// let header = reader.read_header()?;
+///
+/// let flag_stats = alignment::flagstat(reader.records(&header))?;
+/// assert_eq!(flag_stats.total(), 2);
+/// assert_eq!(flag_stats.mapped(), 0);
+/// assert_eq!(flag_stats.paired(), 2);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn flagstat<I, R>(records: I) -> io::Result<FlagStats>
+where
+    I: IntoIterator<Item = io::Result<R>>,
+    R: AlignmentRecord,
+{
+    let mut flag_stats = FlagStats::default();
+
+    for result in records {
+        let record = result?;
+        flag_stats.add(record.flags());
+    }
+
+    Ok(flag_stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam as sam;
+
+    use super::*;
+
+    #[test]
+    fn test_flagstat() -> Result<(), Box<dyn std::error::Error>> {
+        let data = "\
+r0\t99\tsq0\t1\t60\t4M\t=\t5\t8\tACGT\tNDLS
+r0\t147\tsq0\t5\t60\t4M\t=\t1\t-8\tACGT\tNDLS
+r1\t73\tsq0\t1\t60\t4M\t=\t1\t0\tACGT\tNDLS
+r1\t133\tsq0\t1\t255\t*\t=\t1\t0\tACGT\tNDLS
+r2\t1024\tsq0\t1\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r2\t256\tsq0\t9\t0\t4M\t*\t0\t0\tACGT\tNDLS
+r2\t2048\tsq0\t9\t0\t4H\t*\t0\t0\t*\t*
+r3\t516\t*\t0\t255\t*\t*\t0\t0\tACGT\tNDLS
+";
+
+        let records = data.lines().map(|line| {
+            line.parse::<sam::Record>()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        });
+
+        let actual = flagstat(records)?;
+
+        let expected = FlagStats {
+            total: 8,
+            qc_failed: 1,
+            primary: 6,
+            secondary: 1,
+            supplementary: 1,
+            duplicate: 1,
+            primary_duplicate: 1,
+            mapped: 6,
+            primary_mapped: 4,
+            paired: 4,
+            read_1: 2,
+            read_2: 2,
+            proper_pair: 2,
+            mate_mapped: 2,
+            singleton: 1,
+        };
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
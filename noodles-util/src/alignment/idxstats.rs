@@ -0,0 +1,168 @@
+use std::io;
+
+use noodles_csi::{binning_index::ReferenceSequenceExt, BinningIndex};
+use noodles_sam as sam;
+
+const UNPLACED_REFERENCE_SEQUENCE_NAME: &str = "*";
+
+/// Returns the number of mapped and unmapped records of each reference sequence from an index.
+///
+/// This is similar to the output of `samtools idxstats`. Each tuple is the reference sequence
+/// name, reference sequence length, and the number of mapped and unmapped records, as stored in
+/// the index reference sequence metadata. No records are read.
+///
+/// Reference sequences without metadata have counts of 0. The last tuple is named `*` and has the
+/// number of unplaced, unmapped records.
+///
+/// This returns an error if the number of reference sequences in the header and index differ.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bam::bai;
+/// use noodles_sam::{self as sam, header::ReferenceSequence};
+/// use noodles_util::alignment;
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+///     .build();
+///
+/// let index = bai::Index::new(
+///     vec![bai::index::ReferenceSequence::new(Vec::new(), Vec::new(), None)],
+///     Some(2),
+/// );
+///
+/// let stats = alignment::idxstats(&header, &index)?;
+/// assert_eq!(stats, [("sq0", 8, 0, 0), ("*", 0, 0, 2)]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn idxstats<'h, I, RS>(
+    header: &'h sam::Header,
+    index: &I,
+) -> io::Result<Vec<(&'h str, i32, u64, u64)>>
+where
+    I: BinningIndex<RS>,
+    RS: ReferenceSequenceExt,
+{
+    let header_reference_sequences = header.reference_sequences();
+    let index_reference_sequences = index.reference_sequences();
+
+    if header_reference_sequences.len() != index_reference_sequences.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "reference sequence count mismatch: expected {}, got {}",
+                header_reference_sequences.len(),
+                index_reference_sequences.len()
+            ),
+        ));
+    }
+
+    let mut stats: Vec<_> = header_reference_sequences
+        .iter()
+        .zip(index_reference_sequences)
+        .map(|((name, reference_sequence), index_reference_sequence)| {
+            let (mapped_record_count, unmapped_record_count) = index_reference_sequence
+                .metadata()
+                .map(|metadata| {
+                    (
+                        metadata.mapped_record_count(),
+                        metadata.unmapped_record_count(),
+                    )
+                })
+                .unwrap_or_default();
+
+            (
+                name.as_str(),
+                reference_sequence.len(),
+                mapped_record_count,
+                unmapped_record_count,
+            )
+        })
+        .collect();
+
+    stats.push((
+        UNPLACED_REFERENCE_SEQUENCE_NAME,
+        0,
+        0,
+        index.unplaced_unmapped_record_count().unwrap_or_default(),
+    ));
+
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_bam::bai;
+    use noodles_bgzf as bgzf;
+    use noodles_csi::index::reference_sequence::Metadata;
+    use noodles_sam::header::ReferenceSequence;
+
+    use super::*;
+
+    #[test]
+    fn test_idxstats() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+            .add_reference_sequence(ReferenceSequence::new("sq1".parse()?, 13)?)
+            .add_reference_sequence(ReferenceSequence::new("sq2".parse()?, 21)?)
+            .build();
+
+        let index = bai::Index::new(
+            vec![
+                bai::index::ReferenceSequence::new(
+                    Vec::new(),
+                    Vec::new(),
+                    Some(Metadata::new(
+                        bgzf::VirtualPosition::from(610),
+                        bgzf::VirtualPosition::from(1597),
+                        55,
+                        3,
+                    )),
+                ),
+                bai::index::ReferenceSequence::new(Vec::new(), Vec::new(), None),
+                bai::index::ReferenceSequence::new(
+                    Vec::new(),
+                    Vec::new(),
+                    Some(Metadata::new(
+                        bgzf::VirtualPosition::from(2584),
+                        bgzf::VirtualPosition::from(4181),
+                        89,
+                        0,
+                    )),
+                ),
+            ],
+            Some(5),
+        );
+
+        let actual = idxstats(&header, &index)?;
+
+        let expected = [
+            ("sq0", 8, 55, 3),
+            ("sq1", 13, 0, 0),
+            ("sq2", 21, 89, 0),
+            ("*", 0, 0, 5),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_idxstats_with_reference_sequence_count_mismatch(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+            .build();
+
+        let index = bai::Index::new(Vec::new(), None);
+
+        assert!(matches!(
+            idxstats(&header, &index),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
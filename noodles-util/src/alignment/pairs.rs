@@ -0,0 +1,169 @@
+use std::io;
+
+use noodles_sam::AlignmentRecord;
+
+/// A primary alignment record and, if present, its mate.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Pair<R> {
+    /// A read and its mate, ordered as the first and last segments.
+    Paired(R, R),
+    /// A read with no mate in the input.
+    Unpaired(R),
+}
+
+/// An iterator that joins mates of name-sorted alignment records.
+///
+/// Records with the same read name that are adjacent in the input are yielded as a
+/// [`Pair::Paired`]. Reads that are not segmented or whose mates are not adjacent are yielded as a
+/// [`Pair::Unpaired`].
+///
+/// Secondary and supplementary records are skipped.
+#[derive(Debug)]
+pub struct Pairs<I, R> {
+    records: I,
+    pending_record: Option<R>,
+}
+
+impl<I, R> Pairs<I, R>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: AlignmentRecord,
+{
+    /// Creates an iterator that joins mates of name-sorted alignment records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_util::alignment::{self, Pair, Pairs};
+    ///
+    /// let data = Cursor::new(b"@HD\tVN:1.6\tSO:queryname
+    /// r0\t77\t*\t0\t255\t*\t*\t0\t0\t*\t*
+    /// r0\t141\t*\t0\t255\t*\t*\t0\t0\t*\t*
+    /// ");
+    ///
+    /// let mut reader = alignment::Reader::builder(data).build()?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let mut pairs = Pairs::new(reader.records(&header));
+    /// assert!(matches!(pairs.next().transpose()?, Some(Pair::Paired(_, _))));
+    /// assert!(pairs.next().is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn new(records: I) -> Self {
+        Self {
+            records,
+            pending_record: None,
+        }
+    }
+
+    fn next_primary_record(&mut self) -> Option<io::Result<R>> {
+        if let Some(record) = self.pending_record.take() {
+            return Some(Ok(record));
+        }
+
+        loop {
+            match self.records.next()? {
+                Ok(record) => {
+                    let flags = record.flags();
+
+                    if !flags.is_secondary() && !flags.is_supplementary() {
+                        return Some(Ok(record));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+impl<I, R> Iterator for Pairs<I, R>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: AlignmentRecord,
+{
+    type Item = io::Result<Pair<R>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let record = match self.next_primary_record()? {
+            Ok(record) => record,
+            Err(e) => return Some(Err(e)),
+        };
+
+        if !record.flags().is_segmented() {
+            return Some(Ok(Pair::Unpaired(record)));
+        }
+
+        let mate = match self.next_primary_record() {
+            Some(Ok(mate)) => mate,
+            Some(Err(e)) => return Some(Err(e)),
+            None => return Some(Ok(Pair::Unpaired(record))),
+        };
+
+        if !is_mate(&record, &mate) {
+            self.pending_record = Some(mate);
+            return Some(Ok(Pair::Unpaired(record)));
+        }
+
+        if record.flags().is_last_segment() && !mate.flags().is_last_segment() {
+            Some(Ok(Pair::Paired(mate, record)))
+        } else {
+            Some(Ok(Pair::Paired(record, mate)))
+        }
+    }
+}
+
+fn is_mate<R>(a: &R, b: &R) -> bool
+where
+    R: AlignmentRecord,
+{
+    b.flags().is_segmented() && a.read_name().is_some() && a.read_name() == b.read_name()
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam as sam;
+
+    use super::*;
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let data = "\
+r0\t77\t*\t0\t255\t*\t*\t0\t0\t*\t*
+r0\t141\t*\t0\t255\t*\t*\t0\t0\t*\t*
+r1\t141\t*\t0\t255\t*\t*\t0\t0\t*\t*
+r1\t333\t*\t0\t255\t*\t*\t0\t0\t*\t*
+r1\t77\t*\t0\t255\t*\t*\t0\t0\t*\t*
+r2\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*
+r3\t77\t*\t0\t255\t*\t*\t0\t0\t*\t*
+r4\t2125\t*\t0\t255\t*\t*\t0\t0\t*\t*
+";
+
+        let records = data.lines().map(|line| {
+            line.parse::<sam::Record>()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        });
+
+        let actual: Vec<_> = Pairs::new(records)
+            .map(|result| {
+                result.map(|pair| match pair {
+                    Pair::Paired(read_1, read_2) => (read_1.to_string(), Some(read_2.to_string())),
+                    Pair::Unpaired(read) => (read.to_string(), None),
+                })
+            })
+            .collect::<io::Result<_>>()?;
+
+        let lines: Vec<_> = data.lines().map(String::from).collect();
+
+        let expected = [
+            (lines[0].clone(), Some(lines[1].clone())),
+            (lines[4].clone(), Some(lines[2].clone())),
+            (lines[5].clone(), None),
+            (lines[6].clone(), None),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
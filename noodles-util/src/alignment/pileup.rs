@@ -0,0 +1,460 @@
+//! Alignment pileup.
+
+pub mod consensus;
+
+use std::{collections::VecDeque, io, rc::Rc};
+
+use noodles_core::Position;
+use noodles_sam::{
+    self as sam,
+    record::{cigar::op::Kind, quality_scores::Score, sequence::Base},
+    AlignmentRecord,
+};
+
+/// An observation of a read at a reference sequence position.
+#[derive(Debug)]
+pub struct Observation<R> {
+    record: Rc<R>,
+    read_position: Option<usize>,
+}
+
+impl<R> Observation<R>
+where
+    R: AlignmentRecord,
+{
+    /// Returns the alignment record of the observation.
+    pub fn record(&self) -> &R {
+        &self.record
+    }
+
+    /// Returns whether the read has a deletion at the position.
+    pub fn is_deletion(&self) -> bool {
+        self.read_position.is_none()
+    }
+
+    /// Returns the read base at the position.
+    ///
+    /// This is `None` if the read has a deletion at the position or has no sequence.
+    pub fn base(&self) -> Option<Base> {
+        self.read_position
+            .and_then(|i| self.record.sequence().as_ref().get(i).copied())
+    }
+
+    /// Returns the read base quality score at the position.
+    ///
+    /// This is `None` if the read has a deletion at the position or has no quality scores.
+    pub fn quality_score(&self) -> Option<Score> {
+        self.read_position
+            .and_then(|i| self.record.quality_scores().as_ref().get(i).copied())
+    }
+}
+
+/// A pileup column, i.e., the observations of all reads at a reference sequence position.
+#[derive(Debug)]
+pub struct Column<R> {
+    reference_sequence_id: usize,
+    position: Position,
+    observations: Vec<Observation<R>>,
+}
+
+impl<R> Column<R> {
+    /// Returns the reference sequence ID of the column.
+    pub fn reference_sequence_id(&self) -> usize {
+        self.reference_sequence_id
+    }
+
+    /// Returns the reference sequence position of the column.
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Returns the observations of the reads that overlap the position.
+    pub fn observations(&self) -> &[Observation<R>] {
+        &self.observations
+    }
+}
+
+#[derive(Debug)]
+struct ActiveRecord<R> {
+    record: Rc<R>,
+    // The aligned reference positions and read positions. A missing read position is a deletion.
+    positions: VecDeque<(Position, Option<usize>)>,
+}
+
+#[derive(Debug)]
+struct PendingRecord<R> {
+    reference_sequence_id: usize,
+    alignment_start: Position,
+    record: R,
+}
+
+/// An iterator over pileup columns of coordinate-sorted alignment records.
+///
+/// A column is yielded for each reference sequence position that is covered by at least one read.
+/// Reads with a deletion at a position are included as observations without a base. Insertions do
+/// not consume the reference sequence and are not observed. Reference skips and soft clips are
+/// not observed.
+///
+/// Unmapped records are skipped.
+#[derive(Debug)]
+pub struct Pileup<'h, I, R> {
+    records: I,
+    header: &'h sam::Header,
+    pending_record: Option<PendingRecord<R>>,
+    is_eof: bool,
+    reference_sequence_id: usize,
+    active_records: Vec<ActiveRecord<R>>,
+}
+
+impl<'h, I, R> Pileup<'h, I, R>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: AlignmentRecord,
+{
+    /// Creates a pileup iterator over coordinate-sorted alignment records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_util::alignment::{self, Pileup};
+    ///
+    /// let data = Cursor::new(b"@HD\tVN:1.6\tSO:coordinate
+    /// @SQ\tSN:sq0\tLN:8
+    /// r0\t0\tsq0\t1\t60\t2M\t*\t0\t0\tAC\tNN
+    /// r1\t0\tsq0\t2\t60\t2M\t*\t0\t0\tCG\tNN
+    /// ");
+    ///
+    /// let mut reader = alignment::Reader::builder(data).build()?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let depths = Pileup::new(reader.records(&header), &header)
+    ///     .map(|result| result.map(|column| column.observations().len()))
+    ///     .collect::<io::Result<Vec<_>>>()?;
+    ///
+    /// assert_eq!(depths, [1, 2, 1]);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn new(records: I, header: &'h sam::Header) -> Self {
+        Self {
+            records,
+            header,
+            pending_record: None,
+            is_eof: false,
+            reference_sequence_id: 0,
+            active_records: Vec::new(),
+        }
+    }
+
+    fn fill_pending_record(&mut self) -> io::Result<()> {
+        while self.pending_record.is_none() && !self.is_eof {
+            let record = match self.records.next() {
+                Some(result) => result?,
+                None => {
+                    self.is_eof = true;
+                    break;
+                }
+            };
+
+            if record.flags().is_unmapped() {
+                continue;
+            }
+
+            let reference_sequence_id = get_reference_sequence_id(self.header, &record)?;
+
+            let alignment_start = record.alignment_start().ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "missing alignment start")
+            })?;
+
+            self.pending_record = Some(PendingRecord {
+                reference_sequence_id,
+                alignment_start,
+                record,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn next_position(&self) -> Option<Position> {
+        let active_position = self
+            .active_records
+            .iter()
+            .filter_map(|active_record| active_record.positions.front())
+            .map(|(position, _)| *position)
+            .min();
+
+        let pending_position = self
+            .pending_record
+            .as_ref()
+            .filter(|pending_record| {
+                self.active_records.is_empty()
+                    || pending_record.reference_sequence_id == self.reference_sequence_id
+            })
+            .map(|pending_record| pending_record.alignment_start);
+
+        match (active_position, pending_position) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    fn activate_pending_records(&mut self, position: Position) -> io::Result<()> {
+        loop {
+            self.fill_pending_record()?;
+
+            match self.pending_record.as_ref() {
+                Some(pending_record)
+                    if pending_record.reference_sequence_id == self.reference_sequence_id
+                        && pending_record.alignment_start == position => {}
+                Some(pending_record)
+                    if pending_record.reference_sequence_id == self.reference_sequence_id
+                        && pending_record.alignment_start < position =>
+                {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "records are not coordinate-sorted",
+                    ));
+                }
+                _ => return Ok(()),
+            }
+
+            if let Some(pending_record) = self.pending_record.take() {
+                let positions = build_positions(&pending_record.record, position)?;
+
+                self.active_records.push(ActiveRecord {
+                    record: Rc::new(pending_record.record),
+                    positions,
+                });
+            }
+        }
+    }
+
+    fn next_column(&mut self) -> io::Result<Option<Column<R>>> {
+        loop {
+            self.fill_pending_record()?;
+
+            if self.active_records.is_empty() {
+                match self.pending_record.as_ref() {
+                    Some(pending_record) => {
+                        self.reference_sequence_id = pending_record.reference_sequence_id;
+                    }
+                    None => return Ok(None),
+                }
+            }
+
+            let position = match self.next_position() {
+                Some(position) => position,
+                None => return Ok(None),
+            };
+
+            self.activate_pending_records(position)?;
+
+            let mut observations = Vec::new();
+
+            for active_record in &mut self.active_records {
+                if let Some(&(p, read_position)) = active_record.positions.front() {
+                    if p == position {
+                        active_record.positions.pop_front();
+
+                        observations.push(Observation {
+                            record: active_record.record.clone(),
+                            read_position,
+                        });
+                    }
+                }
+            }
+
+            self.active_records
+                .retain(|active_record| !active_record.positions.is_empty());
+
+            if !observations.is_empty() {
+                return Ok(Some(Column {
+                    reference_sequence_id: self.reference_sequence_id,
+                    position,
+                    observations,
+                }));
+            }
+        }
+    }
+}
+
+impl<'h, I, R> Iterator for Pileup<'h, I, R>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: AlignmentRecord,
+{
+    type Item = io::Result<Column<R>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_column().transpose()
+    }
+}
+
+fn get_reference_sequence_id<R>(header: &sam::Header, record: &R) -> io::Result<usize>
+where
+    R: AlignmentRecord,
+{
+    let reference_sequence = record
+        .reference_sequence(header.reference_sequences())
+        .transpose()?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing reference sequence"))?;
+
+    header
+        .reference_sequences()
+        .get_index_of(reference_sequence.name().as_str())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid reference sequence"))
+}
+
+fn build_positions<R>(
+    record: &R,
+    alignment_start: Position,
+) -> io::Result<VecDeque<(Position, Option<usize>)>>
+where
+    R: AlignmentRecord,
+{
+    let mut positions = VecDeque::new();
+
+    let mut reference_position = usize::from(alignment_start);
+    let mut read_position = 0;
+
+    for op in record.cigar().iter() {
+        let len = op.len();
+
+        match op.kind() {
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                for i in 0..len {
+                    let position = to_position(reference_position + i)?;
+                    positions.push_back((position, Some(read_position + i)));
+                }
+
+                reference_position += len;
+                read_position += len;
+            }
+            Kind::Deletion => {
+                for i in 0..len {
+                    let position = to_position(reference_position + i)?;
+                    positions.push_back((position, None));
+                }
+
+                reference_position += len;
+            }
+            Kind::Skip => reference_position += len,
+            Kind::Insertion | Kind::SoftClip => read_position += len,
+            Kind::HardClip | Kind::Pad => {}
+        }
+    }
+
+    Ok(positions)
+}
+
+fn to_position(n: usize) -> io::Result<Position> {
+    Position::new(n).ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid position"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let header: sam::Header = "@HD\tVN:1.6\tSO:coordinate\n@SQ\tSN:sq0\tLN:8\n".parse()?;
+
+        let data = "\
+r0\t0\tsq0\t1\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r1\t0\tsq0\t2\t60\t1M1D2M\t*\t0\t0\tCTA\tNDL
+r2\t0\tsq0\t3\t60\t2M1I1M\t*\t0\t0\tGTAC\tNDLS
+r3\t4\t*\t0\t0\t*\t*\t0\t0\tACGT\tNDLS
+";
+
+        let records = data.lines().map(|line| {
+            line.parse::<sam::Record>()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        });
+
+        let columns: Vec<_> = Pileup::new(records, &header).collect::<io::Result<_>>()?;
+
+        let positions: Vec<_> = columns
+            .iter()
+            .map(|column| usize::from(column.position()))
+            .collect();
+        assert_eq!(positions, [1, 2, 3, 4, 5]);
+
+        let depths: Vec<_> = columns
+            .iter()
+            .map(|column| column.observations().len())
+            .collect();
+        assert_eq!(depths, [1, 2, 3, 3, 2]);
+
+        let column = &columns[2];
+        assert_eq!(column.reference_sequence_id(), 0);
+
+        let observations: Vec<_> = column
+            .observations()
+            .iter()
+            .map(|observation| {
+                (
+                    observation
+                        .record()
+                        .read_name()
+                        .map(|name| name.to_string()),
+                    observation.is_deletion(),
+                    observation.base(),
+                    observation.quality_score(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            observations,
+            [
+                (
+                    Some(String::from("r0")),
+                    false,
+                    Some(Base::G),
+                    Some(Score::try_from('L')?)
+                ),
+                (Some(String::from("r1")), true, None, None),
+                (
+                    Some(String::from("r2")),
+                    false,
+                    Some(Base::G),
+                    Some(Score::try_from('N')?)
+                ),
+            ]
+        );
+
+        let bases: Vec<_> = columns[4]
+            .observations()
+            .iter()
+            .map(|observation| observation.base())
+            .collect();
+        assert_eq!(bases, [Some(Base::A), Some(Base::C)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_unsorted_records() -> Result<(), Box<dyn std::error::Error>> {
+        let header: sam::Header = "@SQ\tSN:sq0\tLN:8\n".parse()?;
+
+        let data = "\
+r0\t0\tsq0\t3\t60\t2M\t*\t0\t0\tGT\tNN
+r1\t0\tsq0\t1\t60\t2M\t*\t0\t0\tAC\tNN
+";
+
+        let records = data.lines().map(|line| {
+            line.parse::<sam::Record>()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        });
+
+        let mut pileup = Pileup::new(records, &header);
+
+        assert!(matches!(
+            pileup.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
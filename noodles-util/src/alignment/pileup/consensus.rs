@@ -0,0 +1,198 @@
+//! Pileup consensus calling.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{collections::BTreeMap, io};
+
+use noodles_fasta as fasta;
+use noodles_sam::AlignmentRecord;
+
+use super::Column;
+
+const UNKNOWN_BASE: u8 = b'N';
+
+/// A consensus caller.
+///
+/// This calls the majority base at each pileup column. Only observed bases with a quality score
+/// of at least the minimum quality score qualify; deletions and missing bases are ignored. A column
+/// is called as `N` when it has fewer qualifying bases than the minimum depth, when the most
+/// frequent base is tied, or when the most frequent base is less than the minimum fraction of
+/// qualifying bases.
+#[derive(Debug)]
+pub struct Caller {
+    min_depth: usize,
+    min_quality_score: u8,
+    min_fraction: f64,
+}
+
+impl Caller {
+    /// Creates a consensus caller builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::pileup::consensus::Caller;
+    /// let builder = Caller::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    /// Calls the consensus base of a pileup column.
+    pub fn call_base<R>(&self, column: &Column<R>) -> u8
+    where
+        R: AlignmentRecord,
+    {
+        let mut counts: BTreeMap<u8, usize> = BTreeMap::new();
+        let mut depth = 0;
+
+        for observation in column.observations() {
+            let base = match observation.base() {
+                Some(base) => u8::from(base).to_ascii_uppercase(),
+                None => continue,
+            };
+
+            let is_quality_score_passing = observation
+                .quality_score()
+                .map(|score| u8::from(score) >= self.min_quality_score)
+                .unwrap_or(true);
+
+            if is_quality_score_passing {
+                *counts.entry(base).or_default() += 1;
+                depth += 1;
+            }
+        }
+
+        if depth == 0 || depth < self.min_depth {
+            return UNKNOWN_BASE;
+        }
+
+        let max_count = counts.values().copied().max().unwrap_or_default();
+        let mut majority_bases = counts.iter().filter(|(_, &count)| count == max_count);
+
+        match (majority_bases.next(), majority_bases.next()) {
+            (Some((&base, _)), None) if max_count as f64 / depth as f64 >= self.min_fraction => {
+                base
+            }
+            _ => UNKNOWN_BASE,
+        }
+    }
+
+    /// Calls the consensus sequence of pileup columns.
+    ///
+    /// The columns are expected to be on the same reference sequence and in increasing position
+    /// order. The consensus starts at the position of the first column, and positions between
+    /// columns that have no coverage are called as `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_util::alignment::{self, pileup::consensus::Caller, Pileup};
+    ///
+    /// let data = Cursor::new(b"@HD\tVN:1.6\tSO:coordinate
+    /// @SQ\tSN:sq0\tLN:8
+    /// r0\t0\tsq0\t1\t60\t2M\t*\t0\t0\tAC\tNN
+    /// r1\t0\tsq0\t4\t60\t1M\t*\t0\t0\tG\tN
+    /// ");
+    ///
+    /// let mut reader = alignment::Reader::builder(data).build()?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let pileup = Pileup::new(reader.records(&header), &header);
+    /// let sequence = Caller::builder().build().call(pileup)?;
+    ///
+    /// assert_eq!(sequence.as_ref(), b"ACNG");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn call<I, R>(&self, columns: I) -> io::Result<fasta::record::Sequence>
+    where
+        I: IntoIterator<Item = io::Result<Column<R>>>,
+        R: AlignmentRecord,
+    {
+        let mut sequence = Vec::new();
+        let mut start = None;
+
+        for result in columns {
+            let column = result?;
+
+            let (reference_sequence_id, start_position) =
+                *start.get_or_insert((column.reference_sequence_id(), column.position()));
+
+            if column.reference_sequence_id() != reference_sequence_id {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "columns span multiple reference sequences",
+                ));
+            }
+
+            let i = usize::from(column.position()) - usize::from(start_position);
+
+            if i < sequence.len() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "columns are not in increasing position order",
+                ));
+            }
+
+            sequence.resize(i, UNKNOWN_BASE);
+            sequence.push(self.call_base(&column));
+        }
+
+        Ok(fasta::record::Sequence::from(sequence))
+    }
+}
+
+impl Default for Caller {
+    fn default() -> Self {
+        Builder::default().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam as sam;
+
+    use super::*;
+    use crate::alignment::Pileup;
+
+    fn call(caller: &Caller) -> Result<fasta::record::Sequence, Box<dyn std::error::Error>> {
+        let header: sam::Header = "@SQ\tSN:sq0\tLN:8\n".parse()?;
+
+        let data = "\
+r0\t0\tsq0\t1\t60\t3M\t*\t0\t0\tACG\tIII
+r1\t0\tsq0\t1\t60\t3M\t*\t0\t0\tACT\tIII
+r2\t0\tsq0\t1\t60\t3M\t*\t0\t0\tAGG\tIII
+r3\t0\tsq0\t1\t60\t3M\t*\t0\t0\tACT\tII!
+r4\t0\tsq0\t5\t60\t1M\t*\t0\t0\tT\tI
+";
+
+        let records = data.lines().map(|line| {
+            line.parse::<sam::Record>()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        });
+
+        let pileup = Pileup::new(records, &header);
+
+        Ok(caller.call(pileup)?)
+    }
+
+    #[test]
+    fn test_call() -> Result<(), Box<dyn std::error::Error>> {
+        let caller = Caller::default();
+        assert_eq!(call(&caller)?.as_ref(), b"ACNNT");
+
+        let caller = Caller::builder().set_min_quality_score(1).build();
+        assert_eq!(call(&caller)?.as_ref(), b"ACGNT");
+
+        let caller = Caller::builder().set_min_fraction(0.8).build();
+        assert_eq!(call(&caller)?.as_ref(), b"ANNNT");
+
+        let caller = Caller::builder().set_min_depth(2).build();
+        assert_eq!(call(&caller)?.as_ref(), b"ACNNN");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use super::Caller;
+
+/// A consensus caller builder.
+#[derive(Debug)]
+pub struct Builder {
+    min_depth: usize,
+    min_quality_score: u8,
+    min_fraction: f64,
+}
+
+impl Builder {
+    /// Sets the minimum number of qualifying bases at a position.
+    ///
+    /// Positions with fewer bases are called as `N`. By default, this is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::pileup::consensus::Caller;
+    /// let caller = Caller::builder().set_min_depth(3).build();
+    /// ```
+    pub fn set_min_depth(mut self, min_depth: usize) -> Self {
+        self.min_depth = min_depth;
+        self
+    }
+
+    /// Sets the minimum base quality score.
+    ///
+    /// Bases with a lower quality score are ignored. By default, this is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::pileup::consensus::Caller;
+    /// let caller = Caller::builder().set_min_quality_score(20).build();
+    /// ```
+    pub fn set_min_quality_score(mut self, min_quality_score: u8) -> Self {
+        self.min_quality_score = min_quality_score;
+        self
+    }
+
+    /// Sets the minimum fraction of qualifying bases that must agree on the majority base.
+    ///
+    /// Positions where the majority base has a lower fraction are called as `N`. By default, this
+    /// is 0.5.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::pileup::consensus::Caller;
+    /// let caller = Caller::builder().set_min_fraction(0.75).build();
+    /// ```
+    pub fn set_min_fraction(mut self, min_fraction: f64) -> Self {
+        self.min_fraction = min_fraction;
+        self
+    }
+
+    /// Builds a consensus caller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::pileup::consensus::Caller;
+    /// let caller = Caller::builder().build();
+    /// ```
+    pub fn build(self) -> Caller {
+        Caller {
+            min_depth: self.min_depth,
+            min_quality_score: self.min_quality_score,
+            min_fraction: self.min_fraction,
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            min_depth: 1,
+            min_quality_score: 0,
+            min_fraction: 0.5,
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use std::io::{self, Read, Seek};
+
+use noodles_sam as sam;
+
+use super::Reader;
+
+/// Reads only the header of an alignment file.
+///
+/// The format is autodetected. No records are read, i.e., reading stops after the header. For
+/// CRAM, this only reads the file definition and file header container.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Cursor};
+/// use noodles_util::alignment;
+///
+/// let data = Cursor::new(b"@HD\tVN:1.6
+/// @SQ\tSN:sq0\tLN:8
+/// *\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*
+/// ");
+///
+/// let header = alignment::read_header(data)?;
+/// assert_eq!(header.reference_sequences().len(), 1);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn read_header<R>(reader: R) -> io::Result<sam::Header>
+where
+    R: Read + Seek + 'static,
+{
+    Reader::builder(reader).build()?.read_header()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, io::Cursor, rc::Rc};
+
+    use noodles_cram as cram;
+
+    use super::*;
+
+    // Records the furthest stream position read.
+    struct TrackingReader {
+        inner: Cursor<Vec<u8>>,
+        max_position: Rc<Cell<u64>>,
+    }
+
+    impl Read for TrackingReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = self.inner.read(buf)?;
+            let position = self.inner.position();
+
+            if position > self.max_position.get() {
+                self.max_position.set(position);
+            }
+
+            Ok(n)
+        }
+    }
+
+    impl Seek for TrackingReader {
+        fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+            self.inner.seek(pos)
+        }
+    }
+
+    #[test]
+    fn test_read_header_with_cram() -> Result<(), Box<dyn std::error::Error>> {
+        let expected: sam::Header =
+            "@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\tM5:d7eba311421bbc9d3ada44709dd61534\n".parse()?;
+
+        let mut writer = cram::Writer::new(Vec::new());
+        writer.write_file_definition()?;
+        writer.write_file_header(&expected)?;
+
+        let header_len = writer.get_ref().len() as u64;
+
+        for _ in 0..4 {
+            let record = cram::Record::builder()
+                .set_read_length(4)
+                .set_bases("ACGT".parse()?)
+                .build();
+
+            writer.write_record(&expected, record)?;
+        }
+
+        writer.try_finish(&expected)?;
+
+        let data = writer.get_ref().clone();
+        assert!(data.len() as u64 > header_len);
+
+        let max_position = Rc::new(Cell::new(0));
+
+        let reader = TrackingReader {
+            inner: Cursor::new(data),
+            max_position: max_position.clone(),
+        };
+
+        let actual = read_header(reader)?;
+
+        assert_eq!(actual, expected);
+        assert_eq!(max_position.get(), header_len);
+
+        Ok(())
+    }
+}
@@ -87,3 +87,93 @@ impl Reader {
             .alignment_records(&self.reference_sequence_repository, header)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, io::Cursor, rc::Rc};
+
+    use noodles_bam as bam;
+    use noodles_cram as cram;
+    use noodles_sam::AlignmentRecord;
+
+    use super::*;
+    use crate::alignment::{Format, Writer};
+
+    #[test]
+    fn test_records_with_cram_to_bam() -> Result<(), Box<dyn std::error::Error>> {
+        const BASES: &str = "ACGTACGT";
+        // 2 containers, i.e., more records than fit in a single container
+        const RECORD_COUNT: usize = 10240 + 8;
+
+        #[derive(Clone, Default)]
+        struct SharedBuf(Rc<RefCell<Vec<u8>>>);
+
+        impl io::Write for SharedBuf {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.borrow_mut().write(buf)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let header = sam::Header::default();
+
+        let mut cram_writer = cram::Writer::new(Vec::new());
+        cram_writer.write_file_definition()?;
+        cram_writer.write_file_header(&header)?;
+
+        for i in 0..RECORD_COUNT {
+            let read_length = i % 8 + 1;
+
+            let record = cram::Record::builder()
+                .set_read_name(format!("r{}", i).parse()?)
+                .set_read_length(read_length)
+                .set_bases(BASES[..read_length].parse()?)
+                .build();
+
+            cram_writer.write_record(&header, record)?;
+        }
+
+        cram_writer.try_finish(&header)?;
+
+        let data = cram_writer.get_ref().clone();
+
+        let mut reader = Reader::builder(Cursor::new(data)).build()?;
+        let header = reader.read_header()?;
+
+        let buf = SharedBuf::default();
+        let mut writer = Writer::builder(buf.clone()).set_format(Format::Bam).build();
+
+        writer.write_header(&header)?;
+
+        for result in reader.records(&header) {
+            let record = result?;
+            writer.write_record(&header, record.as_ref())?;
+        }
+
+        writer.finish(&header)?;
+
+        let data = buf.0.borrow().clone();
+        let mut bam_reader = bam::Reader::new(&data[..]);
+        bam_reader.read_header()?;
+        bam_reader.read_reference_sequences()?;
+
+        let mut n = 0;
+
+        for (i, result) in bam_reader.records().enumerate() {
+            let record = result?;
+            assert_eq!(
+                record.read_name().map(|name| name.to_string()),
+                Some(format!("r{}", i))
+            );
+            assert_eq!(record.sequence().len(), i % 8 + 1);
+            n += 1;
+        }
+
+        assert_eq!(n, RECORD_COUNT);
+
+        Ok(())
+    }
+}
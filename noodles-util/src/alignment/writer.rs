@@ -7,8 +7,11 @@ use std::io::{self, Write};
 use noodles_sam as sam;
 
 /// An alignment writer.
+///
+/// The header must be written (see [`Self::write_header`]) before any records.
 pub struct Writer {
     inner: Box<dyn sam::AlignmentWriter>,
+    is_header_written: bool,
 }
 
 impl Writer {
@@ -21,21 +24,213 @@ impl Writer {
     }
 
     /// Writes a SAM header.
+    ///
+    /// This must be called before writing any records.
     pub fn write_header(&mut self, header: &sam::Header) -> io::Result<()> {
-        self.inner.write_alignment_header(header)
+        self.inner.write_alignment_header(header)?;
+        self.is_header_written = true;
+        Ok(())
     }
 
     /// Writes an alignment record.
+    ///
+    /// This returns an error if the header has not yet been written.
     pub fn write_record(
         &mut self,
         header: &sam::Header,
         record: &dyn sam::AlignmentRecord,
     ) -> io::Result<()> {
+        if !self.is_header_written {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the header must be written before records",
+            ));
+        }
+
         self.inner.write_alignment_record(header, record)
     }
 
     /// Shuts down the alignment format writer.
+    ///
+    /// This writes any format-specific end-of-file marker, e.g., the BGZF EOF block for BAM and
+    /// the EOF container for CRAM.
     pub fn finish(&mut self, header: &sam::Header) -> io::Result<()> {
         self.inner.finish(header)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alignment::Format;
+
+    #[test]
+    fn test_write_record_before_header() -> io::Result<()> {
+        let header = sam::Header::default();
+        let record = sam::Record::default();
+
+        for format in [Format::Sam, Format::Bam, Format::Cram] {
+            let mut writer = Writer::builder(io::sink()).set_format(format).build();
+
+            assert!(matches!(
+                writer.write_record(&header, &record),
+                Err(e) if e.kind() == io::ErrorKind::InvalidInput
+            ));
+
+            writer.write_header(&header)?;
+            writer.write_record(&header, &record)?;
+            writer.finish(&header)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish_with_bam() -> io::Result<()> {
+        use std::{cell::RefCell, rc::Rc};
+
+        // § 4.1.2 "End-of-file marker" (2021-06-03)
+        static BGZF_EOF: &[u8] = &[
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1b, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        #[derive(Clone, Default)]
+        struct SharedBuf(Rc<RefCell<Vec<u8>>>);
+
+        impl Write for SharedBuf {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.borrow_mut().write(buf)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let header = sam::Header::default();
+        let buf = SharedBuf::default();
+
+        let mut writer = Writer::builder(buf.clone()).set_format(Format::Bam).build();
+
+        writer.write_header(&header)?;
+        writer.write_record(&header, &sam::Record::default())?;
+        writer.finish(&header)?;
+
+        assert!(buf.0.borrow().ends_with(BGZF_EOF));
+
+        drop(writer);
+
+        let data = buf.0.borrow();
+        let eof_count = data
+            .windows(BGZF_EOF.len())
+            .filter(|window| *window == BGZF_EOF)
+            .count();
+
+        assert_eq!(eof_count, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_bam_records_to_cram() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{cell::RefCell, rc::Rc};
+
+        use md5::{Digest, Md5};
+        use noodles_bam as bam;
+        use noodles_fasta as fasta;
+
+        const REFERENCE_SEQUENCE: &[u8] = b"ACGTACGTACGTACGT";
+
+        #[derive(Clone, Default)]
+        struct SharedBuf(Rc<RefCell<Vec<u8>>>);
+
+        impl Write for SharedBuf {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.borrow_mut().write(buf)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        fn write_cram(
+            header: &sam::Header,
+            repository: &fasta::Repository,
+            records: &[&dyn sam::AlignmentRecord],
+        ) -> io::Result<Vec<u8>> {
+            let buf = SharedBuf::default();
+
+            let mut writer = Writer::builder(buf.clone())
+                .set_format(Format::Cram)
+                .set_reference_sequence_repository(repository.clone())
+                .build();
+
+            writer.write_header(header)?;
+
+            for &record in records {
+                writer.write_record(header, record)?;
+            }
+
+            writer.finish(header)?;
+
+            let data = buf.0.borrow().clone();
+            Ok(data)
+        }
+
+        let raw_header = format!(
+            "@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:{}\tM5:{:x}\n@RG\tID:rg0\n",
+            REFERENCE_SEQUENCE.len(),
+            Md5::digest(REFERENCE_SEQUENCE)
+        );
+        let header: sam::Header = raw_header.parse()?;
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(REFERENCE_SEQUENCE.to_vec()),
+        )]);
+
+        let raw_records = [
+            "r0\t99\tsq0\t1\t60\t4M\t=\t9\t12\tACGT\tNDLS\tNM:i:0",
+            "r0\t147\tsq0\t9\t60\t2M1I1M\t=\t1\t-12\tACTT\tNDLS",
+            "r1\t0\tsq0\t3\t60\t1S3M\t*\t0\t0\tTGTA\tNDLS\tRG:Z:rg0",
+            "r2\t4\t*\t0\t255\t*\t*\t0\t0\tACGT\t*",
+        ];
+
+        let bam_records = raw_records
+            .iter()
+            .map(|s| {
+                let sam_record: sam::Record = s.parse()?;
+                let record =
+                    bam::Record::try_from_sam_record(header.reference_sequences(), &sam_record)?;
+                Ok(record)
+            })
+            .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+
+        // BAM records are converted directly to CRAM records.
+        let records: Vec<&dyn sam::AlignmentRecord> = bam_records
+            .iter()
+            .map(|record| record as &dyn sam::AlignmentRecord)
+            .collect();
+
+        let actual = write_cram(&header, &repository, &records)?;
+
+        // BAM records are first converted to SAM records.
+        let sam_records = bam_records
+            .iter()
+            .map(|record| record.try_into_sam_record(header.reference_sequences()))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let records: Vec<&dyn sam::AlignmentRecord> = sam_records
+            .iter()
+            .map(|record| record as &dyn sam::AlignmentRecord)
+            .collect();
+
+        let expected = write_cram(&header, &repository, &records)?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
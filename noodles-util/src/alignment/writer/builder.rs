@@ -54,6 +54,9 @@ where
             ),
         };
 
-        Writer { inner }
+        Writer {
+            inner,
+            is_header_written: false,
+        }
     }
 }
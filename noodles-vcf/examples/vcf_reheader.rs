@@ -12,7 +12,7 @@ use std::{
 
 use noodles_vcf as vcf;
 
-fn add_comment(header: &mut vcf::Header) {
+fn add_comment(header: &mut vcf::Header) -> Result<(), vcf::header::InsertError> {
     use vcf::header::{
         record::{Key, Value},
         Record,
@@ -21,7 +21,7 @@ fn add_comment(header: &mut vcf::Header) {
     header.insert(Record::new(
         Key::Other(String::from("comment")),
         Value::String(String::from("a comment added by noodles-vcf")),
-    ));
+    ))
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -30,7 +30,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut reader = File::open(src).map(BufReader::new).map(vcf::Reader::new)?;
 
     let mut header = reader.read_header()?.parse()?;
-    add_comment(&mut header);
+    add_comment(&mut header)?;
 
     let stdout = io::stdout();
     let handle = stdout.lock();
@@ -13,7 +13,7 @@ use tokio::{
     io::{self, BufReader},
 };
 
-fn add_comment(header: &mut vcf::Header) {
+fn add_comment(header: &mut vcf::Header) -> Result<(), vcf::header::InsertError> {
     use vcf::header::{
         record::{Key, Value},
         Record,
@@ -22,7 +22,7 @@ fn add_comment(header: &mut vcf::Header) {
     header.insert(Record::new(
         Key::Other(String::from("comment")),
         Value::String(String::from("a comment added by noodles-vcf")),
-    ));
+    ))
 }
 
 #[tokio::main]
@@ -35,7 +35,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .map(vcf::AsyncReader::new)?;
 
     let mut header = reader.read_header().await?.parse()?;
-    add_comment(&mut header);
+    add_comment(&mut header)?;
 
     let mut writer = vcf::AsyncWriter::new(io::stdout());
     writer.write_header(&header).await?;
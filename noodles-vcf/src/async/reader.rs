@@ -9,7 +9,10 @@ use noodles_tabix as tabix;
 use tokio::io::{self, AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncSeek};
 
 use self::query::query;
-use crate::{reader::resolve_region, Header, Record};
+use crate::{
+    reader::{resolve_region, ParseError},
+    Header, Record,
+};
 
 const LINE_FEED: char = '\n';
 const CARRIAGE_RETURN: char = '\r';
@@ -51,6 +54,7 @@ const HEADER_PREFIX: u8 = b'#';
 /// ```
 pub struct Reader<R> {
     inner: R,
+    line_number: usize,
 }
 
 impl<R> Reader<R>
@@ -67,7 +71,10 @@ where
     /// let reader = vcf::AsyncReader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            line_number: 0,
+        }
     }
 
     /// Reads the raw VCF header.
@@ -102,7 +109,9 @@ where
     /// # }
     /// ```
     pub async fn read_header(&mut self) -> io::Result<String> {
-        read_header(&mut self.inner).await
+        let header = read_header(&mut self.inner).await?;
+        self.line_number += header.lines().count();
+        Ok(header)
     }
 
     /// Reads a single raw VCF record.
@@ -145,7 +154,30 @@ where
     /// # }
     /// ```
     pub async fn read_record(&mut self, buf: &mut String) -> io::Result<usize> {
-        read_line(&mut self.inner, buf).await
+        let n = read_line(&mut self.inner, buf).await?;
+
+        if n > 0 {
+            self.line_number += 1;
+        }
+
+        Ok(n)
+    }
+
+    /// Returns the number of lines read.
+    ///
+    /// This is the 1-based line number of the last header line or record read. Lines are counted
+    /// from where the reader started and are not adjusted when seeking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let data = [];
+    /// let reader = vcf::AsyncReader::new(&data[..]);
+    /// assert_eq!(reader.line_number(), 0);
+    /// ```
+    pub fn line_number(&self) -> usize {
+        self.line_number
     }
 
     /// Returns an (async) stream over records starting from the current (input) stream position.
@@ -153,7 +185,9 @@ where
     /// The (input) stream is expected to be directly after the header or at the start of another
     /// record.
     ///
-    /// Unlike [`Self::read_record`], each record is parsed as a [`Record`].
+    /// Unlike [`Self::read_record`], each record is parsed as a [`Record`]. If a record fails to
+    /// parse, the inner error of the returned [`io::Error`] is a [`ParseError`], which includes the
+    /// line number of the record.
     ///
     /// # Examples
     ///
@@ -184,15 +218,24 @@ where
         header: &'h Header,
     ) -> impl Stream<Item = io::Result<Record>> + 'r {
         Box::pin(stream::try_unfold(
-            (&mut self.inner, String::new()),
-            |(mut reader, mut buf)| async {
+            (self, String::new()),
+            |(reader, mut buf)| async {
                 buf.clear();
 
-                match read_line(&mut reader, &mut buf).await? {
+                match reader.read_record(&mut buf).await? {
                     0 => Ok(None),
-                    _ => Record::try_from_str(&buf, header)
-                        .map(|record| Some((record, (reader, buf))))
-                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+                    _ => {
+                        let line_number = reader.line_number();
+
+                        Record::try_from_str(&buf, header)
+                            .map(|record| Some((record, (reader, buf))))
+                            .map_err(|e| {
+                                io::Error::new(
+                                    io::ErrorKind::InvalidData,
+                                    ParseError::new(line_number, e),
+                                )
+                            })
+                    }
                 }
             },
         ))
@@ -364,6 +407,36 @@ where
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn test_records_with_invalid_record() -> Result<(), Box<dyn std::error::Error>> {
+        use futures::TryStreamExt;
+
+        let data = b"##fileformat=VCFv4.3
+##fileDate=20200501
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+sq0	8	.	A	.	.	PASS	.
+sq0	ndls	.	C	.	.	PASS	.
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let header = reader.read_header().await?.parse()?;
+
+        let mut records = reader.records(&header);
+        assert!(records.try_next().await?.is_some());
+
+        let e = records.try_next().await.unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+
+        let parse_error = e
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<ParseError>())
+            .expect("missing parse error");
+
+        assert_eq!(parse_error.line_number(), 5);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_read_line() -> io::Result<()> {
         async fn t(buf: &mut String, mut data: &[u8], expected: &str) -> io::Result<()> {
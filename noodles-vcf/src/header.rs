@@ -21,10 +21,12 @@ pub use self::{
     number::Number, parser::ParseError, pedigree::Pedigree, record::Record, sample::Sample,
 };
 
-use std::str::FromStr;
+use std::{error, str::FromStr};
 
 use indexmap::{IndexMap, IndexSet};
 
+use crate::record::{select_samples::validate_indices, SelectSamplesError};
+
 /// VCF header info records.
 pub type Infos = IndexMap<info::Key, Info>;
 
@@ -596,6 +598,88 @@ impl Header {
         &mut self.sample_names
     }
 
+    /// Renames a sample.
+    ///
+    /// The sample keeps its position in the header record. This returns an error if the old
+    /// sample name does not exist or if the new sample name is already used by another sample.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut header = vcf::Header::builder()
+    ///     .add_sample_name("sample0")
+    ///     .add_sample_name("sample1")
+    ///     .build();
+    ///
+    /// header.rename_sample("sample0", "sample2")?;
+    ///
+    /// let sample_names: Vec<_> = header.sample_names().iter().collect();
+    /// assert_eq!(sample_names, ["sample2", "sample1"]);
+    /// # Ok::<_, vcf::header::RenameSampleError>(())
+    /// ```
+    pub fn rename_sample<N>(&mut self, old_name: &str, new_name: N) -> Result<(), RenameSampleError>
+    where
+        N: Into<String>,
+    {
+        let new_name = new_name.into();
+
+        let i = self
+            .sample_names
+            .get_index_of(old_name)
+            .ok_or_else(|| RenameSampleError::MissingSampleName(old_name.into()))?;
+
+        if new_name == old_name {
+            return Ok(());
+        }
+
+        if self.sample_names.contains(&new_name) {
+            return Err(RenameSampleError::DuplicateSampleName(new_name));
+        }
+
+        // The new name is appended and then swapped into the position of the old name.
+        self.sample_names.insert(new_name);
+        self.sample_names.swap_remove_index(i);
+
+        Ok(())
+    }
+
+    /// Returns a copy of the header with only the sample names at the given indices.
+    ///
+    /// This is the header counterpart of [`crate::Record::select_samples`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_sample_name("sample0")
+    ///     .add_sample_name("sample1")
+    ///     .add_sample_name("sample2")
+    ///     .build();
+    ///
+    /// let header = header.select_samples(&[2, 0])?;
+    ///
+    /// let sample_names: Vec<_> = header.sample_names().iter().collect();
+    /// assert_eq!(sample_names, ["sample2", "sample0"]);
+    /// # Ok::<_, vcf::record::SelectSamplesError>(())
+    /// ```
+    pub fn select_samples(&self, indices: &[usize]) -> Result<Self, SelectSamplesError> {
+        validate_indices(indices, self.sample_names.len())?;
+
+        let mut header = self.clone();
+
+        header.sample_names = indices
+            .iter()
+            .filter_map(|&i| self.sample_names.get_index(i))
+            .cloned()
+            .collect();
+
+        Ok(header)
+    }
+
     /// Returns a map of the unstructured header records.
     ///
     /// This includes all records other than `fileformat`, `INFO`, `FILTER`, `FORMAT`, `ALT`,
@@ -676,30 +760,184 @@ impl Header {
         self.map.get(key).map(|r| &**r)
     }
 
-    /// Inserts a key-value pair representing an unstructured record into the header.
+    /// Inserts a header record.
+    ///
+    /// Records with a structured key (`INFO`, `FILTER`, `FORMAT`, `ALT`, `contig`, `META`,
+    /// `SAMPLE`, and `PEDIGREE`) are validated and added to their respective maps, i.e., they must
+    /// carry all of their mandatory fields, e.g., an `INFO` record requires `ID`, `Number`, `Type`,
+    /// and `Description`. The values of `fileformat`, `assembly`, and `pedigreeDB` records must be
+    /// strings.
+    ///
+    /// A structured record replaces an existing record of the same kind with the same ID rather than
+    /// adding a duplicate. The replaced record keeps its original position in the header.
+    ///
+    /// Any other record is unstructured and is appended to the list of records with the same key,
+    /// i.e., duplicates are allowed.
     ///
     /// # Examples
     ///
     /// ```
-    /// use noodles_vcf::{self as vcf, header::{record::{Key, Value}, Record}};
+    /// use noodles_vcf::{self as vcf, header::{info::Key as InfoKey, record::{Key, Value}, Record}};
+    ///
+    /// let mut header = vcf::Header::default();
     ///
     /// let record = Record::new(
     ///     Key::Other(String::from("fileDate")),
     ///     Value::String(String::from("20200709")),
     /// );
     ///
-    /// let mut header = vcf::Header::default();
-    ///
     /// assert!(header.get("fileDate").is_none());
+    /// header.insert(record.clone())?;
+    /// assert_eq!(header.get("fileDate"), Some(&[record][..]));
     ///
-    /// header.insert(record.clone());
+    /// let record: Record =
+    ///     r#"##INFO=<ID=NS,Number=1,Type=Integer,Description="Number of samples with data">"#
+    ///         .parse()?;
     ///
-    /// assert_eq!(header.get("fileDate"), Some(&[record][..]));
+    /// header.insert(record)?;
+    /// assert!(header.infos().contains_key(&InfoKey::SamplesWithDataCount));
+    ///
+    /// // missing `Type`
+    /// let record = Record::new(
+    ///     Key::Info,
+    ///     Value::Struct(vec![
+    ///         (String::from("ID"), String::from("DP")),
+    ///         (String::from("Number"), String::from("1")),
+    ///         (String::from("Description"), String::from("Combined depth")),
+    ///     ]),
+    /// );
+    ///
+    /// assert!(header.insert(record).is_err());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// ```
-    pub fn insert(&mut self, record: Record) {
-        let key = record.key().to_string();
-        let records = self.map.entry(key).or_default();
-        records.push(record);
+    pub fn insert(&mut self, record: Record) -> Result<(), InsertError> {
+        use record::{Key, Value};
+
+        match record.key() {
+            Key::FileFormat => match record.value() {
+                Value::String(value) => {
+                    self.file_format = value.parse().map_err(InsertError::InvalidFileFormat)?;
+                }
+                _ => return Err(InsertError::InvalidRecordValue),
+            },
+            Key::Info => {
+                let info = Info::try_from_record_file_format(record, self.file_format)
+                    .map_err(InsertError::InvalidInfo)?;
+                self.infos.insert(info.id().clone(), info);
+            }
+            Key::Filter => {
+                let filter = Filter::try_from(record).map_err(InsertError::InvalidFilter)?;
+                self.filters.insert(filter.id().into(), filter);
+            }
+            Key::Format => {
+                let format = Format::try_from_record_file_format(record, self.file_format)
+                    .map_err(InsertError::InvalidFormat)?;
+                self.formats.insert(format.id().clone(), format);
+            }
+            Key::AlternativeAllele => {
+                let alternative_allele = AlternativeAllele::try_from(record)
+                    .map_err(InsertError::InvalidAlternativeAllele)?;
+                self.alternative_alleles
+                    .insert(alternative_allele.id().clone(), alternative_allele);
+            }
+            Key::Assembly => match record.value() {
+                Value::String(value) => self.assembly = Some(value.into()),
+                _ => return Err(InsertError::InvalidRecordValue),
+            },
+            Key::Contig => {
+                let contig = Contig::try_from(record).map_err(InsertError::InvalidContig)?;
+                self.contigs.insert(contig.id().into(), contig);
+            }
+            Key::Meta => {
+                let meta = Meta::try_from(record).map_err(InsertError::InvalidMeta)?;
+                self.meta.insert(meta.id().into(), meta);
+            }
+            Key::Sample => {
+                let sample = Sample::try_from(record).map_err(InsertError::InvalidSample)?;
+                self.samples.insert(sample.id().into(), sample);
+            }
+            Key::Pedigree => {
+                let pedigree = Pedigree::try_from(record).map_err(InsertError::InvalidPedigree)?;
+                self.pedigrees.insert(pedigree.id().into(), pedigree);
+            }
+            Key::PedigreeDb => match record.value() {
+                Value::String(value) => self.pedigree_db = Some(value.into()),
+                _ => return Err(InsertError::InvalidRecordValue),
+            },
+            Key::Other(_) => {
+                let key = record.key().to_string();
+                let records = self.map.entry(key).or_default();
+                records.push(record);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when a header record fails to be inserted into a header.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InsertError {
+    /// The file format (`fileformat`) is invalid.
+    InvalidFileFormat(file_format::ParseError),
+    /// The record value is invalid.
+    InvalidRecordValue,
+    /// An information record (`INFO`) is invalid.
+    InvalidInfo(info::TryFromRecordError),
+    /// A filter record (`FILTER`) is invalid.
+    InvalidFilter(filter::TryFromRecordError),
+    /// A genotype format record (`FORMAT`) is invalid.
+    InvalidFormat(format::TryFromRecordError),
+    /// A symbolic alternate allele record (`ALT`) is invalid.
+    InvalidAlternativeAllele(alternative_allele::TryFromRecordError),
+    /// A contig record (`contig`) is invalid.
+    InvalidContig(contig::TryFromRecordError),
+    /// A meta record (`META`) is invalid.
+    InvalidMeta(meta::TryFromRecordError),
+    /// A sample record (`SAMPLE`) is invalid.
+    InvalidSample(sample::TryFromRecordError),
+    /// A pedigree record (`PEDIGREE`) is invalid.
+    InvalidPedigree(pedigree::TryFromRecordError),
+}
+
+impl error::Error for InsertError {}
+
+impl std::fmt::Display for InsertError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidFileFormat(e) => write!(f, "invalid file format: {}", e),
+            Self::InvalidRecordValue => f.write_str("invalid record value"),
+            Self::InvalidInfo(e) => write!(f, "invalid info: {}", e),
+            Self::InvalidFilter(e) => write!(f, "invalid filter: {}", e),
+            Self::InvalidFormat(e) => write!(f, "invalid format: {}", e),
+            Self::InvalidAlternativeAllele(e) => {
+                write!(f, "invalid alternative allele: {}", e)
+            }
+            Self::InvalidContig(e) => write!(f, "invalid contig: {}", e),
+            Self::InvalidMeta(e) => write!(f, "invalid meta: {}", e),
+            Self::InvalidSample(e) => write!(f, "invalid sample: {}", e),
+            Self::InvalidPedigree(e) => write!(f, "invalid pedigree: {}", e),
+        }
+    }
+}
+
+/// An error returned when a sample fails to be renamed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RenameSampleError {
+    /// The sample name does not exist.
+    MissingSampleName(String),
+    /// The new sample name is already used by another sample.
+    DuplicateSampleName(String),
+}
+
+impl error::Error for RenameSampleError {}
+
+impl std::fmt::Display for RenameSampleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingSampleName(name) => write!(f, "missing sample name: {}", name),
+            Self::DuplicateSampleName(name) => write!(f, "duplicate sample name: {}", name),
+        }
     }
 }
 
@@ -878,7 +1116,42 @@ mod tests {
     }
 
     #[test]
-    fn test_insert_with_duplicate_keys() {
+    fn test_rename_sample() -> Result<(), Box<dyn std::error::Error>> {
+        let mut header = Header::builder()
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .add_sample_name("sample2")
+            .build();
+
+        header.rename_sample("sample1", "sample3")?;
+
+        let expected = "##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\tsample3\tsample2
+";
+        assert_eq!(header.to_string(), expected);
+
+        header.rename_sample("sample0", "sample0")?;
+        assert_eq!(header.to_string(), expected);
+
+        assert_eq!(
+            header.rename_sample("sample1", "sample4"),
+            Err(RenameSampleError::MissingSampleName(String::from(
+                "sample1"
+            )))
+        );
+
+        assert_eq!(
+            header.rename_sample("sample0", "sample2"),
+            Err(RenameSampleError::DuplicateSampleName(String::from(
+                "sample2"
+            )))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_with_duplicate_keys() -> Result<(), InsertError> {
         let records = [
             Record::new(
                 record::Key::Other(String::from("noodles")),
@@ -893,9 +1166,111 @@ mod tests {
         let mut header = Header::default();
 
         for record in &records {
-            header.insert(record.clone());
+            header.insert(record.clone())?;
         }
 
         assert_eq!(header.get("noodles"), Some(&records[..]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_with_structured_records() -> Result<(), Box<dyn std::error::Error>> {
+        let mut header = Header::default();
+
+        header.insert(
+            r#"##INFO=<ID=NS,Number=1,Type=Integer,Description="Number of samples with data">"#
+                .parse()?,
+        )?;
+        header.insert(r#"##FILTER=<ID=q10,Description="Quality below 10">"#.parse()?)?;
+        header
+            .insert(r#"##FORMAT=<ID=GT,Number=1,Type=String,Description="Genotype">"#.parse()?)?;
+        header.insert("##contig=<ID=sq0,length=8>".parse()?)?;
+        header.insert("##assembly=file:///assemblies.fasta".parse()?)?;
+
+        assert_eq!(header.infos().len(), 1);
+        assert_eq!(header.filters().len(), 1);
+        assert_eq!(header.formats().len(), 1);
+        assert_eq!(header.contigs().len(), 1);
+        assert_eq!(header.assembly(), Some("file:///assemblies.fasta"));
+        assert!(header.records().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_with_duplicate_ids() -> Result<(), Box<dyn std::error::Error>> {
+        let mut header = Header::default();
+
+        header.insert(
+            r#"##INFO=<ID=NS,Number=1,Type=Integer,Description="Number of samples with data">"#
+                .parse()?,
+        )?;
+        header.insert(
+            r#"##INFO=<ID=DP,Number=1,Type=Integer,Description="Combined depth across samples">"#
+                .parse()?,
+        )?;
+        header.insert(
+            r#"##INFO=<ID=NS,Number=1,Type=Integer,Description="Number of samples">"#.parse()?,
+        )?;
+
+        let infos = header.infos();
+        assert_eq!(infos.len(), 2);
+        assert_eq!(infos[0].id(), &info::Key::SamplesWithDataCount);
+        assert_eq!(infos[0].description(), "Number of samples");
+        assert_eq!(infos[1].id(), &info::Key::TotalDepth);
+
+        let expected = r#"##fileformat=VCFv4.3
+##INFO=<ID=NS,Number=1,Type=Integer,Description="Number of samples">
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Combined depth across samples">
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#;
+
+        assert_eq!(header.to_string(), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_with_missing_mandatory_field() {
+        let mut header = Header::default();
+
+        let record = Record::new(
+            record::Key::Info,
+            record::Value::Struct(vec![
+                (String::from("ID"), String::from("NS")),
+                (String::from("Number"), String::from("1")),
+                (
+                    String::from("Description"),
+                    String::from("Number of samples with data"),
+                ),
+            ]),
+        );
+
+        assert_eq!(
+            header.insert(record),
+            Err(InsertError::InvalidInfo(
+                info::TryFromRecordError::MissingField("Type")
+            ))
+        );
+
+        assert!(header.infos().is_empty());
+
+        let record = Record::new(
+            record::Key::Filter,
+            record::Value::Struct(vec![(String::from("ID"), String::from("q10"))]),
+        );
+
+        assert!(matches!(
+            header.insert(record),
+            Err(InsertError::InvalidFilter(_))
+        ));
+
+        let record = Record::new(
+            record::Key::Assembly,
+            record::Value::Struct(vec![(String::from("ID"), String::from("sq0"))]),
+        );
+
+        assert_eq!(header.insert(record), Err(InsertError::InvalidRecordValue));
     }
 }
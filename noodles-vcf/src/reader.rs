@@ -1,12 +1,13 @@
 //! VCF reader and iterators.
 
+mod parse_error;
 pub(crate) mod query;
 mod records;
 
-pub use self::{query::Query, records::Records};
+pub use self::{parse_error::ParseError, query::Query, records::Records};
 
 use std::{
-    io::{self, BufRead, Read, Seek},
+    io::{self, BufRead, BufReader, Read, Seek},
     ops::Bound,
 };
 
@@ -48,6 +49,7 @@ use super::Header;
 #[derive(Debug)]
 pub struct Reader<R> {
     inner: R,
+    line_number: usize,
 }
 
 impl<R> Reader<R>
@@ -69,7 +71,10 @@ where
     /// let reader = vcf::Reader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            line_number: 0,
+        }
     }
 
     /// Returns a reference to the underlying reader.
@@ -142,7 +147,9 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn read_header(&mut self) -> io::Result<String> {
-        read_header(&mut self.inner)
+        let header = read_header(&mut self.inner)?;
+        self.line_number += header.lines().count();
+        Ok(header)
     }
 
     /// Reads a single raw VCF record.
@@ -180,14 +187,53 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn read_record(&mut self, buf: &mut String) -> io::Result<usize> {
-        read_line(&mut self.inner, buf)
+        let n = read_line(&mut self.inner, buf)?;
+
+        if n > 0 {
+            self.line_number += 1;
+        }
+
+        Ok(n)
+    }
+
+    /// Returns the number of lines read.
+    ///
+    /// This is the 1-based line number of the last header line or record read. Lines are counted
+    /// from where the reader started and are not adjusted when seeking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let data = b"##fileformat=VCFv4.3
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// sq0\t1\t.\tA\t.\t.\tPASS\t.
+    /// ";
+    ///
+    /// let mut reader = vcf::Reader::new(&data[..]);
+    /// assert_eq!(reader.line_number(), 0);
+    ///
+    /// reader.read_header()?;
+    /// assert_eq!(reader.line_number(), 2);
+    ///
+    /// let mut buf = String::new();
+    /// reader.read_record(&mut buf)?;
+    /// assert_eq!(reader.line_number(), 3);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn line_number(&self) -> usize {
+        self.line_number
     }
 
     /// Returns an iterator over records starting from the current stream position.
     ///
     /// The stream is expected to be directly after the header or at the start of another record.
     ///
-    /// Unlike [`Self::read_record`], each record is parsed as a [`crate::Record`].
+    /// Unlike [`Self::read_record`], each record is parsed as a [`crate::Record`]. If a record
+    /// fails to parse, the inner error of the returned [`io::Error`] is a [`ParseError`], which
+    /// includes the line number of the record.
     ///
     /// # Examples
     ///
@@ -212,6 +258,43 @@ where
     }
 }
 
+impl<'a> Reader<Box<dyn BufRead + 'a>> {
+    /// Creates a VCF reader that detects whether the input is BGZF-compressed.
+    ///
+    /// If the stream starts with a BGZF block header, it is transparently decompressed.
+    /// Otherwise, it is read as plain text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let data = b"##fileformat=VCFv4.3
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// ";
+    ///
+    /// let mut reader = vcf::Reader::from_reader(&data[..])?;
+    /// let header = reader.read_header()?;
+    ///
+    /// assert_eq!(header, "##fileformat=VCFv4.3\n#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn from_reader<R>(reader: R) -> io::Result<Self>
+    where
+        R: Read + 'a,
+    {
+        let (compression, reader) = bgzf::detect_compression(reader)?;
+
+        let inner: Box<dyn BufRead + 'a> = match compression {
+            Some(bgzf::Compression::Bgzf) => Box::new(bgzf::Reader::new(reader)),
+            _ => Box::new(BufReader::new(reader)),
+        };
+
+        Ok(Self::new(inner))
+    }
+}
+
 impl<R> Reader<bgzf::Reader<R>>
 where
     R: Read,
@@ -420,6 +503,97 @@ sq0\t8
 sq0\t13
 ";
 
+    #[test]
+    fn test_from_reader() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Write;
+
+        fn read_records(data: &[u8]) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+            let mut reader = Reader::from_reader(data)?;
+            let header = reader.read_header()?.parse()?;
+
+            reader
+                .records(&header)
+                .map(|result| {
+                    result
+                        .map(|record| record.to_string())
+                        .map_err(|e| e.into())
+                })
+                .collect()
+        }
+
+        let data = b"##fileformat=VCFv4.3
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+sq0	8	.	A	.	.	PASS	.
+sq0	13	.	C	.	.	PASS	.
+";
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(data)?;
+        let compressed_data = writer.finish()?;
+
+        let expected = [
+            "sq0\t8\t.\tA\t.\t.\tPASS\t.",
+            "sq0\t13\t.\tC\t.\t.\tPASS\t.",
+        ];
+
+        assert_eq!(read_records(data)?, expected);
+        assert_eq!(read_records(&compressed_data)?, expected);
+
+        // a stream that returns at most 1 byte per read
+        struct ByteReader<'a>(&'a [u8]);
+
+        impl Read for ByteReader<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                let len = self.0.len().min(buf.len()).min(1);
+                let (head, tail) = self.0.split_at(len);
+                buf[..len].copy_from_slice(head);
+                self.0 = tail;
+                Ok(len)
+            }
+        }
+
+        for src in [&data[..], &compressed_data[..]] {
+            let mut reader = Reader::from_reader(ByteReader(src))?;
+            let header = reader.read_header()?.parse()?;
+            assert_eq!(reader.records(&header).count(), 2);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_invalid_record() -> Result<(), Box<dyn std::error::Error>> {
+        let data = b"##fileformat=VCFv4.3
+##fileDate=20200501
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+sq0	8	.	A	.	.	PASS	.
+sq0	ndls	.	C	.	.	PASS	.
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let header = reader.read_header()?.parse()?;
+
+        let mut records = reader.records(&header);
+        assert!(records.next().transpose()?.is_some());
+
+        let e = records.next().transpose().unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+
+        let parse_error = e
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<ParseError>())
+            .expect("missing parse error");
+
+        assert_eq!(parse_error.line_number(), 5);
+        assert!(matches!(
+            parse_error.error(),
+            crate::record::ParseError::InvalidPosition(_)
+        ));
+        assert!(e.to_string().starts_with("invalid record at line 5: "));
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_header() -> io::Result<()> {
         let mut reader = DATA;
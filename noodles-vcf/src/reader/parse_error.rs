@@ -0,0 +1,64 @@
+use std::{error, fmt};
+
+use crate::record;
+
+/// An error returned when a VCF record read from a stream fails to parse.
+///
+/// This is the inner error of the [`std::io::Error`] returned by record iterators. It carries the
+/// 1-based line number of the record in the input.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    line_number: usize,
+    error: record::ParseError,
+}
+
+impl ParseError {
+    pub(crate) fn new(line_number: usize, error: record::ParseError) -> Self {
+        Self { line_number, error }
+    }
+
+    /// Returns the 1-based line number of the record that failed to parse.
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+
+    /// Returns the record parse error.
+    pub fn error(&self) -> &record::ParseError {
+        &self.error
+    }
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid record at line {}: {}",
+            self.line_number, self.error
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source() {
+        let error = ParseError::new(5, record::ParseError::MissingField(record::Field::Position));
+
+        let source = error::Error::source(&error)
+            .and_then(|e| e.downcast_ref::<record::ParseError>())
+            .expect("missing source");
+
+        assert_eq!(
+            source,
+            &record::ParseError::MissingField(record::Field::Position)
+        );
+    }
+}
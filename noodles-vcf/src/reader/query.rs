@@ -4,6 +4,7 @@ use std::{
 };
 
 use noodles_bgzf as bgzf;
+use noodles_core::region;
 use noodles_csi::index::reference_sequence::bin::Chunk;
 
 use super::Reader;
@@ -161,9 +162,6 @@ pub(crate) fn intersects(
         .map(i32::from)
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
-    Ok(name == reference_sequence_name && in_interval(start, end, interval_start, interval_end))
-}
-
-fn in_interval(a_start: i32, a_end: i32, b_start: i32, b_end: i32) -> bool {
-    a_start <= b_end && b_start <= a_end
+    Ok(name == reference_sequence_name
+        && region::overlaps(start, end, interval_start..=interval_end))
 }
@@ -1,6 +1,6 @@
 use std::io::{self, BufRead};
 
-use super::Reader;
+use super::{ParseError, Reader};
 use crate::{Header, Record};
 
 /// An iterator over records of a VCF reader.
@@ -37,8 +37,12 @@ where
         match self.inner.read_record(&mut self.line_buf) {
             Ok(0) => None,
             Ok(_) => Some(
-                Record::try_from_str(&self.line_buf, self.header)
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+                Record::try_from_str(&self.line_buf, self.header).map_err(|e| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        ParseError::new(self.inner.line_number(), e),
+                    )
+                }),
             ),
             Err(e) => Some(Err(e)),
         }
@@ -8,16 +8,20 @@ pub mod filters;
 pub mod genotypes;
 pub mod ids;
 pub mod info;
+pub mod normalization;
 mod parser;
 pub mod position;
 pub mod quality_score;
 pub mod reference_bases;
+pub(crate) mod select_samples;
+mod split_alleles;
 pub(crate) mod value;
 
 pub use self::{
     alternate_bases::AlternateBases, builder::Builder, chromosome::Chromosome, field::Field,
     filters::Filters, genotypes::Genotypes, ids::Ids, info::Info, parser::ParseError,
     position::Position, quality_score::QualityScore, reference_bases::ReferenceBases,
+    select_samples::SelectSamplesError, split_alleles::SplitAllelesError,
 };
 
 #[deprecated(
@@ -803,6 +807,44 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_fmt_with_quality_score() -> Result<(), Box<dyn std::error::Error>> {
+        for (raw_quality_score, expected) in [(".", None), ("30", Some(30.0)), ("29.5", Some(29.5))]
+        {
+            let s = format!("sq0\t1\t.\tA\t.\t{}\tPASS\t.", raw_quality_score);
+            let record: Record = s.parse()?;
+
+            assert_eq!(record.quality_score().map(f32::from), expected);
+            assert_eq!(record.to_string(), s);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fmt_with_modified_info() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::header::info::Key;
+
+        use super::info::{field::Value, Field};
+
+        let mut record: Record = "sq0\t1\t.\tA\t.\t.\tPASS\tNS=2;DP=13".parse()?;
+
+        let info = record.info_mut();
+        info.insert(Field::new(Key::IsSomaticMutation, Some(Value::Flag)));
+        info.insert(Field::new(Key::TotalDepth, Some(Value::Integer(8))));
+        info.remove(&Key::SamplesWithDataCount);
+
+        assert_eq!(record.to_string(), "sq0\t1\t.\tA\t.\t.\tPASS\tDP=8;SOMATIC");
+
+        let info = record.info_mut();
+        info.remove(&Key::TotalDepth);
+        info.remove(&Key::IsSomaticMutation);
+
+        assert_eq!(record.to_string(), "sq0\t1\t.\tA\t.\t.\tPASS\t.");
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt_with_format() -> Result<(), Box<dyn std::error::Error>> {
         use super::genotypes::Genotype;
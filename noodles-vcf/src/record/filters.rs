@@ -4,6 +4,8 @@ use std::{error, fmt, str::FromStr};
 
 use indexmap::IndexSet;
 
+use crate::header;
+
 const PASS_STATUS: &str = "PASS";
 const DELIMITER: char = ';';
 
@@ -39,6 +41,23 @@ impl fmt::Display for TryFromIteratorError {
     }
 }
 
+/// An error returned when VCF filters fail validation against a header.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidateError {
+    /// A filter is not defined in the header (`FILTER`).
+    UndefinedFilter(String),
+}
+
+impl error::Error for ValidateError {}
+
+impl fmt::Display for ValidateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UndefinedFilter(id) => write!(f, "undefined filter: {}", id),
+        }
+    }
+}
+
 impl Filters {
     /// Performs a conversion from a string iterator to a set of filters.
     ///
@@ -83,6 +102,41 @@ impl Filters {
             Ok(Self::Fail(filters))
         }
     }
+
+    /// Validates that all filters are defined in the header filter records (`FILTER`).
+    ///
+    /// `PASS` is always considered valid, whether or not it is defined in the header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::Filter, record::Filters};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_filter(Filter::new("q10", "Quality below 10"))
+    ///     .build();
+    ///
+    /// let filters: Filters = "q10".parse()?;
+    /// assert!(filters.validate(header.filters()).is_ok());
+    ///
+    /// let filters: Filters = "q10;s50".parse()?;
+    /// assert!(filters.validate(header.filters()).is_err());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn validate(&self, filters: &header::Filters) -> Result<(), ValidateError> {
+        match self {
+            Self::Pass => Ok(()),
+            Self::Fail(ids) => {
+                for id in ids {
+                    if !filters.contains_key(id) {
+                        return Err(ValidateError::UndefinedFilter(id.clone()));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+    }
 }
 
 impl fmt::Display for Filters {
@@ -185,6 +239,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate() -> Result<(), ParseError> {
+        use crate::{header::Filter, Header};
+
+        let header = Header::builder()
+            .add_filter(Filter::new("q10", "Quality below 10"))
+            .add_filter(Filter::new("s50", "Less than 50% of samples have data"))
+            .build();
+
+        let filters: Filters = "q10;s50".parse()?;
+        assert!(matches!(&filters, Filters::Fail(ids) if ids.len() == 2));
+        assert_eq!(filters.to_string(), "q10;s50");
+        assert_eq!(filters.validate(header.filters()), Ok(()));
+
+        assert_eq!(Filters::Pass.validate(&header::Filters::new()), Ok(()));
+
+        let header = Header::builder()
+            .add_filter(Filter::new("q10", "Quality below 10"))
+            .build();
+
+        assert_eq!(
+            filters.validate(header.filters()),
+            Err(ValidateError::UndefinedFilter(String::from("s50")))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() -> Result<(), TryFromIteratorError> {
         assert_eq!(Filters::Pass.to_string(), "PASS");
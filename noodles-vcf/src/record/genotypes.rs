@@ -12,9 +12,11 @@ use std::{
     str::FromStr,
 };
 
-use self::genotype::field;
+use indexmap::{IndexMap, IndexSet};
+
+use self::genotype::{field, Field};
 use super::FIELD_DELIMITER;
-use crate::Header;
+use crate::{header::format::Key, Header};
 
 /// VCF record genotypes.
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -119,12 +121,101 @@ impl Genotypes {
         &mut self.keys
     }
 
+    /// Adds a key to the genotypes keys and all samples.
+    ///
+    /// Samples are given a missing value for the new key. If the key is the genotype key (`GT`),
+    /// it is added as the first key. Otherwise, it is added as the last key.
+    ///
+    /// This returns whether the key was added, i.e., `false` if the key already exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     header::format::Key,
+    ///     record::{genotypes::genotype::field::Value, Genotypes},
+    /// };
+    ///
+    /// let mut genotypes: Genotypes = "GT\t0|0\t1|1".parse()?;
+    /// assert!(genotypes.insert_key(Key::ConditionalGenotypeQuality));
+    /// assert_eq!(genotypes.to_string(), "GT:GQ\t0|0:.\t1|1:.");
+    ///
+    /// if let Some(field) = genotypes[1].get_mut(&Key::ConditionalGenotypeQuality) {
+    ///     *field.value_mut() = Some(Value::Integer(13));
+    /// }
+    ///
+    /// assert_eq!(genotypes.to_string(), "GT:GQ\t0|0:.\t1|1:13");
+    /// # Ok::<_, noodles_vcf::record::genotypes::ParseError>(())
+    /// ```
+    pub fn insert_key(&mut self, key: Key) -> bool {
+        if self.keys.contains(&key) {
+            return false;
+        }
+
+        if key == Key::Genotype {
+            // § 1.6.2 Genotype fields (2021-01-13): "If present, the GT field must be the first
+            // field."
+            let mut keys = IndexSet::with_capacity(self.keys.len() + 1);
+            keys.insert(key);
+            keys.extend(self.keys.drain(..));
+            *self.keys = keys;
+        } else {
+            self.keys.insert(key);
+        }
+
+        self.normalize_genotypes();
+
+        true
+    }
+
+    /// Removes a key from the genotypes keys and all samples.
+    ///
+    /// This returns whether the key was removed, i.e., `false` if the key does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{header::format::Key, record::Genotypes};
+    ///
+    /// let mut genotypes: Genotypes = "GT:GQ\t0|0:8\t1|1:13".parse()?;
+    /// assert!(genotypes.remove_key(&Key::ConditionalGenotypeQuality));
+    /// assert_eq!(genotypes.to_string(), "GT\t0|0\t1|1");
+    /// # Ok::<_, noodles_vcf::record::genotypes::ParseError>(())
+    /// ```
+    pub fn remove_key(&mut self, key: &Key) -> bool {
+        if !self.keys.shift_remove(key) {
+            return false;
+        }
+
+        self.normalize_genotypes();
+
+        true
+    }
+
     /// Returns the VCF record genotype value.
     pub fn genotypes(
         &self,
     ) -> Result<Vec<Option<field::value::Genotype>>, genotype::GenotypeError> {
         self.iter().map(|g| g.genotype().transpose()).collect()
     }
+
+    // Rebuilds the fields of each sample to match the genotypes keys, in order. Missing fields are
+    // given a missing value, and fields with keys that are not in the genotypes keys are dropped.
+    fn normalize_genotypes(&mut self) {
+        for genotype in &mut self.genotypes {
+            let mut fields = IndexMap::with_capacity(self.keys.len());
+
+            for key in self.keys.iter() {
+                let field = genotype
+                    .remove(key)
+                    .unwrap_or_else(|| Field::new(key.clone(), None));
+
+                fields.insert(key.clone(), field);
+            }
+
+            **genotype = fields;
+        }
+    }
 }
 
 impl Deref for Genotypes {
@@ -215,7 +306,7 @@ mod tests {
 
     #[test]
     fn test_genotypes() -> Result<(), Box<dyn std::error::Error>> {
-        use crate::header::{format::Key, Format};
+        use crate::header::Format;
 
         let header = crate::Header::builder()
             .add_format(Format::from(Key::Genotype))
@@ -244,10 +335,43 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_insert_key() -> Result<(), Box<dyn std::error::Error>> {
+        use self::genotype::field::Value;
+
+        let mut genotypes: Genotypes = "GT\t0|0\t.\t1|1".parse()?;
+
+        assert!(genotypes.insert_key(Key::ConditionalGenotypeQuality));
+        assert!(!genotypes.insert_key(Key::ConditionalGenotypeQuality));
+        assert_eq!(genotypes.to_string(), "GT:GQ\t0|0:.\t.:.\t1|1:.");
+
+        if let Some(field) = genotypes[2].get_mut(&Key::ConditionalGenotypeQuality) {
+            *field.value_mut() = Some(Value::Integer(13));
+        }
+
+        assert_eq!(genotypes.to_string(), "GT:GQ\t0|0:.\t.:.\t1|1:13");
+
+        let mut genotypes: Genotypes = "GQ\t8\t13".parse()?;
+        assert!(genotypes.insert_key(Key::Genotype));
+        assert_eq!(genotypes.to_string(), "GT:GQ\t.:8\t.:13");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_key() -> Result<(), Box<dyn std::error::Error>> {
+        let mut genotypes: Genotypes = "GT:GQ:DP\t0|0:8:5\t1|1:13:3".parse()?;
+
+        assert!(genotypes.remove_key(&Key::ConditionalGenotypeQuality));
+        assert!(!genotypes.remove_key(&Key::ConditionalGenotypeQuality));
+        assert_eq!(genotypes.to_string(), "GT:DP\t0|0:5\t1|1:3");
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() -> Result<(), Box<dyn std::error::Error>> {
         use self::genotype::{field::Value, Field};
-        use crate::header::format::Key;
 
         let genotypes = Genotypes::new(
             Keys::try_from(vec![Key::Genotype, Key::ConditionalGenotypeQuality])?,
@@ -265,7 +389,6 @@ mod tests {
     #[test]
     fn test_from_str() -> Result<(), Box<dyn std::error::Error>> {
         use super::genotype::{field::Value, Field};
-        use crate::header::format::Key;
 
         let expected = Genotypes::new(
             Keys::try_from(vec![Key::Genotype, Key::ConditionalGenotypeQuality])?,
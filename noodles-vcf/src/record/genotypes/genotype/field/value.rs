@@ -8,7 +8,7 @@ use std::{error, fmt, num, str};
 
 use crate::{
     header::{format::Type, Format, Number},
-    record::value::{self, percent_decode},
+    record::value::{self, percent_decode, percent_encode},
 };
 
 const DELIMITER: char = ',';
@@ -41,7 +41,7 @@ impl fmt::Display for Value {
             Self::Integer(n) => write!(f, "{}", n),
             Self::Float(n) => write!(f, "{}", n),
             Self::Character(c) => write!(f, "{}", c),
-            Self::String(s) => write!(f, "{}", s),
+            Self::String(s) => write!(f, "{}", percent_encode(s)),
             Self::IntegerArray(values) => {
                 for (i, value) in values.iter().enumerate() {
                     if i > 0 {
@@ -94,7 +94,7 @@ impl fmt::Display for Value {
                     }
 
                     if let Some(v) = value {
-                        write!(f, "{}", v)?;
+                        write!(f, "{}", percent_encode(v))?;
                     } else {
                         f.write_str(MISSING_VALUE)?;
                     }
@@ -233,7 +233,7 @@ fn parse_raw_char(s: &str) -> Result<char, ParseError> {
     let mut chars = s.chars();
 
     if let Some(c) = chars.next() {
-        if chars.next().is_none() {
+        if chars.next().is_none() && is_valid_char(c) {
             return Ok(c);
         }
     }
@@ -241,6 +241,11 @@ fn parse_raw_char(s: &str) -> Result<char, ParseError> {
     Err(ParseError::InvalidCharacter)
 }
 
+// Control characters and the genotype field delimiters are not allowed.
+fn is_valid_char(c: char) -> bool {
+    !c.is_control() && !matches!(c, ':' | ',')
+}
+
 fn parse_char(s: &str) -> Result<Value, ParseError> {
     parse_raw_char(s).map(Value::Character)
 }
@@ -297,6 +302,9 @@ mod tests {
         let value = Value::String(String::from("noodles"));
         assert_eq!(value.to_string(), "noodles");
 
+        let value = Value::String(String::from("8%:x"));
+        assert_eq!(value.to_string(), "8%25%3Ax");
+
         let value = Value::IntegerArray(vec![Some(2)]);
         assert_eq!(value.to_string(), "2");
 
@@ -444,6 +452,14 @@ mod tests {
             Value::from_str_format("n", &format),
             Ok(Value::Character('n'))
         );
+        assert_eq!(
+            Value::from_str_format("\t", &format),
+            Err(ParseError::InvalidCharacter)
+        );
+        assert_eq!(
+            Value::from_str_format(":", &format),
+            Err(ParseError::InvalidCharacter)
+        );
 
         let format = Format::new(
             "CHAR".parse()?,
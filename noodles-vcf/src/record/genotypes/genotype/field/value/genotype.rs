@@ -28,6 +28,16 @@ impl DerefMut for Genotype {
     }
 }
 
+impl fmt::Display for Genotype {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for allele in self.iter() {
+            write!(f, "{}", allele)?;
+        }
+
+        Ok(())
+    }
+}
+
 /// An error returned when a raw VCF record genotype value fails to parse.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ParseError {
@@ -109,6 +119,16 @@ impl fmt::Display for TryFromAllelesError {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_fmt() -> Result<(), ParseError> {
+        for s in ["0", "0/1", "1|2", "./.", "0/1|2"] {
+            let genotype: Genotype = s.parse()?;
+            assert_eq!(genotype.to_string(), s);
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_str() {
         use allele::Phasing;
@@ -83,6 +83,20 @@ impl Allele {
     }
 }
 
+impl fmt::Display for Allele {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(phasing) = self.phasing {
+            write!(f, "{}", phasing)?;
+        }
+
+        if let Some(position) = self.position {
+            write!(f, "{}", position)
+        } else {
+            f.write_str(MISSING_POSITION)
+        }
+    }
+}
+
 /// An error returned when a raw VCF record genotype value allele fails to parse.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ParseError {
@@ -142,6 +156,17 @@ fn parse_position(s: &str) -> Result<Option<usize>, ParseError> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Allele::new(Some(0), None).to_string(), "0");
+        assert_eq!(Allele::new(None, None).to_string(), ".");
+        assert_eq!(
+            Allele::new(Some(1), Some(Phasing::Phased)).to_string(),
+            "|1"
+        );
+        assert_eq!(Allele::new(None, Some(Phasing::Unphased)).to_string(), "/.");
+    }
+
     #[test]
     fn test_from_str() {
         assert_eq!(".".parse(), Ok(Allele::new(None, None)));
@@ -204,6 +204,33 @@ impl Info {
         self.0.insert(field.key().clone(), field)
     }
 
+    /// Removes a field with the given key.
+    ///
+    /// This preserves the order of the remaining fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     header::info::Key,
+    ///     record::{info::{field::Value, Field}, Info},
+    /// };
+    ///
+    /// let ns = Field::new(Key::SamplesWithDataCount, Some(Value::Integer(2)));
+    /// let dp = Field::new(Key::TotalDepth, Some(Value::Integer(13)));
+    /// let mut info = Info::try_from(vec![ns.clone(), dp.clone()])?;
+    ///
+    /// assert_eq!(info.remove(&Key::SamplesWithDataCount), Some(ns));
+    /// assert_eq!(info.len(), 1);
+    /// assert_eq!(info.get_index(0), Some(&dp));
+    ///
+    /// assert!(info.remove(&Key::SamplesWithDataCount).is_none());
+    /// # Ok::<_, noodles_vcf::record::info::TryFromFieldsError>(())
+    /// ```
+    pub fn remove(&mut self, key: &Key) -> Option<Field> {
+        self.0.shift_remove(key)
+    }
+
     /// Returns an iterator over all keys.
     ///
     /// # Examples
@@ -5,7 +5,7 @@ use std::{error, fmt, num, str};
 use super::MISSING_VALUE;
 use crate::{
     header::{info::Type, Info, Number},
-    record::value::{self, percent_decode},
+    record::value::{self, percent_decode, percent_encode},
 };
 
 const DELIMITER: char = ',';
@@ -40,7 +40,7 @@ impl fmt::Display for Value {
             Self::Float(n) => write!(f, "{}", n),
             Self::Flag => Ok(()),
             Self::Character(c) => write!(f, "{}", c),
-            Self::String(s) => write!(f, "{}", s),
+            Self::String(s) => write!(f, "{}", percent_encode(s)),
             Self::IntegerArray(values) => {
                 for (i, value) in values.iter().enumerate() {
                     if i > 0 {
@@ -93,7 +93,7 @@ impl fmt::Display for Value {
                     }
 
                     if let Some(v) = value {
-                        write!(f, "{}", v)?;
+                        write!(f, "{}", percent_encode(v))?;
                     } else {
                         f.write_str(MISSING_VALUE)?;
                     }
@@ -177,6 +177,60 @@ impl Value {
             },
         }
     }
+
+    /// Returns whether two values are equal, ignoring the order of array values.
+    ///
+    /// This is useful for comparing fields with set semantics. Arrays are compared as multisets,
+    /// i.e., each value must occur the same number of times in both arrays. Non-array values are
+    /// compared the same as `==`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::info::field::Value;
+    ///
+    /// let a = Value::IntegerArray(vec![Some(1), Some(2)]);
+    /// let b = Value::IntegerArray(vec![Some(2), Some(1)]);
+    ///
+    /// assert_ne!(a, b);
+    /// assert!(a.eq_unordered(&b));
+    /// ```
+    pub fn eq_unordered(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::IntegerArray(a), Self::IntegerArray(b)) => eq_unordered(a, b),
+            (Self::FloatArray(a), Self::FloatArray(b)) => eq_unordered(a, b),
+            (Self::CharacterArray(a), Self::CharacterArray(b)) => eq_unordered(a, b),
+            (Self::StringArray(a), Self::StringArray(b)) => eq_unordered(a, b),
+            _ => self == other,
+        }
+    }
+}
+
+// Values only need to implement `PartialEq`, so each value in `a` is matched with a distinct,
+// equal value in `b`.
+fn eq_unordered<T>(a: &[T], b: &[T]) -> bool
+where
+    T: PartialEq,
+{
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut is_matched = vec![false; b.len()];
+
+    for x in a {
+        let i = b
+            .iter()
+            .enumerate()
+            .position(|(i, y)| !is_matched[i] && x == y);
+
+        match i {
+            Some(i) => is_matched[i] = true,
+            None => return false,
+        }
+    }
+
+    true
 }
 
 fn parse_i32(s: &str) -> Result<Value, ParseError> {
@@ -225,7 +279,7 @@ fn parse_raw_char(s: &str) -> Result<char, ParseError> {
     let mut chars = s.chars();
 
     if let Some(c) = chars.next() {
-        if chars.next().is_none() {
+        if chars.next().is_none() && is_valid_char(c) {
             return Ok(c);
         }
     }
@@ -233,6 +287,11 @@ fn parse_raw_char(s: &str) -> Result<char, ParseError> {
     Err(ParseError::InvalidCharacter)
 }
 
+// Control characters and the INFO field delimiters are not allowed.
+fn is_valid_char(c: char) -> bool {
+    !c.is_control() && !matches!(c, ';' | '=' | ',')
+}
+
 fn parse_char(s: &str) -> Result<Value, ParseError> {
     parse_raw_char(s).map(Value::Character)
 }
@@ -285,6 +344,9 @@ mod tests {
         let value = Value::String(String::from("noodles"));
         assert_eq!(value.to_string(), "noodles");
 
+        let value = Value::String(String::from("8%;x=y"));
+        assert_eq!(value.to_string(), "8%25%3Bx%3Dy");
+
         let value = Value::IntegerArray(vec![Some(2)]);
         assert_eq!(value.to_string(), "2");
 
@@ -323,6 +385,9 @@ mod tests {
 
         let value = Value::StringArray(vec![Some(String::from("noodles")), None]);
         assert_eq!(value.to_string(), "noodles,.");
+
+        let value = Value::StringArray(vec![Some(String::from("8,13")), None]);
+        assert_eq!(value.to_string(), "8%2C13,.");
     }
 
     #[test]
@@ -473,6 +538,14 @@ mod tests {
             String::default(),
         );
         assert_eq!(Value::from_str_info("n", &info), Ok(Value::Character('n')));
+        assert_eq!(
+            Value::from_str_info("\x07", &info),
+            Err(ParseError::InvalidCharacter)
+        );
+        assert_eq!(
+            Value::from_str_info(";", &info),
+            Err(ParseError::InvalidCharacter)
+        );
 
         let info = Info::new(
             "CHAR".parse()?,
@@ -563,4 +636,35 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_eq_unordered() {
+        let a = Value::IntegerArray(vec![Some(1), Some(2)]);
+        let b = Value::IntegerArray(vec![Some(2), Some(1)]);
+        assert_ne!(a, b);
+        assert!(a.eq_unordered(&b));
+
+        let a = Value::IntegerArray(vec![Some(1), Some(1), Some(2)]);
+        let b = Value::IntegerArray(vec![Some(1), Some(2), Some(2)]);
+        assert!(!a.eq_unordered(&b));
+
+        let a = Value::IntegerArray(vec![Some(1), Some(2)]);
+        let b = Value::IntegerArray(vec![Some(1), Some(2), None]);
+        assert!(!a.eq_unordered(&b));
+
+        let a = Value::FloatArray(vec![Some(0.5), None]);
+        let b = Value::FloatArray(vec![None, Some(0.5)]);
+        assert!(a.eq_unordered(&b));
+
+        let a = Value::CharacterArray(vec![Some('n'), Some('d')]);
+        let b = Value::CharacterArray(vec![Some('d'), Some('n')]);
+        assert!(a.eq_unordered(&b));
+
+        let a = Value::StringArray(vec![Some(String::from("ndls")), None]);
+        let b = Value::StringArray(vec![None, Some(String::from("ndls"))]);
+        assert!(a.eq_unordered(&b));
+
+        assert!(Value::Integer(1).eq_unordered(&Value::Integer(1)));
+        assert!(!Value::Integer(1).eq_unordered(&Value::IntegerArray(vec![Some(1)])));
+    }
 }
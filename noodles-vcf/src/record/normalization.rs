@@ -0,0 +1,306 @@
+//! VCF record variant normalization.
+
+use std::io;
+
+use noodles_fasta as fasta;
+
+use super::{
+    alternate_bases::Allele,
+    reference_bases::{Base, ReferenceBases},
+    AlternateBases, Chromosome, Position, Record,
+};
+
+/// Normalizes the alleles of a record.
+///
+/// The reference sequence of the record is fetched from the given repository. Bases common to
+/// the ends of all alleles are trimmed, and indels are shifted left to their leftmost
+/// representation, e.g., a deletion in a homopolymer run is moved to the start of the run.
+/// Bases common to the starts of all alleles are then trimmed while leaving at least one base in
+/// each allele.
+///
+/// Records with a missing alternate allele or with symbolic, breakend, or overlapping deletion
+/// alternate alleles are returned as is.
+///
+/// This returns an error if the reference sequence is missing or if the reference bases do not
+/// match the reference sequence.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fasta as fasta;
+/// use noodles_vcf::{self as vcf, record::{normalization, Position}};
+///
+/// let repository = fasta::Repository::new(vec![fasta::Record::new(
+///     fasta::record::Definition::new("sq0", None),
+///     fasta::record::Sequence::from(b"ACGTTTTA".to_vec()),
+/// )]);
+///
+/// let record = vcf::Record::builder()
+///     .set_chromosome("sq0".parse()?)
+///     .set_position(Position::try_from(6)?)
+///     .set_reference_bases("TT".parse()?)
+///     .set_alternate_bases("T".parse()?)
+///     .build()?;
+///
+/// let normalized_record = normalization::normalize(&repository, &record)?;
+///
+/// assert_eq!(i32::from(normalized_record.position()), 3);
+/// assert_eq!(normalized_record.reference_bases().to_string(), "GT");
+/// assert_eq!(normalized_record.alternate_bases().to_string(), "G");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn normalize(
+    reference_sequence_repository: &fasta::Repository,
+    record: &Record,
+) -> io::Result<Record> {
+    if record.alternate_bases().is_empty() {
+        return Ok(record.clone());
+    }
+
+    let mut alleles = vec![record.reference_bases().to_vec()];
+
+    for allele in record.alternate_bases().iter() {
+        match allele {
+            Allele::Bases(bases) => alleles.push(bases.clone()),
+            _ => return Ok(record.clone()),
+        }
+    }
+
+    let reference_sequence_name = match record.chromosome() {
+        Chromosome::Name(name) => name,
+        Chromosome::Symbol(_) => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "chromosome is not a reference sequence name",
+            ))
+        }
+    };
+
+    let reference_sequence = reference_sequence_repository
+        .get(reference_sequence_name)
+        .transpose()?
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("missing reference sequence: {}", reference_sequence_name),
+            )
+        })?;
+
+    let position = usize::try_from(i32::from(record.position()))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    validate_reference_bases(&reference_sequence, position, record.reference_bases())?;
+
+    let position = normalize_alleles(&reference_sequence, position, &mut alleles)?;
+
+    let mut normalized_record = record.clone();
+
+    *normalized_record.position_mut() = i32::try_from(position)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+        .and_then(|n| {
+            Position::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+        })?;
+
+    let mut alleles = alleles.into_iter();
+
+    if let Some(reference_bases) = alleles.next() {
+        *normalized_record.reference_bases_mut() = ReferenceBases::try_from(reference_bases)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    }
+
+    *normalized_record.alternate_bases_mut() =
+        AlternateBases::from(alleles.map(Allele::Bases).collect::<Vec<_>>());
+
+    Ok(normalized_record)
+}
+
+fn validate_reference_bases(
+    reference_sequence: &fasta::record::Sequence,
+    position: usize,
+    reference_bases: &ReferenceBases,
+) -> io::Result<()> {
+    let actual = position
+        .checked_sub(1)
+        .and_then(|start| {
+            reference_sequence
+                .as_ref()
+                .get(start..start + reference_bases.len())
+        })
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "reference bases are out of reference sequence bounds",
+            )
+        })?;
+
+    let is_match = actual
+        .iter()
+        .zip(reference_bases.iter())
+        .all(|(&a, &b)| char::from(a.to_ascii_uppercase()) == char::from(b));
+
+    if is_match {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "reference bases do not match reference sequence",
+        ))
+    }
+}
+
+// Returns the normalized (1-based) position of the alleles, where the first allele is the
+// reference allele.
+fn normalize_alleles(
+    reference_sequence: &fasta::record::Sequence,
+    mut position: usize,
+    alleles: &mut [Vec<Base>],
+) -> io::Result<usize> {
+    loop {
+        let mut is_changed = false;
+
+        if let Some(last_base) = alleles[0].last().copied() {
+            let can_truncate = alleles
+                .iter()
+                .all(|allele| allele.last() == Some(&last_base))
+                && (position > 1 || alleles.iter().all(|allele| allele.len() > 1));
+
+            if can_truncate {
+                for allele in alleles.iter_mut() {
+                    allele.pop();
+                }
+
+                is_changed = true;
+            }
+        }
+
+        if alleles.iter().any(|allele| allele.is_empty()) {
+            position -= 1;
+
+            let base = get_base(reference_sequence, position)?;
+
+            for allele in alleles.iter_mut() {
+                allele.insert(0, base);
+            }
+
+            is_changed = true;
+        }
+
+        if !is_changed {
+            break;
+        }
+    }
+
+    while alleles.iter().all(|allele| allele.len() > 1)
+        && alleles.iter().all(|allele| allele[0] == alleles[0][0])
+    {
+        for allele in alleles.iter_mut() {
+            allele.remove(0);
+        }
+
+        position += 1;
+    }
+
+    Ok(position)
+}
+
+fn get_base(reference_sequence: &fasta::record::Sequence, position: usize) -> io::Result<Base> {
+    let b = position
+        .checked_sub(1)
+        .and_then(|i| reference_sequence.as_ref().get(i))
+        .copied()
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "position is out of reference sequence bounds",
+            )
+        })?;
+
+    Base::try_from(char::from(b.to_ascii_uppercase()))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_repository() -> fasta::Repository {
+        fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(b"ACGTTTTACAGCAGT".to_vec()),
+        )])
+    }
+
+    fn t(
+        repository: &fasta::Repository,
+        (position, reference_bases, alternate_bases): (i32, &str, &str),
+        expected: (i32, &str, &str),
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::try_from(position)?)
+            .set_reference_bases(reference_bases.parse()?)
+            .set_alternate_bases(alternate_bases.parse()?)
+            .build()?;
+
+        let actual = normalize(repository, &record)?;
+
+        assert_eq!(
+            (
+                i32::from(actual.position()),
+                actual.reference_bases().to_string().as_str(),
+                actual.alternate_bases().to_string().as_str(),
+            ),
+            expected
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize() -> Result<(), Box<dyn std::error::Error>> {
+        let repository = build_repository();
+
+        // deletion in a homopolymer run
+        t(&repository, (6, "TT", "T"), (3, "GT", "G"))?;
+        t(&repository, (5, "TTT", "T"), (3, "GTT", "G"))?;
+        // insertion in a homopolymer run
+        t(&repository, (7, "T", "TT"), (3, "G", "GT"))?;
+        // deletion in a tandem repeat
+        t(&repository, (11, "GCAG", "G"), (8, "ACAG", "A"))?;
+        // common prefix and suffix
+        t(&repository, (2, "CGTT", "CATT"), (3, "G", "A"))?;
+        // multiple alternate alleles
+        t(&repository, (6, "TTA", "TA,TTTA"), (3, "GT", "G,GTT"))?;
+        // already normalized
+        t(&repository, (3, "G", "A"), (3, "G", "A"))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_with_symbolic_allele() -> Result<(), Box<dyn std::error::Error>> {
+        let repository = build_repository();
+        let record: Record = "sq0\t4\t.\tT\t<DEL>\t.\t.\t.".parse()?;
+        assert_eq!(normalize(&repository, &record)?, record);
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_with_invalid_record() -> Result<(), Box<dyn std::error::Error>> {
+        let repository = build_repository();
+
+        let record: Record = "sq0\t1\t.\tC\tG\t.\t.\t.".parse()?;
+        assert!(matches!(
+            normalize(&repository, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let record: Record = "sq1\t1\t.\tA\tG\t.\t.\t.".parse()?;
+        assert!(matches!(
+            normalize(&repository, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
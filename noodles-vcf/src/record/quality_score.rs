@@ -93,8 +93,9 @@ mod tests {
 
     #[test]
     fn test_fmt() {
-        let quality_score = QualityScore(1.3);
-        assert_eq!(quality_score.to_string(), "1.3");
+        assert_eq!(QualityScore(1.3).to_string(), "1.3");
+        assert_eq!(QualityScore(30.0).to_string(), "30");
+        assert_eq!(QualityScore(29.5).to_string(), "29.5");
     }
 
     #[test]
@@ -0,0 +1,211 @@
+use std::{error, fmt};
+
+use super::{
+    genotypes::{genotype, Genotypes},
+    info, Record,
+};
+use crate::header::{self, format};
+
+type InfoValue = info::field::Value;
+
+/// An error returned when samples fail to be selected.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SelectSamplesError {
+    /// A sample index is out of range.
+    InvalidSampleIndex(usize),
+    /// A sample index is selected more than once.
+    DuplicateSampleIndex(usize),
+    /// A genotype (`GT`) is invalid.
+    InvalidGenotype(genotype::GenotypeError),
+}
+
+impl error::Error for SelectSamplesError {}
+
+impl fmt::Display for SelectSamplesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSampleIndex(i) => write!(f, "invalid sample index: {}", i),
+            Self::DuplicateSampleIndex(i) => write!(f, "duplicate sample index: {}", i),
+            Self::InvalidGenotype(e) => write!(f, "invalid genotype: {}", e),
+        }
+    }
+}
+
+impl Record {
+    /// Returns a copy of the record with only the samples at the given indices.
+    ///
+    /// Samples are kept in the order of the given indices. The genotype keys (`FORMAT`) are
+    /// shared by the retained samples and are not changed.
+    ///
+    /// If the samples have genotypes (`GT`), the allele count (`AC`), total allele count (`AN`),
+    /// and number of samples with data (`NS`) INFO fields are recomputed from the retained
+    /// samples when they are present in the record.
+    ///
+    /// Use [`crate::Header::select_samples`] to select the same samples in the header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::{format::Key, Format}};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_format(Format::from(Key::Genotype))
+    ///     .add_sample_name("sample0")
+    ///     .add_sample_name("sample1")
+    ///     .build();
+    ///
+    /// let record = vcf::Record::try_from_str("sq0\t1\t.\tA\tC\t.\t.\t.\tGT\t0|1\t1|1", &header)?;
+    /// let record = record.select_samples(&[1])?;
+    ///
+    /// assert_eq!(record.genotypes().to_string(), "GT\t1|1");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn select_samples(&self, indices: &[usize]) -> Result<Self, SelectSamplesError> {
+        validate_indices(indices, self.genotypes().len())?;
+
+        let mut record = self.clone();
+
+        let genotypes = indices
+            .iter()
+            .map(|&i| self.genotypes()[i].clone())
+            .collect();
+
+        *record.genotypes_mut() = Genotypes::new(self.genotypes().keys().clone(), genotypes);
+
+        if record.genotypes().keys().contains(&format::Key::Genotype) {
+            update_allele_counts(&mut record)?;
+        }
+
+        Ok(record)
+    }
+}
+
+pub(crate) fn validate_indices(indices: &[usize], len: usize) -> Result<(), SelectSamplesError> {
+    for (j, &i) in indices.iter().enumerate() {
+        if i >= len {
+            return Err(SelectSamplesError::InvalidSampleIndex(i));
+        }
+
+        if indices[..j].contains(&i) {
+            return Err(SelectSamplesError::DuplicateSampleIndex(i));
+        }
+    }
+
+    Ok(())
+}
+
+fn update_allele_counts(record: &mut Record) -> Result<(), SelectSamplesError> {
+    let alternate_allele_count = record.alternate_bases().len();
+
+    let mut allele_counts = vec![0; alternate_allele_count];
+    let mut total_allele_count = 0;
+    let mut samples_with_data_count = 0;
+
+    for genotype in record
+        .genotypes()
+        .genotypes()
+        .map_err(SelectSamplesError::InvalidGenotype)?
+        .into_iter()
+        .flatten()
+    {
+        let mut has_data = false;
+
+        for position in genotype.iter().filter_map(|allele| allele.position()) {
+            has_data = true;
+            total_allele_count += 1;
+
+            if let Some(n) = position
+                .checked_sub(1)
+                .and_then(|i| allele_counts.get_mut(i))
+            {
+                *n += 1;
+            }
+        }
+
+        if has_data {
+            samples_with_data_count += 1;
+        }
+    }
+
+    let info = record.info_mut();
+
+    set_info_value(
+        info,
+        header::info::Key::AlleleCount,
+        InfoValue::IntegerArray(allele_counts.into_iter().map(Some).collect()),
+    );
+
+    set_info_value(
+        info,
+        header::info::Key::TotalAlleleCount,
+        InfoValue::Integer(total_allele_count),
+    );
+
+    set_info_value(
+        info,
+        header::info::Key::SamplesWithDataCount,
+        InfoValue::Integer(samples_with_data_count),
+    );
+
+    Ok(())
+}
+
+// Replaces the value of an existing INFO field.
+fn set_info_value(info: &mut super::Info, key: header::info::Key, value: InfoValue) {
+    if let Some(field) = info.get_mut(&key) {
+        *field.value_mut() = Some(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Header;
+
+    #[test]
+    fn test_select_samples() -> Result<(), Box<dyn std::error::Error>> {
+        let header: Header = r#"##fileformat=VCFv4.3
+##INFO=<ID=NS,Number=1,Type=Integer,Description="Number of samples with data">
+##INFO=<ID=AN,Number=1,Type=Integer,Description="Total number of alleles in called genotypes">
+##INFO=<ID=AC,Number=A,Type=Integer,Description="Allele count in genotypes">
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Combined depth across samples">
+##FORMAT=<ID=GT,Number=1,Type=String,Description="Genotype">
+##FORMAT=<ID=DP,Number=1,Type=Integer,Description="Read depth">
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO	FORMAT	sample0	sample1	sample2
+"#
+        .parse()?;
+
+        let record = Record::try_from_str(
+            "sq0\t1\t.\tA\tC,G\t.\tPASS\tNS=3;AN=6;AC=3,1;DP=21\tGT:DP\t0/1:5\t1/2:8\t1|1:8",
+            &header,
+        )?;
+
+        let actual = record.select_samples(&[1])?;
+        assert_eq!(
+            actual.to_string(),
+            "sq0\t1\t.\tA\tC,G\t.\tPASS\tNS=1;AN=2;AC=1,1;DP=21\tGT:DP\t1/2:8"
+        );
+
+        let actual = record.select_samples(&[2, 0])?;
+        assert_eq!(
+            actual.to_string(),
+            "sq0\t1\t.\tA\tC,G\t.\tPASS\tNS=2;AN=4;AC=3,0;DP=21\tGT:DP\t1|1:8\t0/1:5"
+        );
+
+        assert_eq!(
+            record.select_samples(&[3]),
+            Err(SelectSamplesError::InvalidSampleIndex(3))
+        );
+
+        assert_eq!(
+            record.select_samples(&[1, 1]),
+            Err(SelectSamplesError::DuplicateSampleIndex(1))
+        );
+
+        let header = header.select_samples(&[1])?;
+        let sample_names: Vec<_> = header.sample_names().iter().collect();
+        assert_eq!(sample_names, ["sample1"]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,317 @@
+use std::{error, fmt};
+
+use super::{
+    genotypes::genotype::{self, field::value::Genotype as GenotypeValue},
+    AlternateBases, Record,
+};
+use crate::{
+    header::{format, info, Number},
+    Header,
+};
+
+type InfoValue = super::info::field::Value;
+type FormatValue = genotype::field::Value;
+
+/// An error returned when a VCF record fails to split into biallelic records.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SplitAllelesError {
+    /// An INFO field value does not have the number of values given by its header record.
+    InvalidInfoFieldValue(info::Key),
+    /// A genotype field value does not have the number of values given by its header record.
+    InvalidGenotypeFieldValue(format::Key),
+    /// A genotype (`GT`) is invalid.
+    InvalidGenotype(genotype::GenotypeError),
+}
+
+impl error::Error for SplitAllelesError {}
+
+impl fmt::Display for SplitAllelesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidInfoFieldValue(key) => write!(f, "invalid INFO field value: {}", key),
+            Self::InvalidGenotypeFieldValue(key) => {
+                write!(f, "invalid genotype field value: {}", key)
+            }
+            Self::InvalidGenotype(e) => write!(f, "invalid genotype: {}", e),
+        }
+    }
+}
+
+impl Record {
+    /// Splits a multi-allelic record into one biallelic record per alternate allele.
+    ///
+    /// Each record keeps a single alternate allele. INFO and genotype field values with a number
+    /// of `A`, `R`, or `G` are subset to the values of the reference and chosen alternate allele.
+    /// Genotype (`GT`) allele indices are remapped so that the chosen alternate allele is 1. Other
+    /// alternate alleles are set to the reference allele (0).
+    ///
+    /// Records with fewer than two alternate alleles are returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::{info::Key, Info}, record::Position};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(Info::from(Key::AlleleCount))
+    ///     .build();
+    ///
+    /// let record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::try_from(1)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_alternate_bases("C,G".parse()?)
+    ///     .set_info(vcf::record::Info::try_from_str("AC=3,1", header.infos())?)
+    ///     .build()?;
+    ///
+    /// let records = record.split_alleles(&header)?;
+    ///
+    /// let actual: Vec<_> = records.iter().map(|r| r.info().to_string()).collect();
+    /// assert_eq!(actual, ["AC=3", "AC=1"]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn split_alleles(&self, header: &Header) -> Result<Vec<Self>, SplitAllelesError> {
+        let alternate_allele_count = self.alternate_bases().len();
+
+        if alternate_allele_count < 2 {
+            return Ok(vec![self.clone()]);
+        }
+
+        (1..=alternate_allele_count)
+            .map(|i| split_allele(header, self, i))
+            .collect()
+    }
+}
+
+// Builds a biallelic record for the alternate allele at index `i` (1-based).
+fn split_allele(header: &Header, record: &Record, i: usize) -> Result<Record, SplitAllelesError> {
+    let alternate_allele_count = record.alternate_bases().len();
+
+    let mut split_record = record.clone();
+
+    *split_record.alternate_bases_mut() =
+        AlternateBases::from(vec![record.alternate_bases()[i - 1].clone()]);
+
+    for field in split_record.info_mut().as_mut().values_mut() {
+        let number = match header.infos().get(field.key()) {
+            Some(info) => info.number(),
+            None => continue,
+        };
+
+        let (expected_len, indices) = match number {
+            Number::A => (alternate_allele_count, vec![i - 1]),
+            Number::R => (alternate_allele_count + 1, vec![0, i]),
+            Number::G => (
+                diploid_genotype_count(alternate_allele_count),
+                diploid_genotype_indices(i),
+            ),
+            _ => continue,
+        };
+
+        let key = field.key().clone();
+
+        if let Some(value) = field.value_mut() {
+            *value = select_info_values(value, expected_len, &indices)
+                .ok_or(SplitAllelesError::InvalidInfoFieldValue(key))?;
+        }
+    }
+
+    for genotype in split_record.genotypes_mut().iter_mut() {
+        for (key, field) in genotype.iter_mut() {
+            if *key == format::Key::Genotype {
+                if let Some(FormatValue::String(s)) = field.value_mut() {
+                    let genotype: GenotypeValue = s.parse().map_err(|e| {
+                        SplitAllelesError::InvalidGenotype(genotype::GenotypeError::InvalidValue(e))
+                    })?;
+
+                    *s = remap_genotype(genotype, i).to_string();
+                }
+
+                continue;
+            }
+
+            let number = match header.formats().get(key) {
+                Some(format) => format.number(),
+                None => continue,
+            };
+
+            if let Some(value) = field.value_mut() {
+                *value = select_genotype_values(value, number, alternate_allele_count, i)
+                    .ok_or_else(|| SplitAllelesError::InvalidGenotypeFieldValue(key.clone()))?;
+            }
+        }
+    }
+
+    Ok(split_record)
+}
+
+// § 1.6.2 Genotype fields (2021-01-13): "the ordering of genotypes for the likelihoods is given
+// by: F(j/k) = (k*(k+1)/2)+j."
+fn diploid_genotype_count(alternate_allele_count: usize) -> usize {
+    let n = alternate_allele_count + 1;
+    n * (n + 1) / 2
+}
+
+// Returns the indices of the 0/0, 0/i, and i/i genotypes.
+fn diploid_genotype_indices(i: usize) -> Vec<usize> {
+    let j = i * (i + 1) / 2;
+    vec![0, j, j + i]
+}
+
+fn remap_genotype(mut genotype: GenotypeValue, i: usize) -> GenotypeValue {
+    for allele in genotype.iter_mut() {
+        if let Some(position) = allele.position_mut() {
+            *position = if *position == i { 1 } else { 0 };
+        }
+    }
+
+    genotype
+}
+
+fn select<T>(values: &[Option<T>], expected_len: usize, indices: &[usize]) -> Option<Vec<Option<T>>>
+where
+    T: Clone,
+{
+    if values.len() == expected_len {
+        Some(indices.iter().map(|&j| values[j].clone()).collect())
+    } else {
+        None
+    }
+}
+
+fn select_info_values(
+    value: &InfoValue,
+    expected_len: usize,
+    indices: &[usize],
+) -> Option<InfoValue> {
+    match value {
+        InfoValue::IntegerArray(values) => {
+            select(values, expected_len, indices).map(InfoValue::IntegerArray)
+        }
+        InfoValue::FloatArray(values) => {
+            select(values, expected_len, indices).map(InfoValue::FloatArray)
+        }
+        InfoValue::CharacterArray(values) => {
+            select(values, expected_len, indices).map(InfoValue::CharacterArray)
+        }
+        InfoValue::StringArray(values) => {
+            select(values, expected_len, indices).map(InfoValue::StringArray)
+        }
+        _ => None,
+    }
+}
+
+fn select_genotype_values(
+    value: &FormatValue,
+    number: Number,
+    alternate_allele_count: usize,
+    i: usize,
+) -> Option<FormatValue> {
+    if !matches!(number, Number::A | Number::R | Number::G) {
+        return Some(value.clone());
+    }
+
+    let len = match value {
+        FormatValue::IntegerArray(values) => values.len(),
+        FormatValue::FloatArray(values) => values.len(),
+        FormatValue::CharacterArray(values) => values.len(),
+        FormatValue::StringArray(values) => values.len(),
+        _ => return None,
+    };
+
+    let (expected_len, indices) = match number {
+        Number::A => (alternate_allele_count, vec![i - 1]),
+        Number::R => (alternate_allele_count + 1, vec![0, i]),
+        // The number of genotypes depends on the ploidy of the sample. Haploid samples have one
+        // value per allele.
+        _ if len == alternate_allele_count + 1 => (len, vec![0, i]),
+        _ => (
+            diploid_genotype_count(alternate_allele_count),
+            diploid_genotype_indices(i),
+        ),
+    };
+
+    match value {
+        FormatValue::IntegerArray(values) => {
+            select(values, expected_len, &indices).map(FormatValue::IntegerArray)
+        }
+        FormatValue::FloatArray(values) => {
+            select(values, expected_len, &indices).map(FormatValue::FloatArray)
+        }
+        FormatValue::CharacterArray(values) => {
+            select(values, expected_len, &indices).map(FormatValue::CharacterArray)
+        }
+        FormatValue::StringArray(values) => {
+            select(values, expected_len, &indices).map(FormatValue::StringArray)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_alleles() -> Result<(), Box<dyn std::error::Error>> {
+        let header: Header = r#"##fileformat=VCFv4.3
+##INFO=<ID=AC,Number=A,Type=Integer,Description="Allele count in genotypes">
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Combined depth across samples">
+##FORMAT=<ID=GT,Number=1,Type=String,Description="Genotype">
+##FORMAT=<ID=AD,Number=R,Type=Integer,Description="Read depth for each allele">
+##FORMAT=<ID=PL,Number=G,Type=Integer,Description="Phred-scaled genotype likelihoods rounded to the closest integer">
+##contig=<ID=sq0>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO	FORMAT	sample0	sample1
+"#
+        .parse()?;
+
+        let record = Record::try_from_str(
+            "sq0\t1\t.\tA\tC,G\t.\tPASS\tAC=3,1;DP=8\tGT:AD:PL\t1/2:0,3,1:60,10,50,20,0,40\t0|1:5,3,0:0,10,50,20,30,40",
+            &header,
+        )?;
+
+        let actual: Vec<_> = record
+            .split_alleles(&header)?
+            .iter()
+            .map(|r| r.to_string())
+            .collect();
+
+        let expected = [
+            "sq0\t1\t.\tA\tC\t.\tPASS\tAC=3;DP=8\tGT:AD:PL\t1/0:0,3:60,10,50\t0|1:5,3:0,10,50",
+            "sq0\t1\t.\tA\tG\t.\tPASS\tAC=1;DP=8\tGT:AD:PL\t0/1:0,1:60,20,40\t0|0:5,0:0,20,40",
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_alleles_with_one_alternate_allele() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::default();
+        let record: Record = "sq0\t1\t.\tA\tC\t.\t.\t.".parse()?;
+        assert_eq!(record.split_alleles(&header)?, [record]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_alleles_with_invalid_info_field_value() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let header: Header = r#"##fileformat=VCFv4.3
+##INFO=<ID=AC,Number=A,Type=Integer,Description="Allele count in genotypes">
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#
+        .parse()?;
+
+        let record = Record::try_from_str("sq0\t1\t.\tA\tC,G\t.\t.\tAC=3", &header)?;
+
+        assert_eq!(
+            record.split_alleles(&header),
+            Err(SplitAllelesError::InvalidInfoFieldValue(
+                info::Key::AlleleCount
+            ))
+        );
+
+        Ok(())
+    }
+}
@@ -1,16 +1,45 @@
 use std::{borrow::Cow, num, str};
 
-use percent_encoding::percent_decode_str;
+use percent_encoding::{percent_decode_str, percent_encode_byte};
 
 /// Parses a single-precision floating-point.
 pub(crate) fn parse_f32(s: &str) -> Result<f32, num::ParseFloatError> {
     s.parse()
 }
 
+/// Decodes a percent-encoded string value.
 pub(crate) fn percent_decode(s: &str) -> Result<Cow<'_, str>, str::Utf8Error> {
     percent_decode_str(s).decode_utf8()
 }
 
+/// Percent-encodes the characters with special meaning in a string value.
+///
+/// All other characters, including non-ASCII characters, are kept as is.
+pub(crate) fn percent_encode(s: &str) -> Cow<'_, str> {
+    if !s.contains(is_special_character) {
+        return Cow::Borrowed(s);
+    }
+
+    let mut t = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        if is_special_character(c) {
+            t.push_str(percent_encode_byte(c as u8));
+        } else {
+            t.push(c);
+        }
+    }
+
+    Cow::Owned(t)
+}
+
+// § 1.2 Character encoding, non-printable characters and characters with special meaning
+// (2021-01-13):
+// `:`, `;`, `=`, `%`, `,`, CR, LF, and TAB.
+fn is_special_character(c: char) -> bool {
+    matches!(c, ':' | ';' | '=' | '%' | ',' | '\r' | '\n' | '\t')
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -40,4 +69,23 @@ mod tests {
         assert_eq!(percent_decode("noodles%3Dvcf")?, "noodles=vcf");
         Ok(())
     }
+
+    #[test]
+    fn test_percent_encode() {
+        assert_eq!(percent_encode("noodles"), "noodles");
+        assert_eq!(percent_encode("noodles=vcf"), "noodles%3Dvcf");
+        assert_eq!(percent_encode("%:;=, \t\n\r"), "%25%3A%3B%3D%2C %09%0A%0D");
+        assert_eq!(percent_encode("ndls=é"), "ndls%3Dé");
+        assert_eq!(percent_encode("é"), "é");
+    }
+
+    #[test]
+    fn test_percent_encode_and_decode() -> Result<(), str::Utf8Error> {
+        let s = "50% a:b;c=d e,f";
+        assert_eq!(percent_decode(&percent_encode(s))?, s);
+
+        let s = "é";
+        assert_eq!(percent_decode(&percent_encode(s))?, s);
+        Ok(())
+    }
 }